@@ -1,33 +1,52 @@
 use crate::state::{
-    add_app_component, generate_assign_app_message, generate_ownership_message,
-    load_component_addresses, load_component_addresses_with_name, load_component_descriptors,
-    ADO_ADDRESSES, ADO_DESCRIPTORS, APP_NAME,
+    add_app_component, generate_assign_app_message, generate_migrate_message,
+    generate_ownership_message, load_component_addresses, load_component_addresses_with_name,
+    load_component_descriptor, load_component_descriptors, load_component_references,
+    load_dependents, load_proxy_permissions, query_deployed_code_id, remove_component_descriptor,
+    remove_component_proxy_permissions, rename_component_descriptor, update_component_ado_type,
+    ADO_ADDRESSES, ADO_DESCRIPTORS, APP_NAME, PROXY_PERMISSIONS,
 };
 use ado_base::ADOContract;
 use andromeda_app::app::{
-    AppComponent, ComponentAddress, ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
-    QueryMsg,
+    AppComponent, ComponentAddress, ComponentMessage, ComponentVersion, ConfigResponse, ExecuteMsg,
+    InstantiateMsg, MigrateMsg, ProxyPermission, QueryMsg,
 };
 use common::{
-    ado_base::{AndromedaQuery, InstantiateMsg as BaseInstantiateMsg},
-    encode_binary,
+    ado_base::{
+        ado_type::split_versioned_ado_type, AndromedaMsg, AndromedaQuery,
+        ExecuteMsg as AndromedaExecuteMsg, InstantiateMsg as BaseInstantiateMsg,
+    },
+    deduct_funds, encode_binary,
     error::ContractError,
-    parse_message,
+    get_message_name, parse_message,
     response::get_reply_address,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply,
-    ReplyOn, Response, StdError, Storage, SubMsg, WasmMsg,
+    ensure, to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, Event, MessageInfo,
+    QuerierWrapper, Reply, ReplyOn, Response, StdError, Storage, SubMsg, WasmMsg,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 
-use semver::Version;
+/// The reply ID of the first step of an `ExecuteBatch`, above the IDs of the components.
+const BATCH_REPLY_ID_OFFSET: u64 = 1_000_000;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-app-contract";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &[
+    "add_app_component",
+    "claim_ownership",
+    "update_address",
+    "remove_component",
+    "rename_component",
+    "upgrade_component",
+    "upgrade_all",
+    "set_proxy_permission",
+    "remove_proxy_permission",
+];
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -41,6 +60,8 @@ pub fn instantiate(
     ensure!(msg.app.len() <= 50, ContractError::TooManyAppComponents {});
 
     let sender = info.sender.to_string();
+    let app_address = env.contract.address.to_string();
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     let resp = ADOContract::default()
         .instantiate(
             deps.storage,
@@ -60,7 +81,13 @@ pub fn instantiate(
 
     let mut msgs: Vec<SubMsg> = vec![];
     for component in msg.app {
-        let comp_resp = execute_add_app_component(&deps.querier, deps.storage, &sender, component)?;
+        let comp_resp = execute_add_app_component(
+            &deps.querier,
+            deps.storage,
+            &sender,
+            &app_address,
+            component,
+        )?;
         msgs.extend(comp_resp.messages);
     }
 
@@ -75,6 +102,10 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
         )));
     }
 
+    if msg.id >= BATCH_REPLY_ID_OFFSET {
+        return on_batch_step_reply(msg);
+    }
+
     let id = msg.id.to_string();
     let descriptor = ADO_DESCRIPTORS.load(deps.storage, &id)?;
 
@@ -85,6 +116,19 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
     Ok(Response::default().add_submessage(assign_app))
 }
 
+/// Reports the result of a step of an `ExecuteBatch`. Steps only reply on success as a failed step
+/// reverts the whole batch.
+fn on_batch_step_reply(msg: Reply) -> Result<Response, ContractError> {
+    let step = msg.id - BATCH_REPLY_ID_OFFSET;
+    let mut event = Event::new("batch_step_result")
+        .add_attribute("step", step.to_string())
+        .add_attribute("result", "success");
+    if let Some(data) = msg.result.unwrap().data {
+        event = event.add_attribute("data", data.to_base64());
+    }
+    Ok(Response::new().add_event(event))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -92,18 +136,43 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::AndrReceive(msg) => {
             ADOContract::default().execute(deps, env, info, msg, execute)
         }
-        ExecuteMsg::AddAppComponent { component } => {
-            execute_add_app_component(&deps.querier, deps.storage, info.sender.as_str(), component)
-        }
+        ExecuteMsg::AddAppComponent { component } => execute_add_app_component(
+            &deps.querier,
+            deps.storage,
+            info.sender.as_str(),
+            env.contract.address.as_str(),
+            component,
+        ),
         ExecuteMsg::ClaimOwnership { name } => {
             execute_claim_ownership(deps.storage, info.sender.as_str(), name)
         }
         ExecuteMsg::ProxyMessage { msg, name } => execute_message(deps, info, name, msg),
+        ExecuteMsg::ExecuteBatch { msgs } => execute_batch(deps, info, msgs),
         ExecuteMsg::UpdateAddress { name, addr } => execute_update_address(deps, info, name, addr),
+        ExecuteMsg::RemoveComponent { name } => execute_remove_component(deps, info, name),
+        ExecuteMsg::RenameComponent { name, new_name } => {
+            execute_rename_component(deps, info, name, new_name)
+        }
+        ExecuteMsg::UpgradeComponent {
+            name,
+            code_id_key,
+            migrate_msg,
+        } => execute_upgrade_component(deps, info, name, code_id_key, migrate_msg),
+        ExecuteMsg::UpgradeAll { migrate_msg } => execute_upgrade_all(deps, info, migrate_msg),
+        ExecuteMsg::SetProxyPermission {
+            principal,
+            component,
+            messages,
+        } => execute_set_proxy_permission(deps, info, principal, component, messages),
+        ExecuteMsg::RemoveProxyPermission {
+            principal,
+            component,
+        } => execute_remove_proxy_permission(deps, info, principal, component),
     }
 }
 
@@ -111,6 +180,7 @@ fn execute_add_app_component(
     querier: &QuerierWrapper,
     storage: &mut dyn Storage,
     sender: &str,
+    app_address: &str,
     component: AppComponent,
 ) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
@@ -132,7 +202,8 @@ fn execute_add_app_component(
         idx,
         component.instantiate_msg,
         component.ado_type.clone(),
-        sender.to_string(),
+        // The app is the admin so that it can upgrade the component.
+        app_address.to_string(),
     )?;
 
     Ok(Response::new()
@@ -174,12 +245,7 @@ fn execute_message(
     name: String,
     msg: Binary,
 ) -> Result<Response, ContractError> {
-    //Temporary until message sender attached to Andromeda Comms
-    ensure!(
-        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
-        ContractError::Unauthorized {}
-    );
-
+    let msg = generate_proxy_msg(deps.storage, info.sender.as_str(), &name, msg)?;
     let addr = ADO_ADDRESSES.load(deps.storage, name.as_str())?;
     let proxy_msg = SubMsg {
         id: 102,
@@ -198,6 +264,133 @@ fn execute_message(
         .add_attribute("recipient", name))
 }
 
+/// Generates the message to send to the named component on behalf of `sender`. Messages from the
+/// owner are sent as is, those from principals with a proxy permission are wrapped in a
+/// `ProxyReceive`.
+fn generate_proxy_msg(
+    storage: &dyn Storage,
+    sender: &str,
+    name: &str,
+    msg: Binary,
+) -> Result<Binary, ContractError> {
+    if ADOContract::default().is_contract_owner(storage, sender)? {
+        return Ok(msg);
+    }
+    let permission = PROXY_PERMISSIONS
+        .may_load(storage, (sender, name))?
+        .ok_or(ContractError::Unauthorized {})?;
+    if let Some(messages) = permission.messages {
+        ensure!(
+            messages.contains(&get_message_name(&msg)?),
+            ContractError::Unauthorized {}
+        );
+    }
+    Ok(to_binary(&AndromedaExecuteMsg::AndrReceive(
+        AndromedaMsg::ProxyReceive {
+            origin: sender.to_string(),
+            msg,
+        },
+    ))?)
+}
+
+fn execute_batch(
+    deps: DepsMut,
+    info: MessageInfo,
+    msgs: Vec<ComponentMessage>,
+) -> Result<Response, ContractError> {
+    let sender = info.sender.as_str();
+    let steps = msgs.len();
+    let mut remaining_funds = info.funds.clone();
+    let mut sub_msgs: Vec<SubMsg> = vec![];
+    let mut events: Vec<Event> = vec![];
+    for (idx, component_msg) in msgs.into_iter().enumerate() {
+        for coin in component_msg.funds.iter() {
+            deduct_funds(&mut remaining_funds, coin)?;
+        }
+        let msg = generate_proxy_msg(deps.storage, sender, &component_msg.name, component_msg.msg)?;
+        let addr = ADO_ADDRESSES.load(deps.storage, &component_msg.name)?;
+        sub_msgs.push(SubMsg {
+            id: BATCH_REPLY_ID_OFFSET + idx as u64,
+            reply_on: ReplyOn::Success,
+            msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                msg,
+                funds: component_msg.funds,
+                contract_addr: addr.to_string(),
+            }),
+            gas_limit: None,
+        });
+        events.push(
+            Event::new("batch_step")
+                .add_attribute("step", idx.to_string())
+                .add_attribute("recipient", component_msg.name),
+        );
+    }
+    ensure!(
+        remaining_funds.iter().all(|coin| coin.amount.is_zero()),
+        ContractError::InvalidFunds {
+            msg: "Funds sent must be split exactly across the batch".to_string(),
+        }
+    );
+
+    Ok(Response::new()
+        .add_submessages(sub_msgs)
+        .add_events(events)
+        .add_attribute("method", "execute_batch")
+        .add_attribute("steps", steps.to_string()))
+}
+
+fn execute_set_proxy_permission(
+    deps: DepsMut,
+    info: MessageInfo,
+    principal: String,
+    component: String,
+    messages: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    deps.api.addr_validate(&principal)?;
+    ensure!(
+        ADO_ADDRESSES.has(deps.storage, &component),
+        ContractError::InvalidComponent { name: component }
+    );
+
+    let permission = ProxyPermission {
+        principal: principal.clone(),
+        component: component.clone(),
+        messages: messages.clone(),
+    };
+    PROXY_PERMISSIONS.save(deps.storage, (&principal, &component), &permission)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_proxy_permission")
+        .add_attribute("principal", principal)
+        .add_attribute("component", component)
+        .add_attribute(
+            "messages",
+            messages.map_or("all".to_string(), |messages| messages.join(",")),
+        ))
+}
+
+fn execute_remove_proxy_permission(
+    deps: DepsMut,
+    info: MessageInfo,
+    principal: String,
+    component: String,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    PROXY_PERMISSIONS.remove(deps.storage, (&principal, &component));
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_proxy_permission")
+        .add_attribute("principal", principal)
+        .add_attribute("component", component))
+}
+
 fn has_update_address_privilege(
     storage: &dyn Storage,
     sender: &str,
@@ -227,42 +420,138 @@ fn execute_update_address(
         .add_attribute("address", addr))
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
+/// Ensures that no other component refers to `name`.
+fn ensure_not_referenced(storage: &dyn Storage, name: &str) -> Result<(), ContractError> {
+    let dependents = load_dependents(storage, name)?;
+    ensure!(
+        dependents.is_empty(),
+        ContractError::ComponentReferenced {
+            name: name.to_string(),
+            dependents: dependents.join(","),
+        }
+    );
+    Ok(())
+}
+
+fn execute_remove_component(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    ensure_not_referenced(deps.storage, &name)?;
 
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
+    remove_component_descriptor(deps.storage, &name)?;
+    ADO_ADDRESSES.remove(deps.storage, &name);
+    remove_component_proxy_permissions(deps.storage, &name)?;
 
-    let contract = ADOContract::default();
+    Ok(Response::new()
+        .add_attribute("method", "remove_component")
+        .add_attribute("name", name))
+}
 
+fn execute_rename_component(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    new_name: String,
+) -> Result<Response, ContractError> {
     ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
     );
+    ensure!(
+        !ADO_ADDRESSES.has(deps.storage, &new_name),
+        ContractError::NameAlreadyTaken {}
+    );
+    ensure_not_referenced(deps.storage, &name)?;
+
+    rename_component_descriptor(deps.storage, &name, &new_name)?;
+    let addr = ADO_ADDRESSES.load(deps.storage, &name)?;
+    ADO_ADDRESSES.remove(deps.storage, &name);
+    ADO_ADDRESSES.save(deps.storage, &new_name, &addr)?;
+    for mut permission in remove_component_proxy_permissions(deps.storage, &name)? {
+        permission.component = new_name.clone();
+        PROXY_PERMISSIONS.save(
+            deps.storage,
+            (&permission.principal, &new_name),
+            &permission,
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "rename_component")
+        .add_attribute("name", name)
+        .add_attribute("new_name", new_name))
+}
 
-    // New version has to be newer/greater than the old version
+fn execute_upgrade_component(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    code_id_key: Option<String>,
+    migrate_msg: Binary,
+) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
     ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
+        contract.is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
     );
 
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let component = load_component_descriptor(deps.storage, &name)?;
+    let addr = ADO_ADDRESSES.load(deps.storage, &name)?;
+    let code_id_key = code_id_key.unwrap_or(component.ado_type);
+    let code_id = contract.get_code_id(deps.storage, &deps.querier, &code_id_key)?;
+    update_component_ado_type(deps.storage, &name, &code_id_key)?;
 
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
+    Ok(Response::new()
+        .add_message(generate_migrate_message(&addr, code_id, migrate_msg))
+        .add_attribute("method", "upgrade_component")
+        .add_attribute("name", name)
+        .add_attribute("code_id", code_id.to_string()))
+}
 
-    Ok(Response::default())
+fn execute_upgrade_all(
+    deps: DepsMut,
+    info: MessageInfo,
+    migrate_msg: Binary,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut upgraded: Vec<String> = vec![];
+    for version in load_component_versions(deps.as_ref())? {
+        match (version.deployed_code_id, version.latest_code_id) {
+            (Some(deployed), Some(latest)) if deployed != latest => {
+                let addr = ADO_ADDRESSES.load(deps.storage, &version.name)?;
+                msgs.push(generate_migrate_message(&addr, latest, migrate_msg.clone()));
+                let (ado_type, _) = split_versioned_ado_type(&version.ado_type);
+                update_component_ado_type(deps.storage, &version.name, ado_type)?;
+                upgraded.push(version.name);
+            }
+            // Components that are up to date, not yet instantiated or of an ADO type without a
+            // latest version are skipped.
+            _ => {}
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("method", "upgrade_all")
+        .add_attribute("upgraded", upgraded.join(",")))
 }
 
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -274,6 +563,20 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
         QueryMsg::GetComponents {} => encode_binary(&query_component_descriptors(deps)?),
         QueryMsg::Config {} => encode_binary(&query_config(deps)?),
         QueryMsg::ComponentExists { name } => encode_binary(&query_component_exists(deps, name)),
+        QueryMsg::ComponentVersions {} => encode_binary(&load_component_versions(deps)?),
+        QueryMsg::ComponentReferences {} => {
+            encode_binary(&load_component_references(deps.storage)?)
+        }
+        QueryMsg::ProxyPermissions {
+            principal,
+            start_after,
+            limit,
+        } => encode_binary(&load_proxy_permissions(
+            deps.storage,
+            &principal,
+            start_after,
+            limit,
+        )?),
     }
 }
 
@@ -322,3 +625,31 @@ fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
 
     Ok(ConfigResponse { name, owner })
 }
+
+fn load_component_versions(deps: Deps) -> Result<Vec<ComponentVersion>, ContractError> {
+    let contract = ADOContract::default();
+    load_component_descriptors(deps.storage)?
+        .into_iter()
+        .map(|component| {
+            let addr = ADO_ADDRESSES.load(deps.storage, &component.name)?;
+            // The address is empty until the component's instantiation reply is handled.
+            let deployed_code_id = if addr.as_str().is_empty() {
+                None
+            } else {
+                Some(query_deployed_code_id(&deps.querier, &addr)?)
+            };
+            // The latest version is resolved without any pinned version, which may have since
+            // been deprecated. Types whose versions are all deprecated have no latest code ID.
+            let (ado_type, _) = split_versioned_ado_type(&component.ado_type);
+            let latest_code_id = contract
+                .get_code_id(deps.storage, &deps.querier, ado_type)
+                .ok();
+            Ok(ComponentVersion {
+                name: component.name,
+                ado_type: component.ado_type,
+                deployed_code_id,
+                latest_code_id,
+            })
+        })
+        .collect()
+}
@@ -1,9 +1,13 @@
-use andromeda_app::app::{AppComponent, ComponentAddress};
+use ado_base::app::parse_identifiers;
+use andromeda_app::app::{AppComponent, ComponentAddress, ComponentReference, ProxyPermission};
 use common::{
     ado_base::{AndromedaMsg, ExecuteMsg},
     error::ContractError,
 };
-use cosmwasm_std::{to_binary, Addr, Coin, CosmosMsg, Order, ReplyOn, Storage, SubMsg, WasmMsg};
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Coin, ContractInfoResponse, CosmosMsg, Order, QuerierWrapper,
+    QueryRequest, ReplyOn, Storage, SubMsg, WasmMsg, WasmQuery,
+};
 use cw_storage_plus::{Bound, Item, Map};
 
 /// Used to store the addresses of each ADO within the app
@@ -12,6 +16,11 @@ pub const ADO_ADDRESSES: Map<&str, Addr> = Map::new("ado_addresses");
 pub const ADO_DESCRIPTORS: Map<&str, AppComponent> = Map::new("ado_descriptors");
 pub const ADO_IDX: Item<u64> = Item::new("ado_idx");
 pub const APP_NAME: Item<String> = Item::new("app_name");
+/// The messages each principal may proxy to each component, by principal and component name.
+pub const PROXY_PERMISSIONS: Map<(&str, &str), ProxyPermission> = Map::new("proxy_permissions");
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
 
 // DEV NOTE: Very similar to CW721 module instantiation, possibly merge both implementations?
 pub fn add_app_component(
@@ -53,6 +62,18 @@ pub fn load_component_addresses_with_name(
     Ok(addresses)
 }
 
+pub fn load_component_descriptor(
+    storage: &dyn Storage,
+    name: &str,
+) -> Result<AppComponent, ContractError> {
+    load_component_descriptors(storage)?
+        .into_iter()
+        .find(|component| component.name == name)
+        .ok_or(ContractError::InvalidComponent {
+            name: name.to_string(),
+        })
+}
+
 pub fn load_component_descriptors(
     storage: &dyn Storage,
 ) -> Result<Vec<AppComponent>, ContractError> {
@@ -66,9 +87,130 @@ pub fn load_component_descriptors(
     Ok(descriptors)
 }
 
+/// Renames the stored descriptor of the named component.
+pub fn rename_component_descriptor(
+    storage: &mut dyn Storage,
+    name: &str,
+    new_name: &str,
+) -> Result<(), ContractError> {
+    let (idx, mut component) = find_component_descriptor(storage, name)?;
+    component.name = new_name.to_string();
+    ADO_DESCRIPTORS.save(storage, &idx, &component)?;
+    Ok(())
+}
+
+/// Sets the ADO type of the stored descriptor of the named component.
+pub fn update_component_ado_type(
+    storage: &mut dyn Storage,
+    name: &str,
+    ado_type: &str,
+) -> Result<(), ContractError> {
+    let (idx, mut component) = find_component_descriptor(storage, name)?;
+    component.ado_type = ado_type.to_string();
+    ADO_DESCRIPTORS.save(storage, &idx, &component)?;
+    Ok(())
+}
+
+pub fn remove_component_descriptor(
+    storage: &mut dyn Storage,
+    name: &str,
+) -> Result<(), ContractError> {
+    let (idx, _) = find_component_descriptor(storage, name)?;
+    ADO_DESCRIPTORS.remove(storage, &idx);
+    Ok(())
+}
+
+fn find_component_descriptor(
+    storage: &dyn Storage,
+    name: &str,
+) -> Result<(String, AppComponent), ContractError> {
+    let min = Some(Bound::inclusive("1"));
+    ADO_DESCRIPTORS
+        .range(storage, min, None, Order::Ascending)
+        .flatten()
+        .find(|(_, component)| component.name == name)
+        .ok_or(ContractError::InvalidComponent {
+            name: name.to_string(),
+        })
+}
+
+/// Lists the names of the other components that each component's instantiate message refers to.
+pub fn load_component_references(
+    storage: &dyn Storage,
+) -> Result<Vec<ComponentReference>, ContractError> {
+    let components = load_component_descriptors(storage)?;
+    let references = components
+        .iter()
+        .map(|component| {
+            let mut references: Vec<String> = vec![];
+            for identifier in parse_identifiers(&component.instantiate_msg) {
+                if identifier != component.name
+                    && !references.contains(&identifier)
+                    && components.iter().any(|other| other.name == identifier)
+                {
+                    references.push(identifier);
+                }
+            }
+            ComponentReference {
+                name: component.name.clone(),
+                references,
+            }
+        })
+        .collect();
+    Ok(references)
+}
+
+/// Lists the components that refer to `name`.
+pub fn load_dependents(storage: &dyn Storage, name: &str) -> Result<Vec<String>, ContractError> {
+    Ok(load_component_references(storage)?
+        .into_iter()
+        .filter(|component| component.references.iter().any(|r| r == name))
+        .map(|component| component.name)
+        .collect())
+}
+
+pub fn load_proxy_permissions(
+    storage: &dyn Storage,
+    principal: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<ProxyPermission>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let permissions = PROXY_PERMISSIONS
+        .prefix(principal)
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, permission)| permission))
+        .collect::<Result<Vec<ProxyPermission>, _>>()?;
+
+    Ok(permissions)
+}
+
+/// Removes the proxy permissions for the named component and returns them.
+pub fn remove_component_proxy_permissions(
+    storage: &mut dyn Storage,
+    component: &str,
+) -> Result<Vec<ProxyPermission>, ContractError> {
+    let permissions = PROXY_PERMISSIONS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, permission)| permission))
+        .filter(
+            |permission| !matches!(permission, Ok(permission) if permission.component != component),
+        )
+        .collect::<Result<Vec<ProxyPermission>, _>>()?;
+    for permission in permissions.iter() {
+        PROXY_PERMISSIONS.remove(storage, (&permission.principal, component));
+    }
+    Ok(permissions)
+}
+
+/// Generates a message proposing `owner` as the new owner of the component at `addr`. The
+/// proposed owner must then send `AcceptOwnership` to the component directly.
 pub fn generate_ownership_message(addr: Addr, owner: &str) -> Result<SubMsg, ContractError> {
-    let msg = to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::UpdateOwner {
+    let msg = to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::ProposeOwnership {
         address: owner.to_string(),
+        expiration: None,
     }))?;
     Ok(SubMsg {
         id: 101,
@@ -97,3 +239,22 @@ pub fn generate_assign_app_message(addr: &Addr, app_addr: &str) -> Result<SubMsg
         gas_limit: None,
     })
 }
+
+/// Queries the code ID of the contract at `addr`.
+pub fn query_deployed_code_id(querier: &QuerierWrapper, addr: &Addr) -> Result<u64, ContractError> {
+    let res: ContractInfoResponse =
+        querier.query(&QueryRequest::Wasm(WasmQuery::ContractInfo {
+            contract_addr: addr.to_string(),
+        }))?;
+    Ok(res.code_id)
+}
+
+/// Generates a message migrating the component at `addr` to `new_code_id`. The app must be the
+/// admin of the component.
+pub fn generate_migrate_message(addr: &Addr, new_code_id: u64, msg: Binary) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Migrate {
+        contract_addr: addr.to_string(),
+        new_code_id,
+        msg,
+    })
+}
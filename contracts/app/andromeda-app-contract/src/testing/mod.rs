@@ -1,17 +1,20 @@
 use crate::{
     contract::*,
-    state::{ADO_ADDRESSES, ADO_DESCRIPTORS},
+    state::{ADO_ADDRESSES, ADO_DESCRIPTORS, PROXY_PERMISSIONS},
+};
+use andromeda_app::app::{
+    AppComponent, ComponentMessage, ComponentReference, ComponentVersion, ExecuteMsg,
+    InstantiateMsg, ProxyPermission, QueryMsg,
 };
-use andromeda_app::app::{AppComponent, ExecuteMsg, InstantiateMsg};
 use andromeda_testing::{
     reply::MsgInstantiateContractResponse, testing::mock_querier::mock_dependencies_custom,
 };
 use common::{ado_base::AndromedaMsg, error::ContractError};
 use cosmwasm_std::{
-    attr,
-    testing::{mock_dependencies, mock_env, mock_info},
-    to_binary, Addr, CosmosMsg, Empty, Event, Reply, ReplyOn, Response, StdError, SubMsg,
-    SubMsgResponse, SubMsgResult, WasmMsg,
+    attr, coin, coins, from_binary,
+    testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR},
+    to_binary, Addr, Binary, CosmosMsg, DepsMut, Empty, Event, Reply, ReplyOn, Response, StdError,
+    SubMsg, SubMsgResponse, SubMsgResult, WasmMsg,
 };
 use prost::Message;
 
@@ -55,7 +58,7 @@ fn test_instantiation() {
             msg: to_binary(&true).unwrap(),
             funds: vec![],
             label: "Instantiate: cw721".to_string(),
-            admin: Some("creator".to_string()),
+            admin: Some(MOCK_CONTRACT_ADDR.to_string()),
         }),
         reply_on: ReplyOn::Always,
         gas_limit: None,
@@ -195,7 +198,7 @@ fn test_add_app_component() {
             msg: to_binary(&true).unwrap(),
             funds: vec![],
             label: "Instantiate: cw721".to_string(),
-            admin: Some("creator".to_string()),
+            admin: Some(MOCK_CONTRACT_ADDR.to_string()),
         }),
         reply_on: ReplyOn::Always,
         gas_limit: None,
@@ -317,8 +320,9 @@ fn test_claim_ownership_all() {
         id: 101,
         msg: CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: "anchoraddress".to_string(),
-            msg: to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::UpdateOwner {
+            msg: to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::ProposeOwnership {
                 address: "creator".to_string(),
+                expiration: None,
             }))
             .unwrap(),
             funds: vec![],
@@ -330,8 +334,9 @@ fn test_claim_ownership_all() {
         id: 101,
         msg: CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: "tokenaddress".to_string(),
-            msg: to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::UpdateOwner {
+            msg: to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::ProposeOwnership {
                 address: "creator".to_string(),
+                expiration: None,
             }))
             .unwrap(),
             funds: vec![],
@@ -384,8 +389,9 @@ fn test_claim_ownership() {
         id: 101,
         msg: CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: "tokenaddress".to_string(),
-            msg: to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::UpdateOwner {
+            msg: to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::ProposeOwnership {
                 address: "creator".to_string(),
+                expiration: None,
             }))
             .unwrap(),
             funds: vec![],
@@ -648,3 +654,611 @@ fn test_reply_assign_app() {
         ADO_ADDRESSES.load(deps.as_ref().storage, "token").unwrap()
     );
 }
+
+/// Instantiates an app with a "token" cw721 and a "receipt" component, both deployed.
+fn instantiate_with_deployed_components(deps: DepsMut) {
+    let inst_msg = InstantiateMsg {
+        app: vec![
+            AppComponent {
+                name: "token".to_string(),
+                ado_type: "cw721".to_string(),
+                instantiate_msg: to_binary(&true).unwrap(),
+            },
+            AppComponent {
+                name: "receipt".to_string(),
+                ado_type: "receipt".to_string(),
+                instantiate_msg: to_binary(&true).unwrap(),
+            },
+        ],
+        name: String::from("Some App"),
+        primitive_contract: String::from("primitive_contract"),
+    };
+    instantiate(deps, mock_env(), mock_info("creator", &[]), inst_msg).unwrap();
+}
+
+#[test]
+fn test_upgrade_component() {
+    let mut deps = mock_dependencies_custom(&[]);
+    instantiate_with_deployed_components(deps.as_mut());
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "token",
+            &Addr::unchecked("tokenaddress"),
+        )
+        .unwrap();
+
+    let msg = ExecuteMsg::UpgradeComponent {
+        name: "token".to_string(),
+        code_id_key: None,
+        migrate_msg: to_binary(&true).unwrap(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+    let expected = Response::new()
+        .add_message(WasmMsg::Migrate {
+            contract_addr: "tokenaddress".to_string(),
+            new_code_id: 4,
+            msg: to_binary(&true).unwrap(),
+        })
+        .add_attribute("method", "upgrade_component")
+        .add_attribute("name", "token")
+        .add_attribute("code_id", "4");
+    assert_eq!(expected, res);
+
+    // The code ID can be taken from a different key.
+    let msg = ExecuteMsg::UpgradeComponent {
+        name: "token".to_string(),
+        code_id_key: Some("rates".to_string()),
+        migrate_msg: to_binary(&true).unwrap(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+    assert_eq!(
+        CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: "tokenaddress".to_string(),
+            new_code_id: 2,
+            msg: to_binary(&true).unwrap(),
+        }),
+        res.messages[0].msg
+    );
+    // The key is recorded as the component's ADO type.
+    let components: Vec<AppComponent> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetComponents {}).unwrap())
+            .unwrap();
+    assert_eq!("rates", components[0].ado_type);
+
+    let msg = ExecuteMsg::UpgradeComponent {
+        name: "unknown".to_string(),
+        code_id_key: None,
+        migrate_msg: to_binary(&true).unwrap(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap_err();
+    assert_eq!(
+        ContractError::InvalidComponent {
+            name: "unknown".to_string()
+        },
+        err
+    );
+}
+
+#[test]
+fn test_upgrade_all() {
+    let mut deps = mock_dependencies_custom(&[]);
+    instantiate_with_deployed_components(deps.as_mut());
+
+    // Neither component has been instantiated yet.
+    let res: Vec<ComponentVersion> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::ComponentVersions {}).unwrap())
+            .unwrap();
+    assert_eq!(None, res[0].deployed_code_id);
+
+    for (name, addr) in [("token", "tokenaddress"), ("receipt", "receiptaddress")] {
+        ADO_ADDRESSES
+            .save(deps.as_mut().storage, name, &Addr::unchecked(addr))
+            .unwrap();
+    }
+
+    let res: Vec<ComponentVersion> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::ComponentVersions {}).unwrap())
+            .unwrap();
+    assert_eq!(
+        vec![
+            ComponentVersion {
+                name: "token".to_string(),
+                ado_type: "cw721".to_string(),
+                deployed_code_id: Some(1),
+                latest_code_id: Some(4),
+            },
+            ComponentVersion {
+                name: "receipt".to_string(),
+                ado_type: "receipt".to_string(),
+                deployed_code_id: Some(1),
+                latest_code_id: Some(1),
+            },
+        ],
+        res
+    );
+
+    let msg = ExecuteMsg::UpgradeAll {
+        migrate_msg: to_binary(&true).unwrap(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    // The receipt component is already up to date.
+    let res = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+    let expected = Response::new()
+        .add_message(WasmMsg::Migrate {
+            contract_addr: "tokenaddress".to_string(),
+            new_code_id: 4,
+            msg: to_binary(&true).unwrap(),
+        })
+        .add_attribute("method", "upgrade_all")
+        .add_attribute("upgraded", "token");
+    assert_eq!(expected, res);
+}
+
+#[test]
+fn test_proxy_message_paused() {
+    let mut deps = mock_dependencies_custom(&[]);
+    instantiate_with_deployed_components(deps.as_mut());
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "token",
+            &Addr::unchecked("tokenaddress"),
+        )
+        .unwrap();
+    let info = mock_info("creator", &[]);
+    let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause { messages: None });
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::ProxyMessage {
+        name: "token".to_string(),
+        msg: to_binary(&AndromedaMsg::Receive(None)).unwrap(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        ContractError::Paused {
+            msg: "all".to_string()
+        },
+        err
+    );
+
+    // The app can still be administered.
+    let msg = ExecuteMsg::UpdateAddress {
+        name: "token".to_string(),
+        addr: "newtokenaddress".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+}
+
+#[test]
+fn test_proxy_message_with_permission() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app: vec![],
+        name: String::from("Some App"),
+        primitive_contract: String::from("primitive_contract"),
+    };
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "token",
+            &Addr::unchecked("tokenaddress"),
+        )
+        .unwrap();
+    instantiate(deps.as_mut(), mock_env(), info.clone(), inst_msg).unwrap();
+
+    let component_msg = to_binary(&AndromedaMsg::Receive(None)).unwrap();
+    let proxy_msg = ExecuteMsg::ProxyMessage {
+        name: "token".to_string(),
+        msg: component_msg.clone(),
+    };
+    let member = mock_info("member", &[]);
+    let err = execute(deps.as_mut(), mock_env(), member.clone(), proxy_msg.clone()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let msg = ExecuteMsg::SetProxyPermission {
+        principal: "member".to_string(),
+        component: "token".to_string(),
+        messages: Some(vec!["update_operators".to_string()]),
+    };
+    let err = execute(deps.as_mut(), mock_env(), member.clone(), msg).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let msg = ExecuteMsg::SetProxyPermission {
+        principal: "member".to_string(),
+        component: "unknown".to_string(),
+        messages: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        ContractError::InvalidComponent {
+            name: "unknown".to_string()
+        },
+        err
+    );
+
+    // Only the listed messages may be proxied.
+    let msg = ExecuteMsg::SetProxyPermission {
+        principal: "member".to_string(),
+        component: "token".to_string(),
+        messages: Some(vec!["update_operators".to_string()]),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let err = execute(deps.as_mut(), mock_env(), member.clone(), proxy_msg.clone()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let msg = ExecuteMsg::SetProxyPermission {
+        principal: "member".to_string(),
+        component: "token".to_string(),
+        messages: Some(vec!["receive".to_string()]),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let res = execute(deps.as_mut(), mock_env(), member.clone(), proxy_msg.clone()).unwrap();
+    assert_eq!(
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "tokenaddress".to_string(),
+            msg: to_binary(&ExecuteMsg::AndrReceive(AndromedaMsg::ProxyReceive {
+                origin: "member".to_string(),
+                msg: component_msg,
+            }))
+            .unwrap(),
+            funds: vec![],
+        }),
+        res.messages[0].msg
+    );
+
+    let res: Vec<ProxyPermission> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProxyPermissions {
+                principal: "member".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        vec![ProxyPermission {
+            principal: "member".to_string(),
+            component: "token".to_string(),
+            messages: Some(vec!["receive".to_string()]),
+        }],
+        res
+    );
+
+    let msg = ExecuteMsg::RemoveProxyPermission {
+        principal: "member".to_string(),
+        component: "token".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let err = execute(deps.as_mut(), mock_env(), member, proxy_msg).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+}
+
+/// Instantiates an app with a "token" component and an "auction" component referencing it.
+fn instantiate_with_referencing_components(deps: DepsMut) {
+    let inst_msg = InstantiateMsg {
+        app: vec![
+            AppComponent {
+                name: "token".to_string(),
+                ado_type: "cw721".to_string(),
+                instantiate_msg: to_binary(&true).unwrap(),
+            },
+            AppComponent {
+                name: "auction".to_string(),
+                ado_type: "auction".to_string(),
+                instantiate_msg: Binary::from(br#"{"token_address":{"identifier": "token"}}"#),
+            },
+        ],
+        name: String::from("Some App"),
+        primitive_contract: String::from("primitive_contract"),
+    };
+    instantiate(deps, mock_env(), mock_info("creator", &[]), inst_msg).unwrap();
+}
+
+#[test]
+fn test_component_references() {
+    let mut deps = mock_dependencies_custom(&[]);
+    instantiate_with_referencing_components(deps.as_mut());
+
+    let res: Vec<ComponentReference> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::ComponentReferences {}).unwrap())
+            .unwrap();
+    assert_eq!(
+        vec![
+            ComponentReference {
+                name: "token".to_string(),
+                references: vec![],
+            },
+            ComponentReference {
+                name: "auction".to_string(),
+                references: vec!["token".to_string()],
+            },
+        ],
+        res
+    );
+}
+
+#[test]
+fn test_remove_component() {
+    let mut deps = mock_dependencies_custom(&[]);
+    instantiate_with_referencing_components(deps.as_mut());
+    let info = mock_info("creator", &[]);
+    let msg = ExecuteMsg::SetProxyPermission {
+        principal: "member".to_string(),
+        component: "auction".to_string(),
+        messages: None,
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::RemoveComponent {
+        name: "token".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(
+        ContractError::ComponentReferenced {
+            name: "token".to_string(),
+            dependents: "auction".to_string(),
+        },
+        err
+    );
+
+    let remove_auction = ExecuteMsg::RemoveComponent {
+        name: "auction".to_string(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), info.clone(), remove_auction).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_attribute("method", "remove_component")
+            .add_attribute("name", "auction"),
+        res
+    );
+    assert!(!ADO_ADDRESSES.has(deps.as_ref().storage, "auction"));
+    assert!(PROXY_PERMISSIONS
+        .may_load(deps.as_ref().storage, ("member", "auction"))
+        .unwrap()
+        .is_none());
+
+    // The token is no longer referenced.
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let res: Vec<AppComponent> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetComponents {}).unwrap())
+            .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_rename_component() {
+    let mut deps = mock_dependencies_custom(&[]);
+    instantiate_with_referencing_components(deps.as_mut());
+    let info = mock_info("creator", &[]);
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "auction",
+            &Addr::unchecked("auctionaddress"),
+        )
+        .unwrap();
+    let msg = ExecuteMsg::SetProxyPermission {
+        principal: "member".to_string(),
+        component: "auction".to_string(),
+        messages: None,
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::RenameComponent {
+        name: "token".to_string(),
+        new_name: "nft".to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        ContractError::ComponentReferenced {
+            name: "token".to_string(),
+            dependents: "auction".to_string(),
+        },
+        err
+    );
+
+    let msg = ExecuteMsg::RenameComponent {
+        name: "auction".to_string(),
+        new_name: "token".to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(ContractError::NameAlreadyTaken {}, err);
+
+    let msg = ExecuteMsg::RenameComponent {
+        name: "auction".to_string(),
+        new_name: "sale".to_string(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_attribute("method", "rename_component")
+            .add_attribute("name", "auction")
+            .add_attribute("new_name", "sale"),
+        res
+    );
+
+    let addr: String = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetAddress {
+                name: "sale".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!("auctionaddress", addr);
+    assert!(!ADO_ADDRESSES.has(deps.as_ref().storage, "auction"));
+    assert_eq!(
+        "sale",
+        ADO_DESCRIPTORS
+            .load(deps.as_ref().storage, "2")
+            .unwrap()
+            .name
+    );
+    assert_eq!(
+        ProxyPermission {
+            principal: "member".to_string(),
+            component: "sale".to_string(),
+            messages: None,
+        },
+        PROXY_PERMISSIONS
+            .load(deps.as_ref().storage, ("member", "sale"))
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_execute_batch() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let inst_msg = InstantiateMsg {
+        app: vec![],
+        name: String::from("Some App"),
+        primitive_contract: String::from("primitive_contract"),
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        inst_msg,
+    )
+    .unwrap();
+    for (name, addr) in [("token", "tokenaddress"), ("sale", "saleaddress")] {
+        ADO_ADDRESSES
+            .save(deps.as_mut().storage, name, &Addr::unchecked(addr))
+            .unwrap();
+    }
+
+    let msgs = vec![
+        ComponentMessage {
+            name: "token".to_string(),
+            msg: to_binary(&"mint").unwrap(),
+            funds: vec![],
+        },
+        ComponentMessage {
+            name: "sale".to_string(),
+            msg: to_binary(&"start_sale").unwrap(),
+            funds: coins(100, "uusd"),
+        },
+    ];
+    let msg = ExecuteMsg::ExecuteBatch { msgs: msgs.clone() };
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &coins(150, "uusd")),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidFunds {
+            msg: "Funds sent must be split exactly across the batch".to_string(),
+        },
+        err
+    );
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[coin(100, "uluna")]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::InsufficientFunds {}, err);
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &coins(100, "uusd")),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &coins(100, "uusd")),
+        msg,
+    )
+    .unwrap();
+    let expected = Response::new()
+        .add_submessages(vec![
+            SubMsg::reply_on_success(
+                WasmMsg::Execute {
+                    contract_addr: "tokenaddress".to_string(),
+                    msg: to_binary(&"mint").unwrap(),
+                    funds: vec![],
+                },
+                1_000_000,
+            ),
+            SubMsg::reply_on_success(
+                WasmMsg::Execute {
+                    contract_addr: "saleaddress".to_string(),
+                    msg: to_binary(&"start_sale").unwrap(),
+                    funds: coins(100, "uusd"),
+                },
+                1_000_001,
+            ),
+        ])
+        .add_events(vec![
+            Event::new("batch_step")
+                .add_attribute("step", "0")
+                .add_attribute("recipient", "token"),
+            Event::new("batch_step")
+                .add_attribute("step", "1")
+                .add_attribute("recipient", "sale"),
+        ])
+        .add_attribute("method", "execute_batch")
+        .add_attribute("steps", "2");
+    assert_eq!(expected, res);
+
+    let step_reply = Reply {
+        id: 1_000_001,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(to_binary(&true).unwrap()),
+        }),
+    };
+    let res = reply(deps.as_mut(), mock_env(), step_reply).unwrap();
+    assert_eq!(
+        Response::new().add_event(
+            Event::new("batch_step_result")
+                .add_attribute("step", "1")
+                .add_attribute("result", "success")
+                .add_attribute("data", to_binary(&true).unwrap().to_base64())
+        ),
+        res
+    );
+}
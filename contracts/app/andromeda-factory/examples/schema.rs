@@ -3,7 +3,10 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
-use andromeda_app::factory::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use andromeda_app::app::AppTemplate;
+use andromeda_app::factory::{
+    CodeIdVersionsResponse, DeploymentsResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
+};
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -14,4 +17,8 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+
+    export_schema(&schema_for!(DeploymentsResponse), &out_dir);
+    export_schema(&schema_for!(CodeIdVersionsResponse), &out_dir);
+    export_schema(&schema_for!(AppTemplate), &out_dir);
 }
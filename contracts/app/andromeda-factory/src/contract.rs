@@ -1,26 +1,44 @@
 use crate::{
-    reply::{on_token_creation_reply, REPLY_CREATE_TOKEN},
+    reply::{on_ado_creation_reply, REPLY_CREATE_ADO},
     state::{
-        is_address_defined, is_creator, read_address, read_code_id, store_address, store_code_id,
+        is_creator, load_app_template_names, load_code_id_versions, load_deployments,
+        parse_version, read_address, read_code_id, set_code_id_deprecated, store_address,
+        store_code_id, store_code_id_version, DeploymentFilter, PendingDeployment, APP_TEMPLATES,
+        LATEST_VERSION, PENDING_DEPLOYMENT,
     },
+    template::{render_app_template, validate_app_template},
 };
 use ado_base::state::ADOContract;
-use andromeda_app::factory::{AddressResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use andromeda_app::app::{AppTemplate, InstantiateMsg as AppInstantiateMsg, TemplateParameter};
+use andromeda_app::factory::{
+    AddressResponse, CodeIdVersionsResponse, DeploymentsResponse, ExecuteMsg, InstantiateMsg,
+    MigrateMsg, QueryMsg,
+};
 use common::{
-    ado_base::{AndromedaQuery, InstantiateMsg as BaseInstantiateMsg},
+    ado_base::{
+        ado_type::split_versioned_ado_type, AndromedaQuery, InstantiateMsg as BaseInstantiateMsg,
+    },
     encode_binary,
     error::ContractError,
     parse_message,
 };
 use cosmwasm_std::{
-    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+    attr, ensure, entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, ReplyOn,
+    Response, StdError, SubMsg, WasmMsg,
 };
-use cw2::{get_contract_version, set_contract_version};
-use semver::Version;
+use cw2::set_contract_version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-factory";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &[
+    "add_app_template",
+    "remove_app_template",
+    "update_address",
+    "update_code_id",
+    "set_code_id_deprecated",
+];
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -30,6 +48,7 @@ pub fn instantiate(
     _msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -46,7 +65,7 @@ pub fn instantiate(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.result.is_err() {
         return Err(ContractError::Std(StdError::generic_err(
             msg.result.unwrap_err(),
@@ -54,7 +73,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     }
 
     match msg.id {
-        REPLY_CREATE_TOKEN => on_token_creation_reply(deps, msg),
+        REPLY_CREATE_ADO => on_ado_creation_reply(deps, env, msg),
         _ => Err(ContractError::InvalidReplyId {}),
     }
 }
@@ -66,8 +85,30 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
-        ExecuteMsg::Create { symbol, name } => create(deps, env, info, name, symbol),
+        ExecuteMsg::Create {
+            code_id_key,
+            instantiate_msg,
+        } => create(deps, env, info, code_id_key, instantiate_msg),
+        ExecuteMsg::CreateApp {
+            template,
+            name,
+            primitive_contract,
+            parameters,
+        } => execute_create_app(
+            deps,
+            env,
+            info,
+            template,
+            name,
+            primitive_contract,
+            parameters,
+        ),
+        ExecuteMsg::AddAppTemplate { name, template } => {
+            execute_add_app_template(deps, info, name, template)
+        }
+        ExecuteMsg::RemoveAppTemplate { name } => execute_remove_app_template(deps, info, name),
         ExecuteMsg::UpdateAddress {
             symbol,
             new_address,
@@ -75,7 +116,13 @@ pub fn execute(
         ExecuteMsg::UpdateCodeId {
             code_id_key,
             code_id,
-        } => add_update_code_id(deps, env, info, code_id_key, code_id),
+            version,
+        } => add_update_code_id(deps, env, info, code_id_key, code_id, version),
+        ExecuteMsg::SetCodeIdDeprecated {
+            code_id_key,
+            version,
+            deprecated,
+        } => execute_set_code_id_deprecated(deps, info, code_id_key, version, deprecated),
         ExecuteMsg::AndrReceive(msg) => {
             ADOContract::default().execute(deps, env, info, msg, execute)
         }
@@ -85,89 +132,99 @@ pub fn execute(
 pub fn create(
     deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
-    _name: String,
-    symbol: String,
+    info: MessageInfo,
+    code_id_key: String,
+    instantiate_msg: Binary,
 ) -> Result<Response, ContractError> {
-    //let config = read_config(deps.storage)?;
-
-    ensure!(
-        !is_address_defined(deps.storage, symbol)?,
-        ContractError::SymbolInUse {}
-    );
-    //TODO: make this work with new cw721
-    Ok(Response::new())
-
-    //Assign Code IDs to Modules
-    /*let updated_modules: Vec<ModuleDefinition> = modules
-        .iter()
-        .map(|m| match m {
-            ModuleDefinition::Whitelist {
-                address,
-                operators,
-                code_id: _,
-            } => ModuleDefinition::Whitelist {
-                address: address.clone(),
-                operators: operators.clone(),
-                code_id: Some(read_code_id(deps.storage, "address_list".to_string()).unwrap()),
-            },
-            ModuleDefinition::Blacklist {
-                address,
-                operators,
-                code_id: _,
-            } => ModuleDefinition::Blacklist {
-                address: address.clone(),
-                operators: operators.clone(),
-                code_id: Some(read_code_id(deps.storage, "address_list".to_string()).unwrap()),
-            },
-            ModuleDefinition::Receipt {
-                address,
-                operators,
-                code_id: _,
-            } => ModuleDefinition::Receipt {
-                address: address.clone(),
-                operators: operators.clone(),
-                code_id: Some(read_code_id(deps.storage, "receipt".to_string()).unwrap()),
-            },
-            _ => m.clone(),
-        })
-        .collect();
-
-    let token_inst_msg = TokenInstantiateMsg {
-        name: name.to_string(),
-        symbol: symbol.to_string(),
-        minter: info.sender.to_string(),
-        modules: updated_modules,
-    };
-    // [TOK-01 Validation Process]
-    let validation = token_inst_msg.validate();
-    match validation {
-        Ok(true) => {}
-        Err(error) => panic!("{:?}", error),
-        _ => {}
-    };
-
-    let inst_msg = WasmMsg::Instantiate {
-        admin: Some(info.sender.to_string()),
-        code_id: read_code_id(deps.storage, "token".to_string())?,
-        funds: vec![],
-        label: String::from("Address list instantiation"),
-        msg: encode_binary(&token_inst_msg)?,
-    };
+    let code_id = read_code_id(deps.storage, &code_id_key)?;
+    let creator = info.sender.to_string();
+    let (ado_type, _) = split_versioned_ado_type(&code_id_key);
+    PENDING_DEPLOYMENT.save(
+        deps.storage,
+        &PendingDeployment {
+            creator: creator.clone(),
+            ado_type: ado_type.to_string(),
+            code_id,
+        },
+    )?;
 
     let msg = SubMsg {
-        msg: inst_msg.into(),
+        id: REPLY_CREATE_ADO,
+        reply_on: ReplyOn::Success,
+        msg: WasmMsg::Instantiate {
+            admin: Some(creator.clone()),
+            code_id,
+            msg: instantiate_msg,
+            funds: info.funds,
+            label: format!("Instantiate: {}", code_id_key),
+        }
+        .into(),
         gas_limit: None,
-        id: REPLY_CREATE_TOKEN,
-        reply_on: ReplyOn::Always,
     };
 
     Ok(Response::new().add_submessage(msg).add_attributes(vec![
         attr("action", "create"),
+        attr("code_id_key", code_id_key),
+        attr("code_id", code_id.to_string()),
+        attr("creator", creator),
+    ]))
+}
+
+fn execute_create_app(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    template: String,
+    name: String,
+    primitive_contract: String,
+    parameters: Vec<TemplateParameter>,
+) -> Result<Response, ContractError> {
+    let app_template = APP_TEMPLATES.load(deps.storage, &template)?;
+    let components = render_app_template(deps.api, app_template, &parameters)?;
+    let instantiate_msg = to_binary(&AppInstantiateMsg {
+        app: components,
+        name,
+        primitive_contract,
+    })?;
+
+    Ok(create(deps, env, info, "app".to_string(), instantiate_msg)?
+        .add_attribute("template", template))
+}
+
+fn execute_add_app_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    template: AppTemplate,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    validate_app_template(&template)?;
+    APP_TEMPLATES.save(deps.storage, &name, &template)?;
+
+    Ok(
+        Response::new()
+            .add_attributes(vec![attr("action", "add_app_template"), attr("name", name)]),
+    )
+}
+
+fn execute_remove_app_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    APP_TEMPLATES.remove(deps.storage, &name);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "remove_app_template"),
         attr("name", name),
-        attr("symbol", symbol),
-        attr("owner", info.sender.to_string()),
-    ]))*/
+    ]))
 }
 
 pub fn update_address(
@@ -194,56 +251,56 @@ pub fn add_update_code_id(
     info: MessageInfo,
     code_id_key: String,
     code_id: u64,
+    version: Option<String>,
 ) -> Result<Response, ContractError> {
     ensure!(
         ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
-    store_code_id(deps.storage, &code_id_key, code_id)?;
-
-    Ok(Response::default().add_attributes(vec![
+    let mut response = Response::default().add_attributes(vec![
         attr("action", "add_update_code_id"),
-        attr("code_id_key", code_id_key),
+        attr("code_id_key", code_id_key.clone()),
         attr("code_id", code_id.to_string()),
-    ]))
-}
-
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
+    ]);
+    match version {
+        Some(version) => {
+            let version = parse_version(&version)?;
+            store_code_id_version(deps.storage, &code_id_key, &version, code_id)?;
+            response = response.add_attribute("version", version.to_string());
         }
-    );
+        None => store_code_id(deps.storage, &code_id_key, code_id)?,
+    }
 
-    // New version has to be newer/greater than the old version
+    Ok(response)
+}
+
+fn execute_set_code_id_deprecated(
+    deps: DepsMut,
+    info: MessageInfo,
+    code_id_key: String,
+    version: String,
+    deprecated: bool,
+) -> Result<Response, ContractError> {
     ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
     );
+    let version = parse_version(&version)?;
+    set_code_id_deprecated(deps.storage, &code_id_key, &version, deprecated)?;
 
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "set_code_id_deprecated"),
+        attr("code_id_key", code_id_key),
+        attr("version", version.to_string()),
+        attr("deprecated", deprecated.to_string()),
+    ]))
 }
 
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -251,6 +308,46 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
     match msg {
         QueryMsg::GetAddress { symbol } => encode_binary(&query_address(deps, symbol)?),
         QueryMsg::CodeId { key } => encode_binary(&query_code_id(deps, key)?),
+        QueryMsg::CodeIdVersions {
+            code_id_key,
+            start_after,
+            limit,
+        } => encode_binary(&query_code_id_versions(
+            deps,
+            code_id_key,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::AppTemplate { name } => encode_binary(&APP_TEMPLATES.load(deps.storage, &name)?),
+        QueryMsg::AppTemplates { start_after, limit } => {
+            encode_binary(&load_app_template_names(deps.storage, start_after, limit)?)
+        }
+        QueryMsg::Deployments { start_after, limit } => encode_binary(&query_deployments(
+            deps,
+            DeploymentFilter::All,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::DeploymentsByCreator {
+            creator,
+            start_after,
+            limit,
+        } => encode_binary(&query_deployments(
+            deps,
+            DeploymentFilter::Creator(creator),
+            start_after,
+            limit,
+        )?),
+        QueryMsg::DeploymentsByType {
+            ado_type,
+            start_after,
+            limit,
+        } => encode_binary(&query_deployments(
+            deps,
+            DeploymentFilter::AdoType(ado_type),
+            start_after,
+            limit,
+        )?),
         QueryMsg::AndrQuery(msg) => handle_andromeda_query(deps, env, msg),
     }
 }
@@ -279,19 +376,50 @@ fn query_code_id(deps: Deps, key: String) -> Result<u64, ContractError> {
     Ok(code_id)
 }
 
+fn query_code_id_versions(
+    deps: Deps,
+    code_id_key: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<CodeIdVersionsResponse, ContractError> {
+    Ok(CodeIdVersionsResponse {
+        latest: LATEST_VERSION.may_load(deps.storage, &code_id_key)?,
+        versions: load_code_id_versions(deps.storage, &code_id_key, start_after, limit)?,
+    })
+}
+
+fn query_deployments(
+    deps: Deps,
+    filter: DeploymentFilter,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<DeploymentsResponse, ContractError> {
+    Ok(DeploymentsResponse {
+        deployments: load_deployments(deps.storage, filter, start_after, limit)?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::state::{CODE_ID, SYM_ADDRESS};
 
     use super::*;
-    use andromeda_testing::testing::mock_querier::mock_dependencies_custom;
+    use andromeda_app::app::{AppTemplate, InstantiateMsg as AppInstantiateMsg, TemplateParameter};
+    use andromeda_app::{
+        app::{AppComponent, TemplateComponent},
+        factory::{CodeIdVersion, Deployment},
+    };
+    use andromeda_testing::{
+        reply::MsgInstantiateContractResponse, testing::mock_querier::mock_dependencies_custom,
+    };
+    use common::ado_base::{AndromedaMsg, ExecuteMsg as BaseExecuteMsg};
     use cosmwasm_std::{
         from_binary,
         testing::{mock_dependencies, mock_env, mock_info},
+        to_binary, SubMsgResponse, SubMsgResult,
     };
+    use prost::Message;
 
-    //static TOKEN_CODE_ID: u64 = 0;
-    //const TOKEN_NAME: &str = "test";
     const TOKEN_SYMBOL: &str = "TT";
 
     #[test]
@@ -305,81 +433,407 @@ mod tests {
         assert_eq!(0, res.messages.len());
     }
 
-    /*#[test]
+    fn create_ado(deps: DepsMut, creator: &str, code_id_key: &str) {
+        let msg = ExecuteMsg::Create {
+            code_id_key: code_id_key.to_string(),
+            instantiate_msg: to_binary(&true).unwrap(),
+        };
+        execute(deps, mock_env(), mock_info(creator, &[]), msg).unwrap();
+    }
+
+    fn mock_reply(address: &str) -> Reply {
+        let instantiate_reply = MsgInstantiateContractResponse {
+            contract_address: address.to_string(),
+            data: vec![],
+        };
+        let mut encoded_instantiate_reply =
+            Vec::<u8>::with_capacity(instantiate_reply.encoded_len());
+        instantiate_reply
+            .encode(&mut encoded_instantiate_reply)
+            .unwrap();
+        Reply {
+            id: REPLY_CREATE_ADO,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                data: Some(encoded_instantiate_reply.into()),
+                events: vec![],
+            }),
+        }
+    }
+
+    #[test]
     fn test_create() {
-        let mut deps = mock_dependencies(&[]);
-        let env = mock_env();
-        let info = mock_info("creator", &[]);
+        let mut deps = mock_dependencies();
+        let info = mock_info("owner", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
 
-        let init_msg = InstantiateMsg {};
+        let msg = ExecuteMsg::Create {
+            code_id_key: "cw721".to_string(),
+            instantiate_msg: to_binary(&true).unwrap(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Std(StdError::NotFound { .. })));
 
-        let res = instantiate(deps.as_mut(), env, info.clone(), init_msg).unwrap();
-        assert_eq!(0, res.messages.len());
+        let update_msg = ExecuteMsg::UpdateCodeId {
+            code_id_key: "cw721".to_string(),
+            code_id: 4,
+            version: None,
+        };
+        execute(deps.as_mut(), mock_env(), info, update_msg).unwrap();
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let expected = Response::new()
+            .add_submessage(SubMsg {
+                id: REPLY_CREATE_ADO,
+                reply_on: ReplyOn::Success,
+                msg: WasmMsg::Instantiate {
+                    admin: Some("creator".to_string()),
+                    code_id: 4,
+                    msg: to_binary(&true).unwrap(),
+                    funds: vec![],
+                    label: "Instantiate: cw721".to_string(),
+                }
+                .into(),
+                gas_limit: None,
+            })
+            .add_attributes(vec![
+                attr("action", "create"),
+                attr("code_id_key", "cw721"),
+                attr("code_id", "4"),
+                attr("creator", "creator"),
+            ]);
+        assert_eq!(expected, res);
+
+        // The creator is proposed as the owner of the ADO instantiated by the factory.
+        let res = reply(deps.as_mut(), mock_env(), mock_reply("token_address")).unwrap();
+        assert_eq!(
+            Response::new()
+                .add_message(WasmMsg::Execute {
+                    contract_addr: "token_address".to_string(),
+                    msg: to_binary(&BaseExecuteMsg::AndrReceive(
+                        AndromedaMsg::ProposeOwnership {
+                            address: "creator".to_string(),
+                            expiration: None,
+                        }
+                    ))
+                    .unwrap(),
+                    funds: vec![],
+                })
+                .add_attribute("action", "record_deployment")
+                .add_attribute("deployment_id", "1")
+                .add_attribute("address", "token_address"),
+            res
+        );
+        assert!(PENDING_DEPLOYMENT
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
 
+    #[test]
+    fn test_create_app() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("owner", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
         let msg = ExecuteMsg::UpdateCodeId {
-            code_id_key: "address_list".to_string(),
-            code_id: 1u64,
+            code_id_key: "app".to_string(),
+            code_id: 2,
+            version: None,
         };
-        let _ = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
-        let msg = ExecuteMsg::UpdateCodeId {
-            code_id_key: "receipt".to_string(),
-            code_id: 2u64,
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+        let template = AppTemplate {
+            parameters: vec!["symbol".to_string()],
+            components: vec![
+                TemplateComponent {
+                    name: "token".to_string(),
+                    ado_type: "cw721".to_string(),
+                    instantiate_msg: r#"{"symbol":"{{symbol}}"}"#.to_string(),
+                },
+                TemplateComponent {
+                    name: "auction".to_string(),
+                    ado_type: "auction".to_string(),
+                    instantiate_msg: r#"{"token":{"identifier":"token"}}"#.to_string(),
+                },
+            ],
         };
-        let _ = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
-        let msg = ExecuteMsg::UpdateCodeId {
-            code_id_key: "token".to_string(),
-            code_id: 0u64,
+        let msg = ExecuteMsg::AddAppTemplate {
+            name: "nft_auction".to_string(),
+            template: template.clone(),
         };
-        let _ = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
-
-        let msg = ExecuteMsg::Create {
-            name: TOKEN_NAME.to_string(),
-            symbol: TOKEN_SYMBOL.to_string(),
-            modules: vec![],
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err);
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let res: AppTemplate = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::AppTemplate {
+                    name: "nft_auction".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(template, res);
+
+        let msg = ExecuteMsg::CreateApp {
+            template: "nft_auction".to_string(),
+            name: "My App".to_string(),
+            primitive_contract: "primitive".to_string(),
+            parameters: vec![TemplateParameter {
+                name: "symbol".to_string(),
+                value: "TT".to_string(),
+            }],
         };
-
-        let token_inst_msg = TokenInstantiateMsg {
-            name: TOKEN_NAME.to_string(),
-            symbol: TOKEN_SYMBOL.to_string(),
-            minter: info.sender.to_string(),
-            modules: vec![],
+        let res = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let expected_msg = AppInstantiateMsg {
+            app: vec![
+                AppComponent {
+                    name: "token".to_string(),
+                    ado_type: "cw721".to_string(),
+                    instantiate_msg: Binary::from(br#"{"symbol":"TT"}"#),
+                },
+                AppComponent {
+                    name: "auction".to_string(),
+                    ado_type: "auction".to_string(),
+                    instantiate_msg: Binary::from(br#"{"token":{"identifier":"token"}}"#),
+                },
+            ],
+            name: "My App".to_string(),
+            primitive_contract: "primitive".to_string(),
         };
-        // [TOK-01 Validation Process]
-        let validation = token_inst_msg.validate();
-        match validation {
-            Ok(true) => {}
-            Err(error) => panic!("{:?}", error),
-            _ => {}
+        assert_eq!(
+            SubMsg {
+                id: REPLY_CREATE_ADO,
+                reply_on: ReplyOn::Success,
+                msg: WasmMsg::Instantiate {
+                    admin: Some("creator".to_string()),
+                    code_id: 2,
+                    msg: to_binary(&expected_msg).unwrap(),
+                    funds: vec![],
+                    label: "Instantiate: app".to_string(),
+                }
+                .into(),
+                gas_limit: None,
+            },
+            res.messages[0]
+        );
+        assert_eq!(attr("template", "nft_auction"), res.attributes[4]);
+    }
+
+    #[test]
+    fn test_query_deployments() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("owner", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+        for (code_id_key, code_id) in [("cw721", 4), ("cw20", 5)] {
+            let msg = ExecuteMsg::UpdateCodeId {
+                code_id_key: code_id_key.to_string(),
+                code_id,
+                version: None,
+            };
+            execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+        }
+
+        for (creator, code_id_key, address) in [
+            ("alice", "cw721", "address1"),
+            ("bob", "cw20", "address2"),
+            ("alice", "cw20", "address3"),
+        ] {
+            create_ado(deps.as_mut(), creator, code_id_key);
+            reply(deps.as_mut(), mock_env(), mock_reply(address)).unwrap();
+        }
+
+        let query_addresses = |msg: QueryMsg| -> Vec<String> {
+            let res: DeploymentsResponse =
+                from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            res.deployments.into_iter().map(|d| d.address).collect()
         };
+        assert_eq!(
+            vec!["address1", "address2", "address3"],
+            query_addresses(QueryMsg::Deployments {
+                start_after: None,
+                limit: None
+            })
+        );
+        assert_eq!(
+            vec!["address3"],
+            query_addresses(QueryMsg::Deployments {
+                start_after: Some(2),
+                limit: None
+            })
+        );
+        assert_eq!(
+            vec!["address1", "address3"],
+            query_addresses(QueryMsg::DeploymentsByCreator {
+                creator: "alice".to_string(),
+                start_after: None,
+                limit: None
+            })
+        );
+        assert_eq!(
+            vec!["address2"],
+            query_addresses(QueryMsg::DeploymentsByType {
+                ado_type: "cw20".to_string(),
+                start_after: None,
+                limit: Some(1)
+            })
+        );
+
+        let res: DeploymentsResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::DeploymentsByCreator {
+                    creator: "bob".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![Deployment {
+                deployment_id: 2,
+                creator: "bob".to_string(),
+                ado_type: "cw20".to_string(),
+                code_id: 5,
+                address: "address2".to_string(),
+                height: mock_env().block.height,
+            }],
+            res.deployments
+        );
+    }
 
-        let inst_msg = WasmMsg::Instantiate {
-            admin: Some(info.sender.to_string()),
-            code_id: TOKEN_CODE_ID,
-            funds: vec![],
-            label: String::from("Address list instantiation"),
-            msg: encode_binary(&token_inst_msg).unwrap(),
+    #[test]
+    fn test_code_id_versions() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("owner", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+        for (version, code_id) in [("1.0.0", 4), ("1.1.0", 5), ("0.9.0", 3)] {
+            let msg = ExecuteMsg::UpdateCodeId {
+                code_id_key: "cw721".to_string(),
+                code_id,
+                version: Some(version.to_string()),
+            };
+            execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+        }
+
+        let msg = ExecuteMsg::UpdateCodeId {
+            code_id_key: "cw721".to_string(),
+            code_id: 6,
+            version: Some("1.0.0".to_string()),
         };
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            ContractError::CodeIdVersionExists {
+                code_id_key: "cw721".to_string(),
+                version: "1.0.0".to_string(),
+            },
+            err
+        );
 
-        let expected_msg = SubMsg {
-            msg: inst_msg.into(),
-            gas_limit: None,
-            id: REPLY_CREATE_TOKEN,
-            reply_on: ReplyOn::Always,
+        let msg = ExecuteMsg::UpdateCodeId {
+            code_id_key: "cw721".to_string(),
+            code_id: 6,
+            version: Some("latest".to_string()),
         };
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidVersion {
+                version: "latest".to_string(),
+            },
+            err
+        );
+
+        // The unversioned key follows the versions and cannot be overwritten.
+        for code_id_key in ["cw721", "cw721@1.0.0"] {
+            let msg = ExecuteMsg::UpdateCodeId {
+                code_id_key: code_id_key.to_string(),
+                code_id: 6,
+                version: None,
+            };
+            let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+            assert_eq!(
+                ContractError::CodeIdVersionRequired {
+                    code_id_key: "cw721".to_string(),
+                },
+                err
+            );
+        }
 
-        let expected_res = Response::new()
-            .add_submessage(expected_msg)
-            .add_attributes(vec![
-                attr("action", "create"),
-                attr("name", TOKEN_NAME.to_string()),
-                attr("symbol", TOKEN_SYMBOL.to_string()),
-                attr("owner", info.sender.to_string()),
-            ]);
+        let query_code_id = |deps: Deps, key: &str| -> Result<u64, ContractError> {
+            let msg = QueryMsg::AndrQuery(AndromedaQuery::Get(Some(encode_binary(&key)?)));
+            Ok(from_binary(&query(deps, mock_env(), msg)?)?)
+        };
+        assert_eq!(5, query_code_id(deps.as_ref(), "cw721").unwrap());
+        assert_eq!(3, query_code_id(deps.as_ref(), "cw721@0.9.0").unwrap());
 
-        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
-        assert_eq!(res, expected_res);
-        assert_eq!(1, expected_res.messages.len())
-    }*/
+        let msg = ExecuteMsg::SetCodeIdDeprecated {
+            code_id_key: "cw721".to_string(),
+            version: "1.1.0".to_string(),
+            deprecated: true,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err);
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        assert_eq!(4, query_code_id(deps.as_ref(), "cw721").unwrap());
+        assert_eq!(
+            ContractError::CodeIdDeprecated {
+                code_id_key: "cw721".to_string(),
+                version: "1.1.0".to_string(),
+            },
+            query_code_id(deps.as_ref(), "cw721@1.1.0").unwrap_err()
+        );
+
+        let res: CodeIdVersionsResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::CodeIdVersions {
+                    code_id_key: "cw721".to_string(),
+                    start_after: Some("0.9.0".to_string()),
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            CodeIdVersionsResponse {
+                latest: Some("1.0.0".to_string()),
+                versions: vec![
+                    CodeIdVersion {
+                        version: "1.0.0".to_string(),
+                        code_id: 4,
+                        deprecated: false,
+                    },
+                    CodeIdVersion {
+                        version: "1.1.0".to_string(),
+                        code_id: 5,
+                        deprecated: true,
+                    },
+                ],
+            },
+            res
+        );
+    }
 
     #[test]
     fn test_update_address() {
@@ -450,6 +904,7 @@ mod tests {
         let msg = ExecuteMsg::UpdateCodeId {
             code_id_key: "address_list".to_string(),
             code_id: 1u64,
+            version: None,
         };
 
         let resp = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -486,6 +941,7 @@ mod tests {
         let msg = ExecuteMsg::UpdateCodeId {
             code_id_key: "address_list".to_string(),
             code_id: 1u64,
+            version: None,
         };
 
         let info = mock_info(&operator, &[]);
@@ -517,6 +973,7 @@ mod tests {
         let msg = ExecuteMsg::UpdateCodeId {
             code_id_key: "address_list".to_string(),
             code_id: 1u64,
+            version: None,
         };
 
         let info = mock_info("not_owner", &[]);
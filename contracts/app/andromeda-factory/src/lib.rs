@@ -1,6 +1,7 @@
 pub mod contract;
 mod reply;
 mod state;
+mod template;
 
 #[cfg(test)]
 mod testing;
@@ -1,28 +1,50 @@
-use common::{error::ContractError, response::get_reply_address};
-use cosmwasm_std::{to_binary, DepsMut, QuerierWrapper, QueryRequest, Reply, Response, WasmQuery};
-use cw721::ContractInfoResponse;
+use andromeda_app::factory::Deployment;
+use common::{
+    ado_base::{AndromedaMsg, ExecuteMsg as BaseExecuteMsg},
+    error::ContractError,
+    response::get_reply_address,
+};
+use cosmwasm_std::{to_binary, DepsMut, Env, Reply, Response, WasmMsg};
 
-use crate::state::store_address;
+use crate::state::{deployments, increment_num_deployments, PENDING_DEPLOYMENT};
 
-pub const REPLY_CREATE_TOKEN: u64 = 1;
+pub const REPLY_CREATE_ADO: u64 = 1;
 
-pub fn on_token_creation_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
-    let token_addr = get_reply_address(msg)?;
-    let info = query_token_config(deps.querier, token_addr.to_string())?;
+/// Records the deployment and, as the factory instantiated the ADO and so owns it, proposes its
+/// creator as the new owner. The creator takes ownership by sending `AcceptOwnership`.
+pub fn on_ado_creation_reply(
+    deps: DepsMut,
+    env: Env,
+    msg: Reply,
+) -> Result<Response, ContractError> {
+    let address = get_reply_address(msg)?;
+    let pending = PENDING_DEPLOYMENT.load(deps.storage)?;
+    PENDING_DEPLOYMENT.remove(deps.storage);
 
-    store_address(deps.storage, info.symbol, &token_addr)?;
+    let deployment_id = increment_num_deployments(deps.storage)?;
+    let propose_ownership = WasmMsg::Execute {
+        contract_addr: address.clone(),
+        msg: to_binary(&BaseExecuteMsg::AndrReceive(
+            AndromedaMsg::ProposeOwnership {
+                address: pending.creator.clone(),
+                expiration: None,
+            },
+        ))?,
+        funds: vec![],
+    };
+    let deployment = Deployment {
+        deployment_id,
+        creator: pending.creator,
+        ado_type: pending.ado_type,
+        code_id: pending.code_id,
+        address: address.clone(),
+        height: env.block.height,
+    };
+    deployments().save(deps.storage, deployment_id, &deployment)?;
 
-    Ok(Response::new())
-}
-
-fn query_token_config(
-    querier: QuerierWrapper,
-    addr: String,
-) -> Result<ContractInfoResponse, ContractError> {
-    let res: ContractInfoResponse = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: addr,
-        msg: to_binary(&"")?,
-    }))?;
-
-    Ok(res)
+    Ok(Response::new()
+        .add_message(propose_ownership)
+        .add_attribute("action", "record_deployment")
+        .add_attribute("deployment_id", deployment_id.to_string())
+        .add_attribute("address", address))
 }
@@ -1,12 +1,127 @@
-use common::{ado_base::ownership::ContractOwnerResponse, error::ContractError};
+use andromeda_app::{
+    app::AppTemplate,
+    factory::{CodeIdVersion, Deployment},
+};
+use common::{
+    ado_base::{ado_type::split_versioned_ado_type, ownership::ContractOwnerResponse},
+    error::ContractError,
+};
 use cosmwasm_std::{
-    to_binary, DepsMut, QuerierWrapper, QueryRequest, StdResult, Storage, WasmQuery,
+    to_binary, DepsMut, Order, QuerierWrapper, QueryRequest, StdError, StdResult, Storage,
+    WasmQuery,
 };
-use cw_storage_plus::Map;
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use schemars::JsonSchema;
+use semver::Version;
+use serde::{Deserialize, Serialize};
 
 // pub const CONFIG: Item<Config> = Item::new("config");
 pub const SYM_ADDRESS: Map<String, String> = Map::new("address");
 pub const CODE_ID: Map<&str, u64> = Map::new("code_id");
+/// The registered versions of each ADO type, keyed by (ado_type, version).
+pub const CODE_ID_VERSIONS: Map<(&str, &str), CodeIdVersion> = Map::new("code_id_versions");
+/// The version of each ADO type whose code ID is stored in `CODE_ID`.
+pub const LATEST_VERSION: Map<&str, String> = Map::new("latest_version");
+pub const APP_TEMPLATES: Map<&str, AppTemplate> = Map::new("app_templates");
+/// The deployment awaiting the reply of its instantiation.
+pub const PENDING_DEPLOYMENT: Item<PendingDeployment> = Item::new("pending_deployment");
+pub const NUM_DEPLOYMENTS: Item<u64> = Item::new("num_deployments");
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PendingDeployment {
+    pub creator: String,
+    pub ado_type: String,
+    pub code_id: u64,
+}
+
+pub struct DeploymentIndexes<'a> {
+    pub creator: MultiIndex<'a, String, Deployment, u64>,
+    pub ado_type: MultiIndex<'a, String, Deployment, u64>,
+}
+
+impl<'a> IndexList<Deployment> for DeploymentIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Deployment>> + '_> {
+        let v: Vec<&dyn Index<Deployment>> = vec![&self.creator, &self.ado_type];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn deployments<'a>() -> IndexedMap<'a, u64, Deployment, DeploymentIndexes<'a>> {
+    let indexes = DeploymentIndexes {
+        creator: MultiIndex::new(
+            |d: &Deployment| d.creator.clone(),
+            "deployments",
+            "deployments__creator",
+        ),
+        ado_type: MultiIndex::new(
+            |d: &Deployment| d.ado_type.clone(),
+            "deployments",
+            "deployments__ado_type",
+        ),
+    };
+    IndexedMap::new("deployments", indexes)
+}
+
+/// Increments the deployment count and returns it as the ID of the next deployment.
+pub fn increment_num_deployments(storage: &mut dyn Storage) -> StdResult<u64> {
+    let num_deployments = NUM_DEPLOYMENTS.may_load(storage)?.unwrap_or_default() + 1;
+    NUM_DEPLOYMENTS.save(storage, &num_deployments)?;
+    Ok(num_deployments)
+}
+
+/// The deployments to query, selected by one of the indexes.
+pub enum DeploymentFilter {
+    All,
+    Creator(String),
+    AdoType(String),
+}
+
+pub fn load_app_template_names(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    APP_TEMPLATES
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+/// Lists the deployments matching `filter` in ascending order of ID.
+pub fn load_deployments(
+    storage: &dyn Storage,
+    filter: DeploymentFilter,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Deployment>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let deployments = deployments();
+    let iter =
+        match filter {
+            DeploymentFilter::All => deployments.range(storage, start, None, Order::Ascending),
+            DeploymentFilter::Creator(creator) => deployments.idx.creator.prefix(creator).range(
+                storage,
+                start,
+                None,
+                Order::Ascending,
+            ),
+            DeploymentFilter::AdoType(ado_type) => deployments.idx.ado_type.prefix(ado_type).range(
+                storage,
+                start,
+                None,
+                Order::Ascending,
+            ),
+        };
+    iter.take(limit)
+        .map(|item| item.map(|(_, deployment)| deployment))
+        .collect()
+}
 
 // #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 // pub struct Config {
@@ -14,12 +129,147 @@ pub const CODE_ID: Map<&str, u64> = Map::new("code_id");
 //     pub receipt_code_id: u64,
 //     pub address_list_code_id: u64,
 // }
-pub fn store_code_id(storage: &mut dyn Storage, code_id_key: &str, code_id: u64) -> StdResult<()> {
-    CODE_ID.save(storage, code_id_key, &code_id)
+/// Stores `code_id` under an unversioned key. The key of an ADO type with registered versions
+/// always points at its latest version, so it cannot be overwritten.
+pub fn store_code_id(
+    storage: &mut dyn Storage,
+    code_id_key: &str,
+    code_id: u64,
+) -> Result<(), ContractError> {
+    let (ado_type, version) = split_versioned_ado_type(code_id_key);
+    let has_versions = CODE_ID_VERSIONS
+        .prefix(ado_type)
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    if version.is_some() || has_versions {
+        return Err(ContractError::CodeIdVersionRequired {
+            code_id_key: ado_type.to_string(),
+        });
+    }
+    Ok(CODE_ID.save(storage, code_id_key, &code_id)?)
+}
+
+/// Resolves `code_id_key` to a code ID. Keys of the form `ado_type@version` resolve to that
+/// version, which must not be deprecated.
+pub fn read_code_id(storage: &dyn Storage, code_id_key: &str) -> Result<u64, ContractError> {
+    match split_versioned_ado_type(code_id_key) {
+        (ado_type, Some(version)) => {
+            let version = parse_version(version)?.to_string();
+            let code_id_version = CODE_ID_VERSIONS.load(storage, (ado_type, &version))?;
+            if code_id_version.deprecated {
+                return Err(ContractError::CodeIdDeprecated {
+                    code_id_key: ado_type.to_string(),
+                    version,
+                });
+            }
+            Ok(code_id_version.code_id)
+        }
+        (_, None) => Ok(CODE_ID.load(storage, code_id_key)?),
+    }
+}
+
+pub fn parse_version(version: &str) -> Result<Version, ContractError> {
+    version.parse().map_err(|_| ContractError::InvalidVersion {
+        version: version.to_string(),
+    })
+}
+
+/// Registers a new version of `ado_type`. Registered versions cannot be overwritten.
+pub fn store_code_id_version(
+    storage: &mut dyn Storage,
+    ado_type: &str,
+    version: &Version,
+    code_id: u64,
+) -> Result<(), ContractError> {
+    let version = version.to_string();
+    if CODE_ID_VERSIONS.has(storage, (ado_type, &version)) {
+        return Err(ContractError::CodeIdVersionExists {
+            code_id_key: ado_type.to_string(),
+            version,
+        });
+    }
+    CODE_ID_VERSIONS.save(
+        storage,
+        (ado_type, &version),
+        &CodeIdVersion {
+            version: version.clone(),
+            code_id,
+            deprecated: false,
+        },
+    )?;
+    update_latest_version(storage, ado_type)
 }
 
-pub fn read_code_id(storage: &dyn Storage, code_id_key: &str) -> StdResult<u64> {
-    CODE_ID.load(storage, code_id_key)
+pub fn set_code_id_deprecated(
+    storage: &mut dyn Storage,
+    ado_type: &str,
+    version: &Version,
+    deprecated: bool,
+) -> Result<(), ContractError> {
+    let version = version.to_string();
+    CODE_ID_VERSIONS.update(
+        storage,
+        (ado_type, &version),
+        |code_id_version| -> StdResult<CodeIdVersion> {
+            let mut code_id_version = code_id_version
+                .ok_or_else(|| StdError::not_found("andromeda_app::factory::CodeIdVersion"))?;
+            code_id_version.deprecated = deprecated;
+            Ok(code_id_version)
+        },
+    )?;
+    update_latest_version(storage, ado_type)
+}
+
+/// Points the unpinned key of `ado_type` at its newest non-deprecated version. The key is removed
+/// if every version is deprecated.
+fn update_latest_version(storage: &mut dyn Storage, ado_type: &str) -> Result<(), ContractError> {
+    let mut latest: Option<(Version, u64)> = None;
+    for item in CODE_ID_VERSIONS
+        .prefix(ado_type)
+        .range(storage, None, None, Order::Ascending)
+    {
+        let (_, code_id_version) = item?;
+        if code_id_version.deprecated {
+            continue;
+        }
+        let version = parse_version(&code_id_version.version)?;
+        let is_newer = match &latest {
+            Some((latest, _)) => version > *latest,
+            None => true,
+        };
+        if is_newer {
+            latest = Some((version, code_id_version.code_id));
+        }
+    }
+    match latest {
+        Some((version, code_id)) => {
+            LATEST_VERSION.save(storage, ado_type, &version.to_string())?;
+            CODE_ID.save(storage, ado_type, &code_id)?;
+        }
+        None => {
+            LATEST_VERSION.remove(storage, ado_type);
+            CODE_ID.remove(storage, ado_type);
+        }
+    }
+    Ok(())
+}
+
+/// Lists the versions of `ado_type` in ascending order of version string.
+pub fn load_code_id_versions(
+    storage: &dyn Storage,
+    ado_type: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CodeIdVersion>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    CODE_ID_VERSIONS
+        .prefix(ado_type)
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, code_id_version)| code_id_version))
+        .collect()
 }
 //
 // pub fn store_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
@@ -38,13 +288,6 @@ pub fn read_address(storage: &dyn Storage, symbol: String) -> StdResult<String>
     SYM_ADDRESS.load(storage, symbol)
 }
 
-pub fn is_address_defined(storage: &dyn Storage, symbol: String) -> StdResult<bool> {
-    match read_address(storage, symbol) {
-        Ok(_addr) => Ok(true),
-        _ => Ok(false),
-    }
-}
-
 pub fn is_creator(deps: &DepsMut, symbol: String, address: String) -> Result<bool, ContractError> {
     let contract_address = read_address(deps.storage, symbol)?;
     let owner = query_ado_owner(deps.querier, contract_address)?;
@@ -0,0 +1,206 @@
+use ado_base::app::{parse_identifiers, validate_component_reference};
+use andromeda_app::app::{AppComponent, AppTemplate, TemplateParameter};
+use common::error::ContractError;
+use cosmwasm_std::{ensure, to_vec, Api, Binary};
+
+/// The maximum number of components an app can be instantiated with.
+pub const MAX_APP_COMPONENTS: usize = 50;
+
+pub fn validate_app_template(template: &AppTemplate) -> Result<(), ContractError> {
+    ensure!(
+        template.components.len() <= MAX_APP_COMPONENTS,
+        ContractError::TooManyAppComponents {}
+    );
+    for (idx, component) in template.components.iter().enumerate() {
+        ensure!(
+            !template.components[..idx]
+                .iter()
+                .any(|earlier| earlier.name == component.name),
+            ContractError::NameAlreadyTaken {}
+        );
+    }
+    Ok(())
+}
+
+/// Fills in the parameters of `template` and ensures that every component only references
+/// addresses or the components before it. Parameter values are JSON-escaped, so placeholders
+/// belong inside JSON strings.
+pub fn render_app_template(
+    api: &dyn Api,
+    template: AppTemplate,
+    parameters: &[TemplateParameter],
+) -> Result<Vec<AppComponent>, ContractError> {
+    for parameter in parameters {
+        ensure!(
+            template.parameters.contains(&parameter.name),
+            ContractError::UnknownTemplateParameter {
+                name: parameter.name.clone()
+            }
+        );
+    }
+    for name in template.parameters.iter() {
+        ensure!(
+            parameters.iter().any(|parameter| &parameter.name == name),
+            ContractError::MissingTemplateParameter { name: name.clone() }
+        );
+    }
+    let mut values: Vec<(String, String)> = vec![];
+    for parameter in parameters {
+        values.push((
+            format!("{{{{{}}}}}", parameter.name),
+            json_escape(&parameter.value)?,
+        ));
+    }
+
+    let mut components: Vec<AppComponent> = vec![];
+    for component in template.components {
+        let instantiate_msg = values
+            .iter()
+            .fold(component.instantiate_msg, |text, (placeholder, value)| {
+                text.replace(placeholder, value)
+            });
+        let instantiate_msg = Binary::from(instantiate_msg.as_bytes());
+        for identifier in parse_identifiers(&instantiate_msg) {
+            validate_component_reference(api, identifier, |name| {
+                Ok(components.iter().any(|earlier| earlier.name == name))
+            })?;
+        }
+        components.push(AppComponent {
+            name: component.name,
+            ado_type: component.ado_type,
+            instantiate_msg,
+        });
+    }
+    Ok(components)
+}
+
+/// Escapes `value` for use inside a JSON string.
+fn json_escape(value: &str) -> Result<String, ContractError> {
+    let quoted = String::from_utf8(to_vec(value)?)?;
+    Ok(quoted[1..quoted.len() - 1].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use andromeda_app::app::TemplateComponent;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    fn template_component(name: &str, instantiate_msg: &str) -> TemplateComponent {
+        TemplateComponent {
+            name: name.to_string(),
+            ado_type: name.to_string(),
+            instantiate_msg: instantiate_msg.to_string(),
+        }
+    }
+
+    fn parameter(name: &str, value: &str) -> TemplateParameter {
+        TemplateParameter {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_app_template() {
+        let deps = mock_dependencies();
+        let template = AppTemplate {
+            parameters: vec!["symbol".to_string(), "nft".to_string()],
+            components: vec![
+                template_component("cw721", r#"{"symbol":"{{symbol}}"}"#),
+                template_component("auction", r#"{"token":{"identifier":"{{nft}}"}}"#),
+            ],
+        };
+
+        let err = render_app_template(
+            deps.as_ref().api,
+            template.clone(),
+            &[parameter("symbol", "TT")],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::MissingTemplateParameter {
+                name: "nft".to_string()
+            },
+            err
+        );
+
+        let err = render_app_template(
+            deps.as_ref().api,
+            template.clone(),
+            &[
+                parameter("symbol", "TT"),
+                parameter("nft", "cw721"),
+                parameter("rates", "rates"),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::UnknownTemplateParameter {
+                name: "rates".to_string()
+            },
+            err
+        );
+
+        // Values are escaped so they cannot break out of the JSON strings they are placed in.
+        let components = render_app_template(
+            deps.as_ref().api,
+            template,
+            &[parameter("symbol", r#"T"T"#), parameter("nft", "cw721")],
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                AppComponent {
+                    name: "cw721".to_string(),
+                    ado_type: "cw721".to_string(),
+                    instantiate_msg: Binary::from(br#"{"symbol":"T\"T"}"#),
+                },
+                AppComponent {
+                    name: "auction".to_string(),
+                    ado_type: "auction".to_string(),
+                    instantiate_msg: Binary::from(br#"{"token":{"identifier":"cw721"}}"#),
+                },
+            ],
+            components
+        );
+    }
+
+    #[test]
+    fn test_render_app_template_invalid_reference() {
+        let deps = mock_dependencies();
+        // The names are too short to be mock addresses. The auction references the token before
+        // it is created.
+        let template = AppTemplate {
+            parameters: vec![],
+            components: vec![
+                template_component("au", r#"{"token":{"identifier":"nf"}}"#),
+                template_component("nf", "{}"),
+            ],
+        };
+
+        let err = render_app_template(deps.as_ref().api, template, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidComponent {
+                name: "nf".to_string()
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_validate_app_template_duplicate_name() {
+        let template = AppTemplate {
+            parameters: vec![],
+            components: vec![
+                template_component("cw721", "{}"),
+                template_component("cw721", "{}"),
+            ],
+        };
+
+        assert_eq!(
+            ContractError::NameAlreadyTaken {},
+            validate_app_template(&template).unwrap_err()
+        );
+    }
+}
@@ -65,16 +65,19 @@ fn test_create() {
     let msg = ExecuteMsg::UpdateCodeId {
         code_id_key: "address_list".to_string(),
         code_id: 1u64,
+        version: None,
     };
     let _ = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
     let msg = ExecuteMsg::UpdateCodeId {
         code_id_key: "receipt".to_string(),
         code_id: 2u64,
+        version: None,
     };
     let _ = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
     let msg = ExecuteMsg::UpdateCodeId {
         code_id_key: "token".to_string(),
         code_id: 0u64,
+        version: None,
     };
     let _ = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
 
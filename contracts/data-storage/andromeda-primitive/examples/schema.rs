@@ -1,7 +1,10 @@
 use std::env::current_dir;
 use std::fs::create_dir_all;
 
-use andromeda_data_storage::primitive::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use andromeda_data_storage::primitive::{
+    AllKeysResponse, ExecuteMsg, GetValueAtResponse, InstantiateMsg, NamespaceWritersResponse,
+    QueryMsg, ScheduledValueResponse, SchemaResponse,
+};
 use common::primitive::GetValueResponse;
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
@@ -16,4 +19,9 @@ fn main() {
     export_schema(&schema_for!(QueryMsg), &out_dir);
 
     export_schema(&schema_for!(GetValueResponse), &out_dir);
+    export_schema(&schema_for!(SchemaResponse), &out_dir);
+    export_schema(&schema_for!(NamespaceWritersResponse), &out_dir);
+    export_schema(&schema_for!(AllKeysResponse), &out_dir);
+    export_schema(&schema_for!(GetValueAtResponse), &out_dir);
+    export_schema(&schema_for!(ScheduledValueResponse), &out_dir);
 }
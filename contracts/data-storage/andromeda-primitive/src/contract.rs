@@ -1,24 +1,31 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{ensure, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError};
-use cw2::{get_contract_version, set_contract_version};
+use cosmwasm_std::{ensure, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, Storage};
+use cw2::set_contract_version;
 
-use crate::state::{DATA, DEFAULT_KEY};
+use crate::state::{
+    is_namespace_writer, load_keys, load_value, load_value_at, settle_scheduled_value, write_value,
+    DEFAULT_KEY, NAMESPACE_WRITERS, SCHEDULED_VALUES, SCHEMAS,
+};
 use ado_base::state::ADOContract;
-use andromeda_data_storage::primitive::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use andromeda_data_storage::primitive::{
+    AllKeysResponse, ExecuteMsg, GetValueAtResponse, InstantiateMsg, MigrateMsg,
+    NamespaceWritersResponse, QueryMsg, ScheduledValue, ScheduledValueResponse, SchemaResponse,
+};
 use common::{
     ado_base::{AndromedaQuery, InstantiateMsg as BaseInstantiateMsg},
     encode_binary,
     error::ContractError,
     parse_message,
-    primitive::{GetValueResponse, Primitive},
+    primitive::{GetValueResponse, Primitive, PrimitiveType},
 };
-use cw_utils::nonpayable;
-use semver::Version;
+use cw_utils::{nonpayable, Expiration};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-primitive";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &["set_schema", "set_namespace_writers"];
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -28,6 +35,7 @@ pub fn instantiate(
     _msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -50,17 +58,57 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::AndrReceive(msg) => {
             ADOContract::default().execute(deps, env, info, msg, execute)
         }
-        ExecuteMsg::SetValue { key, value } => execute_set_value(deps, info, key, value),
-        ExecuteMsg::DeleteValue { key } => execute_delete_value(deps, info, key),
+        ExecuteMsg::SetValue { key, value } => execute_set_value(deps, env, info, key, value),
+        ExecuteMsg::DeleteValue { key } => execute_delete_value(deps, env, info, key),
+        ExecuteMsg::ScheduleValue {
+            key,
+            value,
+            activate_at,
+        } => execute_schedule_value(deps, env, info, key, value, activate_at),
+        ExecuteMsg::SetSchema {
+            key,
+            primitive_type,
+        } => execute_set_schema(deps, env, info, key, primitive_type),
+        ExecuteMsg::SetNamespaceWriters { namespace, writers } => {
+            execute_set_namespace_writers(deps, info, namespace, writers)
+        }
     }
 }
 
+/// Returns whether `sender` may set or delete the value of the key.
+fn can_write_key(storage: &dyn Storage, key: &str, sender: &str) -> Result<bool, ContractError> {
+    Ok(
+        ADOContract::default().is_owner_or_operator(storage, sender)?
+            || is_namespace_writer(storage, key, sender)?,
+    )
+}
+
+/// Ensures that `sender` may write the value to the key.
+fn ensure_can_write_value(
+    deps: Deps,
+    key: &str,
+    sender: &str,
+    value: &Primitive,
+) -> Result<(), ContractError> {
+    ensure!(
+        can_write_key(deps.storage, key, sender)?,
+        ContractError::Unauthorized {}
+    );
+    value.validate(deps.api)?;
+    if let Some(primitive_type) = SCHEMAS.may_load(deps.storage, key)? {
+        ensure_primitive_type(key, value, primitive_type)?;
+    }
+    Ok(())
+}
+
 pub fn execute_set_value(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     key: Option<String>,
     value: Primitive,
@@ -68,18 +116,16 @@ pub fn execute_set_value(
     nonpayable(&info)?;
 
     let sender = info.sender.to_string();
-    ensure!(
-        ADOContract::default().is_owner_or_operator(deps.storage, &sender)?,
-        ContractError::Unauthorized {}
-    );
-    if value.is_invalid() {
-        return Err(ContractError::InvalidPrimitive {});
-    }
     let key: &str = get_key_or_default(&key);
-    DATA.update::<_, StdError>(deps.storage, key, |old| match old {
-        Some(_) => Ok(value.clone()),
-        None => Ok(value.clone()),
-    })?;
+    ensure_can_write_value(deps.as_ref(), key, &sender, &value)?;
+    settle_scheduled_value(deps.storage, &env.block, key)?;
+    write_value(
+        deps.storage,
+        key,
+        Some(value.clone()),
+        sender.clone(),
+        env.block.height,
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "set_value")
@@ -90,65 +136,168 @@ pub fn execute_set_value(
 
 pub fn execute_delete_value(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     key: Option<String>,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
     let sender = info.sender.to_string();
+    let key = get_key_or_default(&key);
     ensure!(
-        ADOContract::default().is_owner_or_operator(deps.storage, &sender)?,
+        can_write_key(deps.storage, key, &sender)?,
         ContractError::Unauthorized {}
     );
-    let key = get_key_or_default(&key);
-    DATA.remove(deps.storage, key);
+    settle_scheduled_value(deps.storage, &env.block, key)?;
+    write_value(deps.storage, key, None, sender.clone(), env.block.height)?;
     Ok(Response::new()
         .add_attribute("method", "delete_value")
         .add_attribute("sender", sender)
         .add_attribute("key", key))
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
+pub fn execute_schedule_value(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    key: Option<String>,
+    value: Primitive,
+    activate_at: Expiration,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
 
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
+    let sender = info.sender.to_string();
+    let key: &str = get_key_or_default(&key);
+    ensure_can_write_value(deps.as_ref(), key, &sender, &value)?;
+    ensure!(
+        activate_at != Expiration::Never {},
+        ContractError::ExpirationMustNotBeNever {}
+    );
+    ensure!(
+        !activate_at.is_expired(&env.block),
+        ContractError::ExpirationInPast {}
+    );
+    // A previously scheduled value that is already active must not be replaced.
+    settle_scheduled_value(deps.storage, &env.block, key)?;
+    SCHEDULED_VALUES.save(
+        deps.storage,
+        key,
+        &ScheduledValue {
+            value: value.clone(),
+            setter: sender.clone(),
+            activate_at,
+        },
+    )?;
 
-    let contract = ADOContract::default();
+    Ok(Response::new()
+        .add_attribute("method", "schedule_value")
+        .add_attribute("sender", sender)
+        .add_attribute("key", key)
+        .add_attribute("value", format!("{:?}", value))
+        .add_attribute("activate_at", activate_at.to_string()))
+}
 
+pub fn execute_set_schema(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    key: Option<String>,
+    primitive_type: Option<PrimitiveType>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let sender = info.sender.to_string();
     ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
+        ADOContract::default().is_owner_or_operator(deps.storage, &sender)?,
+        ContractError::Unauthorized {}
     );
+    let key = get_key_or_default(&key);
+    match primitive_type {
+        Some(primitive_type) => {
+            // The current and scheduled values must already conform to the schema.
+            if let Some(value) = load_value(deps.storage, &env.block, key)? {
+                ensure_primitive_type(key, &value, primitive_type)?;
+            }
+            if let Some(scheduled) = SCHEDULED_VALUES.may_load(deps.storage, key)? {
+                ensure_primitive_type(key, &scheduled.value, primitive_type)?;
+            }
+            SCHEMAS.save(deps.storage, key, &primitive_type)?;
+        }
+        None => SCHEMAS.remove(deps.storage, key),
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "set_schema")
+        .add_attribute("sender", sender)
+        .add_attribute("key", key)
+        .add_attribute("primitive_type", format!("{:?}", primitive_type)))
+}
 
-    // New version has to be newer/greater than the old version
+pub fn execute_set_namespace_writers(
+    deps: DepsMut,
+    info: MessageInfo,
+    namespace: String,
+    writers: Vec<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let sender = info.sender.to_string();
     ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
+        ADOContract::default().is_contract_owner(deps.storage, &sender)?,
+        ContractError::Unauthorized {}
     );
+    for writer in writers.iter() {
+        deps.api.addr_validate(writer)?;
+    }
+    if writers.is_empty() {
+        NAMESPACE_WRITERS.remove(deps.storage, &namespace);
+    } else {
+        NAMESPACE_WRITERS.save(deps.storage, &namespace, &writers)?;
+    }
 
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
+    Ok(Response::new()
+        .add_attribute("method", "set_namespace_writers")
+        .add_attribute("sender", sender)
+        .add_attribute("namespace", namespace)
+        .add_attribute("writers", writers.join(",")))
+}
 
-    Ok(Response::default())
+fn ensure_primitive_type(
+    key: &str,
+    value: &Primitive,
+    primitive_type: PrimitiveType,
+) -> Result<(), ContractError> {
+    ensure!(
+        value.primitive_type() == primitive_type,
+        ContractError::InvalidPrimitiveType {
+            key: key.to_string(),
+            expected: format!("{:?}", primitive_type),
+        }
+    );
+    Ok(())
 }
 
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::AndrQuery(msg) => handle_andromeda_query(deps, env, msg),
+        QueryMsg::GetValueAt { key, height } => {
+            encode_binary(&query_value_at(deps, env, key, height)?)
+        }
+        QueryMsg::ScheduledValue { key } => encode_binary(&query_scheduled_value(deps, key)?),
+        QueryMsg::Schema { key } => encode_binary(&query_schema(deps, key)?),
+        QueryMsg::NamespaceWriters { namespace } => {
+            encode_binary(&query_namespace_writers(deps, namespace)?)
+        }
+        QueryMsg::AllKeys {
+            prefix,
+            start_after,
+            limit,
+        } => encode_binary(&query_all_keys(deps, prefix, start_after, limit)?),
     }
 }
 
@@ -160,25 +309,83 @@ fn handle_andromeda_query(
     match msg {
         AndromedaQuery::Get(data) => match data {
             // Treat no binary as request to get value with default key.
-            None => encode_binary(&query_value(deps, None)?),
+            None => encode_binary(&query_value(deps, env, None)?),
             Some(_) => {
                 let name: String = parse_message(&data)?;
-                encode_binary(&query_value(deps, Some(name))?)
+                encode_binary(&query_value(deps, env, Some(name))?)
             }
         },
         _ => ADOContract::default().query(deps, env, msg, query),
     }
 }
 
-fn query_value(deps: Deps, key: Option<String>) -> Result<GetValueResponse, ContractError> {
+fn query_value(
+    deps: Deps,
+    env: Env,
+    key: Option<String>,
+) -> Result<GetValueResponse, ContractError> {
     let key = get_key_or_default(&key);
-    let value = DATA.load(deps.storage, key)?;
+    let value = load_value(deps.storage, &env.block, key)?
+        .ok_or_else(|| StdError::not_found("Primitive"))?;
     Ok(GetValueResponse {
         key: key.to_string(),
         value,
     })
 }
 
+fn query_value_at(
+    deps: Deps,
+    env: Env,
+    key: Option<String>,
+    height: u64,
+) -> Result<GetValueAtResponse, ContractError> {
+    let key = get_key_or_default(&key);
+    Ok(GetValueAtResponse {
+        key: key.to_string(),
+        record: load_value_at(deps.storage, &env.block, key, height)?,
+    })
+}
+
+fn query_scheduled_value(
+    deps: Deps,
+    key: Option<String>,
+) -> Result<ScheduledValueResponse, ContractError> {
+    let key = get_key_or_default(&key);
+    Ok(ScheduledValueResponse {
+        key: key.to_string(),
+        scheduled: SCHEDULED_VALUES.may_load(deps.storage, key)?,
+    })
+}
+
+fn query_schema(deps: Deps, key: Option<String>) -> Result<SchemaResponse, ContractError> {
+    let key = get_key_or_default(&key);
+    Ok(SchemaResponse {
+        key: key.to_string(),
+        primitive_type: SCHEMAS.may_load(deps.storage, key)?,
+    })
+}
+
+fn query_namespace_writers(
+    deps: Deps,
+    namespace: String,
+) -> Result<NamespaceWritersResponse, ContractError> {
+    let writers = NAMESPACE_WRITERS
+        .may_load(deps.storage, &namespace)?
+        .unwrap_or_default();
+    Ok(NamespaceWritersResponse { namespace, writers })
+}
+
+fn query_all_keys(
+    deps: Deps,
+    prefix: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<AllKeysResponse, ContractError> {
+    Ok(AllKeysResponse {
+        keys: load_keys(deps.storage, prefix, start_after, limit)?,
+    })
+}
+
 fn get_key_or_default(name: &Option<String>) -> &str {
     match name {
         None => DEFAULT_KEY,
@@ -189,9 +396,12 @@ fn get_key_or_default(name: &Option<String>) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::{HISTORY, MAX_HISTORY_LENGTH};
+    use andromeda_data_storage::primitive::ValueRecord;
     use common::ado_base::AndromedaMsg;
-    use cosmwasm_std::from_binary;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, Addr, Decimal, Order};
+    use std::collections::BTreeMap;
 
     fn query_value_helper(
         deps: Deps,
@@ -275,6 +485,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_value_paused() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+        let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause {
+            messages: Some(vec!["set_value".to_string()]),
+        });
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::SetValue {
+            key: Some("test1".to_string()),
+            value: Primitive::String("value1".to_string()),
+        };
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            ContractError::Paused {
+                msg: "set_value".to_string()
+            },
+            err
+        );
+
+        // Other messages are unaffected.
+        let msg = ExecuteMsg::SetSchema {
+            key: Some("test1".to_string()),
+            primitive_type: None,
+        };
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    }
+
     #[test]
     fn set_and_update_value_without_key() {
         let mut deps = mock_dependencies();
@@ -345,6 +585,34 @@ mod tests {
         assert_eq!(ContractError::InvalidPrimitive {}, res.unwrap_err());
     }
 
+    #[test]
+    fn set_object_value() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+        let recipients = |recipient: &str| {
+            Primitive::Object(BTreeMap::from([(
+                "recipient".to_string(),
+                Primitive::Addr(Addr::unchecked(recipient)),
+            )]))
+        };
+        let msg = ExecuteMsg::SetValue {
+            key: Some("recipients".to_string()),
+            value: recipients("x"),
+        };
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), msg);
+        assert!(res.is_err());
+
+        let msg = ExecuteMsg::SetValue {
+            key: Some("recipients".to_string()),
+            value: recipients("recipient"),
+        };
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        let res = query_value_helper(deps.as_ref(), Some("recipients".to_string())).unwrap();
+        assert_eq!(recipients("recipient"), res.value);
+    }
+
     #[test]
     fn delete_value_with_key() {
         let mut deps = mock_dependencies();
@@ -525,4 +793,358 @@ mod tests {
             res
         );
     }
+
+    #[test]
+    fn set_value_with_schema() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+        let msg = ExecuteMsg::SetValue {
+            key: Some("price".to_string()),
+            value: Primitive::Decimal(Decimal::one()),
+        };
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+        // The current value does not conform to the schema.
+        let msg = ExecuteMsg::SetSchema {
+            key: Some("price".to_string()),
+            primitive_type: Some(PrimitiveType::Coin),
+        };
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone());
+        let expected_err = ContractError::InvalidPrimitiveType {
+            key: "price".to_string(),
+            expected: "Coin".to_string(),
+        };
+        assert_eq!(expected_err, res.unwrap_err());
+
+        let set_coin = ExecuteMsg::SetValue {
+            key: Some("price".to_string()),
+            value: Primitive::Coin(coin(100, "uusd")),
+        };
+        execute(deps.as_mut(), mock_env(), info.clone(), set_coin.clone()).unwrap();
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+        let res: SchemaResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Schema {
+                    key: Some("price".to_string()),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(Some(PrimitiveType::Coin), res.primitive_type);
+
+        let msg = ExecuteMsg::SetValue {
+            key: Some("price".to_string()),
+            value: Primitive::Decimal(Decimal::one()),
+        };
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone());
+        assert_eq!(expected_err, res.unwrap_err());
+        execute(deps.as_mut(), mock_env(), info.clone(), set_coin).unwrap();
+
+        // Lifting the restriction allows any type again.
+        let lift = ExecuteMsg::SetSchema {
+            key: Some("price".to_string()),
+            primitive_type: None,
+        };
+        execute(deps.as_mut(), mock_env(), info.clone(), lift).unwrap();
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    }
+
+    #[test]
+    fn namespace_writers() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+        let msg = ExecuteMsg::SetNamespaceWriters {
+            namespace: "rates".to_string(),
+            writers: vec!["writer".to_string()],
+        };
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("writer", &[]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let writer = mock_info("writer", &[]);
+        let set_value = |key: &str| ExecuteMsg::SetValue {
+            key: Some(key.to_string()),
+            value: Primitive::Bool(true),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            writer.clone(),
+            set_value("rates/fee"),
+        )
+        .unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            writer.clone(),
+            set_value("factory"),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            writer.clone(),
+            set_value("ratesx/fee"),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        let msg = ExecuteMsg::DeleteValue {
+            key: Some("rates/fee".to_string()),
+        };
+        execute(deps.as_mut(), mock_env(), writer, msg).unwrap();
+
+        let res: NamespaceWritersResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::NamespaceWriters {
+                    namespace: "rates".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(vec!["writer".to_string()], res.writers);
+    }
+
+    #[test]
+    fn query_all_keys() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+        for key in ["rates/b", "factory", "rates/a", "rates/c", "ratesx"] {
+            let msg = ExecuteMsg::SetValue {
+                key: Some(key.to_string()),
+                value: Primitive::Bool(true),
+            };
+            execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+        }
+
+        let all_keys = |prefix: Option<&str>, start_after: Option<&str>, limit: Option<u32>| {
+            let msg = QueryMsg::AllKeys {
+                prefix: prefix.map(String::from),
+                start_after: start_after.map(String::from),
+                limit,
+            };
+            let res: AllKeysResponse =
+                from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            res.keys
+        };
+        assert_eq!(
+            vec!["factory", "rates/a", "rates/b", "rates/c", "ratesx"],
+            all_keys(None, None, None)
+        );
+        assert_eq!(
+            vec!["rates/a", "rates/b", "rates/c"],
+            all_keys(Some("rates/"), None, None)
+        );
+        assert_eq!(
+            vec!["rates/a", "rates/b"],
+            all_keys(Some("rates/"), None, Some(2))
+        );
+        assert_eq!(
+            vec!["rates/c"],
+            all_keys(Some("rates/"), Some("rates/b"), None)
+        );
+        assert_eq!(
+            vec!["rates/a", "rates/b"],
+            all_keys(Some("rates/"), Some("factory"), Some(2))
+        );
+    }
+
+    fn env_at(height: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height = height;
+        env
+    }
+
+    fn query_value_at_helper(deps: Deps, env: Env, height: u64) -> Option<ValueRecord> {
+        let msg = QueryMsg::GetValueAt {
+            key: Some("fee".to_string()),
+            height,
+        };
+        let res: GetValueAtResponse = from_binary(&query(deps, env, msg).unwrap()).unwrap();
+        res.record
+    }
+
+    #[test]
+    fn value_history() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+        let set_value = |value: u128| ExecuteMsg::SetValue {
+            key: Some("fee".to_string()),
+            value: Primitive::Uint128(value.into()),
+        };
+        execute(deps.as_mut(), env_at(100), info.clone(), set_value(1)).unwrap();
+        execute(deps.as_mut(), env_at(110), info.clone(), set_value(2)).unwrap();
+        let msg = ExecuteMsg::DeleteValue {
+            key: Some("fee".to_string()),
+        };
+        execute(deps.as_mut(), env_at(120), info.clone(), msg).unwrap();
+
+        assert_eq!(None, query_value_at_helper(deps.as_ref(), env_at(130), 99));
+        assert_eq!(
+            Some(ValueRecord {
+                value: Some(Primitive::Uint128(1u128.into())),
+                setter: "creator".to_string(),
+                height: 100,
+            }),
+            query_value_at_helper(deps.as_ref(), env_at(130), 109)
+        );
+        assert_eq!(
+            Some(ValueRecord {
+                value: Some(Primitive::Uint128(2u128.into())),
+                setter: "creator".to_string(),
+                height: 110,
+            }),
+            query_value_at_helper(deps.as_ref(), env_at(130), 110)
+        );
+        assert_eq!(
+            Some(ValueRecord {
+                value: None,
+                setter: "creator".to_string(),
+                height: 120,
+            }),
+            query_value_at_helper(deps.as_ref(), env_at(130), 130)
+        );
+
+        // Only the most recent changes are kept.
+        for height in 200..(200 + MAX_HISTORY_LENGTH as u64) {
+            execute(deps.as_mut(), env_at(height), info.clone(), set_value(3)).unwrap();
+        }
+        let heights: Vec<u64> = HISTORY
+            .prefix("fee")
+            .keys(&deps.storage, None, None, Order::Ascending)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(MAX_HISTORY_LENGTH, heights.len());
+        assert_eq!(200, heights[0]);
+        assert_eq!(None, query_value_at_helper(deps.as_ref(), env_at(300), 150));
+    }
+
+    #[test]
+    fn schedule_value() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+        let msg = ExecuteMsg::SetValue {
+            key: Some("fee".to_string()),
+            value: Primitive::Uint128(1u128.into()),
+        };
+        execute(deps.as_mut(), env_at(100), info.clone(), msg).unwrap();
+
+        let schedule = |activate_at: Expiration| ExecuteMsg::ScheduleValue {
+            key: Some("fee".to_string()),
+            value: Primitive::Uint128(2u128.into()),
+            activate_at,
+        };
+        let res = execute(
+            deps.as_mut(),
+            env_at(100),
+            mock_info("anyone", &[]),
+            schedule(Expiration::AtHeight(150)),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+        let res = execute(
+            deps.as_mut(),
+            env_at(100),
+            info.clone(),
+            schedule(Expiration::Never {}),
+        );
+        assert_eq!(ContractError::ExpirationMustNotBeNever {}, res.unwrap_err());
+        let res = execute(
+            deps.as_mut(),
+            env_at(100),
+            info.clone(),
+            schedule(Expiration::AtHeight(100)),
+        );
+        assert_eq!(ContractError::ExpirationInPast {}, res.unwrap_err());
+
+        let res = execute(
+            deps.as_mut(),
+            env_at(100),
+            info.clone(),
+            schedule(Expiration::AtHeight(150)),
+        )
+        .unwrap();
+        assert_eq!(
+            Response::new()
+                .add_attribute("method", "schedule_value")
+                .add_attribute("sender", "creator")
+                .add_attribute("key", "fee")
+                .add_attribute("value", "Uint128(Uint128(2))")
+                .add_attribute("activate_at", "expiration height: 150"),
+            res
+        );
+
+        let query_fee = |deps: Deps, height: u64| {
+            let msg =
+                QueryMsg::AndrQuery(AndromedaQuery::Get(Some(encode_binary(&"fee").unwrap())));
+            let res: GetValueResponse =
+                from_binary(&query(deps, env_at(height), msg).unwrap()).unwrap();
+            res.value
+        };
+        assert_eq!(
+            Primitive::Uint128(1u128.into()),
+            query_fee(deps.as_ref(), 149)
+        );
+        assert_eq!(
+            Primitive::Uint128(2u128.into()),
+            query_fee(deps.as_ref(), 150)
+        );
+        // The scheduled value is known in advance at its activation height.
+        let scheduled_record = Some(ValueRecord {
+            value: Some(Primitive::Uint128(2u128.into())),
+            setter: "creator".to_string(),
+            height: 150,
+        });
+        assert_eq!(
+            scheduled_record,
+            query_value_at_helper(deps.as_ref(), env_at(100), 150)
+        );
+
+        // Writing the key after the activation records the scheduled value first.
+        let msg = ExecuteMsg::SetValue {
+            key: Some("fee".to_string()),
+            value: Primitive::Uint128(3u128.into()),
+        };
+        execute(deps.as_mut(), env_at(160), info, msg).unwrap();
+        let res: ScheduledValueResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                env_at(160),
+                QueryMsg::ScheduledValue {
+                    key: Some("fee".to_string()),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(None, res.scheduled);
+        assert_eq!(
+            scheduled_record,
+            query_value_at_helper(deps.as_ref(), env_at(160), 155)
+        );
+        assert_eq!(
+            Primitive::Uint128(3u128.into()),
+            query_fee(deps.as_ref(), 160)
+        );
+    }
 }
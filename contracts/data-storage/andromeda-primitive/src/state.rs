@@ -1,6 +1,173 @@
-use common::primitive::Primitive;
-use cw_storage_plus::Map;
+use andromeda_data_storage::primitive::{ScheduledValue, ValueRecord, NAMESPACE_SEPARATOR};
+use common::primitive::{Primitive, PrimitiveType};
+use cosmwasm_std::{BlockInfo, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use cw_utils::Expiration;
 
 pub const DEFAULT_KEY: &str = "default";
 
 pub const DATA: Map<&str, Primitive> = Map::new("data");
+/// The types the values of keys are restricted to.
+pub const SCHEMAS: Map<&str, PrimitiveType> = Map::new("schemas");
+/// The addresses that may write the keys of each namespace, beside the owner and operators.
+pub const NAMESPACE_WRITERS: Map<&str, Vec<String>> = Map::new("namespace_writers");
+/// The recent changes of each key by the height from which they apply.
+pub const HISTORY: Map<(&str, u64), ValueRecord> = Map::new("history");
+/// The value to be set for each key once its activation is reached.
+pub const SCHEDULED_VALUES: Map<&str, ScheduledValue> = Map::new("scheduled_values");
+
+/// The number of changes kept in the history of each key.
+pub const MAX_HISTORY_LENGTH: usize = 20;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+/// Returns the namespace of the key, if it has one.
+pub fn get_namespace(key: &str) -> Option<&str> {
+    key.split_once(NAMESPACE_SEPARATOR)
+        .map(|(namespace, _)| namespace)
+}
+
+/// Returns whether `addr` is a writer of the key's namespace.
+pub fn is_namespace_writer(storage: &dyn Storage, key: &str, addr: &str) -> StdResult<bool> {
+    Ok(match get_namespace(key) {
+        Some(namespace) => NAMESPACE_WRITERS
+            .may_load(storage, namespace)?
+            .unwrap_or_default()
+            .iter()
+            .any(|writer| writer == addr),
+        None => false,
+    })
+}
+
+/// Sets or, if `value` is `None`, deletes the value of the key and records the change in its
+/// history, dropping the oldest changes beyond `MAX_HISTORY_LENGTH`.
+pub fn write_value(
+    storage: &mut dyn Storage,
+    key: &str,
+    value: Option<Primitive>,
+    setter: String,
+    height: u64,
+) -> StdResult<()> {
+    match &value {
+        Some(value) => DATA.save(storage, key, value)?,
+        None => DATA.remove(storage, key),
+    }
+    HISTORY.save(
+        storage,
+        (key, height),
+        &ValueRecord {
+            value,
+            setter,
+            height,
+        },
+    )?;
+    let expired_heights = HISTORY
+        .prefix(key)
+        .keys(storage, None, None, Order::Descending)
+        .skip(MAX_HISTORY_LENGTH)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for height in expired_heights {
+        HISTORY.remove(storage, (key, height));
+    }
+    Ok(())
+}
+
+/// Writes the value scheduled for the key if its activation has been reached. Must be called
+/// before any other change to the key so that its history stays in order.
+pub fn settle_scheduled_value(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    key: &str,
+) -> StdResult<()> {
+    if let Some(scheduled) = SCHEDULED_VALUES.may_load(storage, key)? {
+        if scheduled.activate_at.is_expired(block) {
+            SCHEDULED_VALUES.remove(storage, key);
+            // The exact height is only known for height based activations.
+            let height = match scheduled.activate_at {
+                Expiration::AtHeight(height) => height,
+                _ => block.height,
+            };
+            write_value(
+                storage,
+                key,
+                Some(scheduled.value),
+                scheduled.setter,
+                height,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the current value of the key, taking into account a scheduled value whose activation
+/// has been reached but which has not been written yet.
+pub fn load_value(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    key: &str,
+) -> StdResult<Option<Primitive>> {
+    match SCHEDULED_VALUES.may_load(storage, key)? {
+        Some(scheduled) if scheduled.activate_at.is_expired(block) => Ok(Some(scheduled.value)),
+        _ => DATA.may_load(storage, key),
+    }
+}
+
+/// Returns the latest change of the key at or before `height`.
+pub fn load_value_at(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    key: &str,
+    height: u64,
+) -> StdResult<Option<ValueRecord>> {
+    if let Some(scheduled) = SCHEDULED_VALUES.may_load(storage, key)? {
+        let activation_height = match scheduled.activate_at {
+            Expiration::AtHeight(activation_height) => Some(activation_height),
+            // Time based activations are only known to apply from the current block.
+            _ if scheduled.activate_at.is_expired(block) => Some(block.height),
+            _ => None,
+        };
+        if let Some(activation_height) = activation_height.filter(|h| *h <= height) {
+            return Ok(Some(ValueRecord {
+                value: Some(scheduled.value),
+                setter: scheduled.setter,
+                height: activation_height,
+            }));
+        }
+    }
+    HISTORY
+        .prefix(key)
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(height)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()
+        .map(|record| record.map(|(_, record)| record))
+}
+
+/// Lists the keys with a value in ascending order, optionally only those starting with `prefix`.
+pub fn load_keys(
+    storage: &dyn Storage,
+    prefix: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let prefix = prefix.unwrap_or_default();
+    // Keys are ordered bytewise, so the keys starting with the prefix are those from the prefix
+    // itself up to the first key that does not start with it.
+    let start = match start_after.as_deref() {
+        Some(start_after) if start_after >= prefix.as_str() => Bound::exclusive(start_after),
+        _ => Bound::inclusive(prefix.as_str()),
+    };
+    DATA.keys(storage, Some(start), None, Order::Ascending)
+        .take_while(|key| match key {
+            Ok(key) => key.starts_with(&prefix),
+            Err(_) => true,
+        })
+        .take(limit)
+        .collect()
+}
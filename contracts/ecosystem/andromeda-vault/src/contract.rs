@@ -19,13 +19,14 @@ use cosmwasm_std::{
     DepsMut, Empty, Env, MessageInfo, Order, QueryRequest, Reply, ReplyOn, Response, StdError,
     SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw_utils::nonpayable;
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-vault";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &["update_strategy"];
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -36,6 +37,7 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -69,6 +71,7 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::AndrReceive(msg) => execute_andr_receive(deps, env, info, msg),
         ExecuteMsg::Deposit {
@@ -95,6 +98,13 @@ fn execute_andr_receive(
 ) -> Result<Response, ContractError> {
     match msg {
         AndromedaMsg::Receive(None) => {
+            // Receiving is an alias of `Deposit` so is paused with it.
+            let deposit = ExecuteMsg::Deposit {
+                amount: None,
+                recipient: None,
+                strategy: None,
+            };
+            ADOContract::default().ensure_execute_not_paused(deps.storage, &deposit)?;
             let sender = info.sender.to_string();
             execute_deposit(deps, env, info, None, Some(Recipient::Addr(sender)), None)
         }
@@ -380,41 +390,10 @@ fn execute_update_strategy(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -58,6 +58,43 @@ fn test_deposit() {
     assert_eq!(uluna_balance, extra_sent_funds.amount)
 }
 
+#[test]
+fn test_deposit_paused() {
+    let mut env = mock_env();
+    let mut deps = mock_dependencies_custom(&[]);
+    let owner = mock_info("owner", &[]);
+    instantiate(deps.as_mut(), env.clone(), owner.clone(), InstantiateMsg {}).unwrap();
+    let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause { messages: None });
+    execute(deps.as_mut(), env.clone(), owner.clone(), msg).unwrap();
+
+    let info = mock_info("depositor", &[coin(100, "uusd")]);
+    let paused = ContractError::Paused {
+        msg: "all".to_string(),
+    };
+    let msg = ExecuteMsg::Deposit {
+        recipient: None,
+        amount: None,
+        strategy: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(paused, err);
+    let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Receive(None));
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    assert_eq!(paused, err);
+
+    // Strategies can still be updated.
+    env.contract.address = Addr::unchecked(MOCK_VAULT_CONTRACT);
+    add_strategy(
+        deps.as_mut(),
+        env,
+        owner,
+        StrategyType::Anchor,
+        AndrAddress {
+            identifier: MOCK_ANCHOR_CONTRACT.to_string(),
+        },
+    );
+}
+
 fn add_strategy(
     deps: DepsMut,
     env: Env,
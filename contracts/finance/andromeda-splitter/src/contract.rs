@@ -15,15 +15,16 @@ use common::{
 };
 use cosmwasm_std::{
     attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, StdError, SubMsg, Timestamp, Uint128,
+    Response, SubMsg, Timestamp, Uint128,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw_utils::{nonpayable, Expiration};
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-splitter";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &["update_recipients", "update_lock"];
 // 1 day in seconds
 const ONE_DAY: u64 = 86_400;
 // 1 year in seconds
@@ -68,6 +69,7 @@ pub fn instantiate(
 
     SPLITTER.save(deps.storage, &splitter)?;
 
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -111,24 +113,28 @@ pub fn execute(
         return contract.execute(deps, env, info, andr_msg, execute);
     };
 
-    contract.module_hook::<Response>(
+    let hook_response = contract.module_hook_response(
         deps.storage,
         deps.api,
         deps.querier,
         AndromedaHook::OnExecute {
             sender: info.sender.to_string(),
             payload: encode_binary(&msg)?,
+            merkle_proof: None,
         },
     )?;
 
-    match msg {
+    let res = match msg {
         ExecuteMsg::UpdateRecipients { recipients } => {
             execute_update_recipients(deps, env, info, recipients)
         }
         ExecuteMsg::UpdateLock { lock_time } => execute_update_lock(deps, env, info, lock_time),
         ExecuteMsg::Send {} => execute_send(deps, info),
         ExecuteMsg::AndrReceive(msg) => execute_andromeda(deps, env, info, msg),
-    }
+    }?;
+    Ok(res
+        .add_submessages(hook_response.msgs)
+        .add_events(hook_response.events))
 }
 
 pub fn execute_andromeda(
@@ -280,41 +286,10 @@ fn execute_update_lock(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[entry_point]
@@ -1,5 +1,5 @@
 use cosmwasm_std::{
-    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, SubMsg,
+    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, SubMsg,
 };
 
 use crate::state::{escrows, get_key, get_keys_for_recipient};
@@ -13,8 +13,7 @@ use common::{
     encode_binary,
     error::ContractError,
 };
-use cw2::{get_contract_version, set_contract_version};
-use semver::Version;
+use cw2::set_contract_version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-timelock";
@@ -58,17 +57,18 @@ pub fn execute(
         return contract.execute(deps, env, info, andr_msg, execute);
     };
 
-    contract.module_hook::<Response>(
+    let hook_response = contract.module_hook_response(
         deps.storage,
         deps.api,
         deps.querier,
         AndromedaHook::OnExecute {
             sender: info.sender.to_string(),
             payload: encode_binary(&msg)?,
+            merkle_proof: None,
         },
     )?;
 
-    match msg {
+    let res = match msg {
         ExecuteMsg::HoldFunds {
             condition,
             recipient,
@@ -85,7 +85,10 @@ pub fn execute(
         ExecuteMsg::AndrReceive(msg) => {
             ADOContract::default().execute(deps, env, info, msg, execute)
         }
-    }
+    }?;
+    Ok(res
+        .add_submessages(hook_response.msgs)
+        .add_events(hook_response.events))
 }
 
 fn execute_hold_funds(
@@ -202,41 +205,8 @@ fn execute_release_specific_funds(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[entry_point]
@@ -25,6 +25,7 @@ fn test_modules() {
         modules: Some(vec![Module {
             module_type: "address_list".to_string(),
             is_mutable: false,
+            priority: None,
             address: AndrAddress {
                 identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
             },
@@ -76,6 +77,7 @@ fn test_update_app_contract() {
             identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
 
     let info = mock_info("app_contract", &[]);
@@ -2,13 +2,12 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     ensure, Binary, Coin, CosmosMsg, Deps, DepsMut, DistributionMsg, Env, GovMsg, MessageInfo,
-    QuerierWrapper, Response, StakingMsg, StdError, Uint128, VoteOption,
+    QuerierWrapper, Response, StakingMsg, Uint128, VoteOption,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw_asset::AssetInfo;
 
 use cw_utils::nonpayable;
-use semver::Version;
 use std::cmp;
 
 use ado_base::ADOContract;
@@ -25,6 +24,8 @@ use crate::state::{
 
 const CONTRACT_NAME: &str = "crates.io:andromeda-vesting";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Governance messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &["vote"];
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -44,6 +45,7 @@ pub fn instantiate(
 
     CONFIG.save(deps.storage, &config)?;
 
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -66,6 +68,7 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::AndrReceive(msg) => {
             ADOContract::default().execute(deps, env, info, msg, execute)
@@ -519,41 +522,10 @@ fn get_set_withdraw_address_msg(address: String) -> CosmosMsg {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -12,7 +12,11 @@ use crate::{
 };
 
 use andromeda_finance::vesting::{BatchResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
-use common::{ado_base::recipient::Recipient, error::ContractError, withdraw::WithdrawalType};
+use common::{
+    ado_base::{recipient::Recipient, AndromedaMsg},
+    error::ContractError,
+    withdraw::WithdrawalType,
+};
 
 const DEFAULT_VALIDATOR: &str = "validator";
 const UNBONDING_BLOCK_DURATION: u64 = 5;
@@ -1572,6 +1576,36 @@ fn test_withdraw_rewards_unauthorized() {
     assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
 }
 
+#[test]
+fn test_create_batch_paused() {
+    let mut deps = mock_dependencies();
+    init(deps.as_mut());
+    let info = mock_info("owner", &coins(100, "uusd"));
+    let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause { messages: None });
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::CreateBatch {
+        lockup_duration: None,
+        release_unit: 10,
+        release_amount: WithdrawalType::Amount(Uint128::new(10)),
+        validator_to_delegate_to: None,
+    };
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    assert_eq!(
+        ContractError::Paused {
+            msg: "all".to_string()
+        },
+        res.unwrap_err()
+    );
+
+    // Voting is still allowed.
+    let msg = ExecuteMsg::Vote {
+        proposal_id: 1,
+        vote: VoteOption::Yes,
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+}
+
 #[test]
 fn test_vote_unauthorized() {
     let mut deps = mock_dependencies();
@@ -35,6 +35,7 @@ fn test_modules() {
         modules: Some(vec![Module {
             module_type: "address_list".to_string(),
             is_mutable: false,
+            priority: None,
             address: AndrAddress {
                 identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
             },
@@ -90,6 +91,7 @@ fn test_update_app_contract() {
             identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
 
     let info = mock_info("app_contract", &[]);
@@ -139,6 +141,7 @@ fn test_update_app_contract_invalid_recipient() {
             identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
 
     let info = mock_info("app_contract", &[]);
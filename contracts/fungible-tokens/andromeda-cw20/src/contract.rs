@@ -2,25 +2,25 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     ensure, from_binary, to_binary, Addr, Api, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg, attr,
+    Response, StdResult, Storage, SubMsg, Uint128, WasmMsg, attr,
 };
 
 
 use ado_base::ADOContract;
 use andromeda_fungible_tokens::{cw20::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, CW20HookMsg, GetTokenPriceResponse} };
 use common::{
-    ado_base::{hooks::AndromedaHook, InstantiateMsg as BaseInstantiateMsg},
+    ado_base::{hooks::AndromedaHook, roles::MINTER, InstantiateMsg as BaseInstantiateMsg},
     error::ContractError,
     Funds, encode_binary,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
 use crate::state::{TOKEN_PRICE, USDC_CONTRACT,BALANCES, ALLOWANCES, GET_TOKEN_INFO, TokenPrice, GetTokenInfo,  };
 use cw20_base::{
     contract::{execute as execute_cw20, instantiate as cw20_instantiate, query as query_cw20},
-    allowances::deduct_allowance
+    allowances::deduct_allowance,
+    state::TOKEN_INFO as CW20_TOKEN_INFO,
 };
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-cw20";
@@ -81,23 +81,25 @@ pub fn execute(
         return contract.execute(deps, env, info, andr_msg, execute);
     };
 
-    contract.module_hook::<Response>(
+    let hook_response = contract.module_hook_response(
         deps.storage,
         deps.api, 
         deps.querier,
         AndromedaHook::OnExecute {
             sender: info.sender.to_string(),
             payload: to_binary(&msg)?,
+            merkle_proof: None,
         },
     )?;
 
-    match msg {
+    let res = match msg {
         ExecuteMsg::IncreaseAllowancePurchase { spender, amount, owner } => execute_increase_allowance_purchase(deps, env, info, owner, spender, amount),
         ExecuteMsg::UpdatePrice { new_price } =>  execute_update_new_price(deps, info, new_price),
         ExecuteMsg::Transfer { recipient, amount } => {
             execute_transfer(deps, env, info, recipient, amount) 
         },
         ExecuteMsg::Receive(msg) => execute_receive_cw20(deps, env, info, msg),
+        ExecuteMsg::Mint { recipient, amount } => execute_mint(deps, env, info, recipient, amount),
         ExecuteMsg::Send {
             contract,
             amount,
@@ -105,11 +107,43 @@ pub fn execute(
         } => execute_send(deps, env, info, contract, amount, msg),
         ExecuteMsg::AndrReceive(msg) => contract.execute(deps, env, info, msg, execute),
         _ => Ok(execute_cw20(deps, env, info, msg.into())?),
-    }
+    }?;
+    Ok(res
+        .add_submessages(hook_response.msgs)
+        .add_events(hook_response.events))
 
 }
 
 
+/// Holders of the minter role mint on behalf of the configured minter.
+fn execute_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let info = if ADOContract::default().has_role(deps.storage, MINTER, info.sender.as_str()) {
+        let minter = CW20_TOKEN_INFO
+            .load(deps.storage)?
+            .mint
+            .ok_or(ContractError::Unauthorized {})?
+            .minter;
+        MessageInfo {
+            sender: minter,
+            funds: info.funds,
+        }
+    } else {
+        info
+    };
+    Ok(execute_cw20(
+        deps,
+        env,
+        info,
+        Cw20ExecuteMsg::Mint { recipient, amount },
+    )?)
+}
+
 fn execute_update_new_price(
     deps: DepsMut,
     info: MessageInfo,
@@ -367,41 +401,8 @@ fn filter_out_cw20_messages(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -7,6 +7,7 @@ use andromeda_testing::testing::mock_querier::{
 use common::{
     ado_base::{
         modules::{Module, ADDRESS_LIST, RATES, RECEIPT},
+        roles::MINTER,
         AndromedaMsg, AndromedaQuery,
     },
     app::AndrAddress,
@@ -16,7 +17,7 @@ use cosmwasm_std::{
     testing::{mock_env, mock_info, mock_dependencies_with_balance},
     to_binary, Addr, CosmosMsg, Event, Response, StdError, SubMsg, Uint128, WasmMsg, DepsMut, coins, from_binary,
 };
-use cw20::{Cw20Coin, Cw20ReceiveMsg, AllowanceResponse, };
+use cw20::{Cw20Coin, Cw20ReceiveMsg, AllowanceResponse, MinterResponse};
 use cw20_base::{state::{BALANCES}, allowances};
 
 fn init(
@@ -146,6 +147,7 @@ fn test_update_price_fails() {
                     identifier: MOCK_RECEIPT_CONTRACT.to_owned(),
                 },
                 is_mutable: false,
+                priority: None,
             },
             Module {
                 module_type: RATES.to_owned(),
@@ -153,6 +155,7 @@ fn test_update_price_fails() {
                     identifier: MOCK_RATES_CONTRACT.to_owned(),
                 },
                 is_mutable: false,
+                priority: None,
             },
             Module {
                 module_type: ADDRESS_LIST.to_owned(),
@@ -160,6 +163,7 @@ fn test_update_price_fails() {
                     identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
                 },
                 is_mutable: false,
+                priority: None,
             },
         ];
     
@@ -225,6 +229,8 @@ fn test_update_price_fails() {
                 receipt: Receipt {
                     events: vec![Event::new("Royalty"), Event::new("Tax")],
                 },
+                sender: Some("sender".to_string()),
+                hook: None,
             })
             .unwrap(),
             funds: vec![],
@@ -333,6 +339,7 @@ fn test_send() {
                 identifier: MOCK_RECEIPT_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
         Module {
             module_type: RATES.to_owned(),
@@ -340,6 +347,7 @@ fn test_send() {
                 identifier: MOCK_RATES_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
         Module {
             module_type: ADDRESS_LIST.to_owned(),
@@ -347,6 +355,7 @@ fn test_send() {
                 identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
     ];
 
@@ -414,6 +423,8 @@ fn test_send() {
             receipt: Receipt {
                 events: vec![Event::new("Royalty"), Event::new("Tax")],
             },
+            sender: Some("sender".to_string()),
+            hook: None,
         })
         .unwrap(),
         funds: vec![],
@@ -475,6 +486,7 @@ fn test_update_app_contract() {
             identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
 
     let info = mock_info("app_contract", &[]);
@@ -510,3 +522,47 @@ fn test_update_app_contract() {
         res
     );
 }
+
+#[test]
+fn test_mint_minter_role() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let owner = mock_info("owner", &[]);
+    let instantiate_msg = InstantiateMsg {
+        token_price: Uint128::from(1u128),
+        name: "Name".into(),
+        symbol: "Symbol".into(),
+        decimals: 6,
+        initial_balances: vec![],
+        mint: Some(MinterResponse {
+            minter: "minter".to_string(),
+            cap: None,
+        }),
+        marketing: None,
+        modules: None,
+        usdc_address: "usdc".into(),
+        owner: "sender".into(),
+    };
+    instantiate(deps.as_mut(), mock_env(), owner.clone(), instantiate_msg).unwrap();
+
+    let msg = ExecuteMsg::Mint {
+        recipient: "recipient".to_string(),
+        amount: 100u128.into(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("role_holder", &[]), msg.clone())
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let grant_msg = ExecuteMsg::AndrReceive(AndromedaMsg::GrantRole {
+        role: MINTER.to_string(),
+        address: "role_holder".to_string(),
+    });
+    execute(deps.as_mut(), mock_env(), owner, grant_msg).unwrap();
+
+    execute(deps.as_mut(), mock_env(), mock_info("role_holder", &[]), msg).unwrap();
+    assert_eq!(
+        Uint128::from(100u128),
+        BALANCES
+            .load(deps.as_ref().storage, &Addr::unchecked("recipient"))
+            .unwrap()
+    );
+}
@@ -12,7 +12,9 @@ use andromeda_fungible_tokens::lockdrop::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, StateResponse,
     UserInfoResponse,
 };
-use common::{error::ContractError, expiration::MILLISECONDS_TO_NANOSECONDS_RATIO};
+use common::{
+    ado_base::AndromedaMsg, error::ContractError, expiration::MILLISECONDS_TO_NANOSECONDS_RATIO,
+};
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
 const MOCK_INCENTIVE_TOKEN: &str = "mock_incentive_token";
@@ -305,6 +307,26 @@ fn test_deposit_native() {
     );
 }
 
+#[test]
+fn test_deposit_native_paused() {
+    let mut deps = mock_dependencies();
+    init(deps.as_mut()).unwrap();
+    let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause {
+        messages: Some(vec!["deposit_native".to_string()]),
+    });
+    execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::DepositNative {};
+    let info = mock_info("sender", &coins(100, "uusd"));
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    assert_eq!(
+        ContractError::Paused {
+            msg: "deposit_native".to_string()
+        },
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn test_deposit_native_zero_amount() {
     let mut deps = mock_dependencies();
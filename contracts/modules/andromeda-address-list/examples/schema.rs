@@ -4,7 +4,8 @@ use std::fs::create_dir_all;
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
 use andromeda_modules::address_list::{
-    ExecuteMsg, IncludesAddressResponse, InstantiateMsg, QueryMsg,
+    AddressesResponse, ExecuteMsg, IncludesAddressResponse, InstantiateMsg, MessageRulesResponse,
+    QueryMsg,
 };
 
 fn main() {
@@ -18,4 +19,6 @@ fn main() {
     export_schema(&schema_for!(QueryMsg), &out_dir);
 
     export_schema(&schema_for!(IncludesAddressResponse), &out_dir);
+    export_schema(&schema_for!(AddressesResponse), &out_dir);
+    export_schema(&schema_for!(MessageRulesResponse), &out_dir);
 }
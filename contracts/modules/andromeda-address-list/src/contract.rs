@@ -1,27 +1,48 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, ensure, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    attr, ensure, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 
-use crate::state::{add_address, includes_address, remove_address, IS_INCLUSIVE};
-use ado_base::ADOContract;
+use crate::state::{
+    add_address, includes_address, load_addresses, load_message_rule, load_message_rules,
+    remove_address, DEFAULT_MESSAGE_RULE, IS_INCLUSIVE, LEGACY_ADDRESS_LIST, MESSAGE_RULES,
+};
+use ado_base::{migrate::MigrationStep, ADOContract};
 use andromeda_modules::address_list::{
-    ExecuteMsg, IncludesAddressResponse, InstantiateMsg, MigrateMsg, QueryMsg,
+    AddressEntry, AddressInfo, AddressesResponse, ExecuteMsg, IncludesAddressResponse,
+    InstantiateMsg, MessageRule, MessageRulesResponse, MigrateMsg, QueryMsg,
 };
 use common::{
-    ado_base::{hooks::AndromedaHook, AndromedaQuery, InstantiateMsg as BaseInstantiateMsg},
+    ado_base::{
+        hooks::{AndromedaHook, HookResponse},
+        AndromedaQuery, InstantiateMsg as BaseInstantiateMsg,
+    },
     encode_binary,
     error::ContractError,
-    parse_message,
+    expiration::expiration_from_milliseconds,
+    get_message_name, parse_message,
 };
 use cw_utils::nonpayable;
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-addresslist";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Operator messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &[
+    "add_address",
+    "remove_address",
+    "add_addresses",
+    "remove_addresses",
+    "set_message_rule",
+    "set_default_message_rule",
+];
+
+const MIGRATION_STEPS: [MigrationStep; 1] = [MigrationStep {
+    version: "0.2.0",
+    migrate: migrate_legacy_addresses,
+}];
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -32,6 +53,7 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     IS_INCLUSIVE.save(deps.storage, &msg.is_inclusive)?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -54,12 +76,23 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::AndrReceive(msg) => {
             ADOContract::default().execute(deps, env, info, msg, execute)
         }
         ExecuteMsg::AddAddress { address } => execute_add_address(deps, info, address),
         ExecuteMsg::RemoveAddress { address } => execute_remove_address(deps, info, address),
+        ExecuteMsg::AddAddresses { addresses } => execute_add_addresses(deps, env, info, addresses),
+        ExecuteMsg::RemoveAddresses { addresses } => {
+            execute_remove_addresses(deps, info, addresses)
+        }
+        ExecuteMsg::SetMessageRule { message_name, rule } => {
+            execute_set_message_rule(deps, info, message_name, rule)
+        }
+        ExecuteMsg::SetDefaultMessageRule { rule } => {
+            execute_set_default_message_rule(deps, info, rule)
+        }
     }
 }
 
@@ -73,7 +106,14 @@ fn execute_add_address(
         ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
-    add_address(deps.storage, &address)?;
+    add_address(
+        deps.storage,
+        &AddressInfo {
+            address: address.clone(),
+            expiration: None,
+            tags: vec![],
+        },
+    )?;
 
     Ok(Response::new().add_attributes(vec![
         attr("action", "add_address"),
@@ -101,65 +141,191 @@ fn execute_remove_address(
     ]))
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
+fn execute_add_addresses(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addresses: Vec<AddressEntry>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure!(
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
 
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
+    let mut added: Vec<String> = Vec::with_capacity(addresses.len());
+    for entry in addresses {
+        let expiration = entry
+            .expiration
+            .map(expiration_from_milliseconds)
+            .transpose()?;
+        if let Some(expiration) = expiration {
+            ensure!(
+                !expiration.is_expired(&env.block),
+                ContractError::ExpirationInPast {}
+            );
+        }
+        add_address(
+            deps.storage,
+            &AddressInfo {
+                address: entry.address.clone(),
+                expiration,
+                tags: entry.tags,
+            },
+        )?;
+        added.push(entry.address);
+    }
 
-    let contract = ADOContract::default();
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "add_addresses"),
+        attr("addresses", added.join(",")),
+    ]))
+}
 
+fn execute_remove_addresses(
+    deps: DepsMut,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
     ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
     );
 
-    // New version has to be newer/greater than the old version
+    for address in addresses.iter() {
+        remove_address(deps.storage, address);
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "remove_addresses"),
+        attr("addresses", addresses.join(",")),
+    ]))
+}
+
+fn execute_set_message_rule(
+    deps: DepsMut,
+    info: MessageInfo,
+    message_name: String,
+    rule: Option<MessageRule>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
     ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+
+    let rule_attr = match rule {
+        Some(rule) => {
+            MESSAGE_RULES.save(deps.storage, &message_name, &rule)?;
+            message_rule_name(rule)
         }
+        None => {
+            MESSAGE_RULES.remove(deps.storage, &message_name);
+            "default"
+        }
+    };
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_message_rule"),
+        attr("message_name", message_name),
+        attr("rule", rule_attr),
+    ]))
+}
+
+fn execute_set_default_message_rule(
+    deps: DepsMut,
+    info: MessageInfo,
+    rule: MessageRule,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure!(
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
     );
 
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    DEFAULT_MESSAGE_RULE.save(deps.storage, &rule)?;
 
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_default_message_rule"),
+        attr("rule", message_rule_name(rule)),
+    ]))
+}
 
-    Ok(Response::default())
+fn message_rule_name(rule: MessageRule) -> &'static str {
+    match rule {
+        MessageRule::Open => "open",
+        MessageRule::Restricted => "restricted",
+    }
 }
 
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &MIGRATION_STEPS)
+}
+
+/// Moves the addresses stored prior to version 0.2.0 to the current address list. Migrated
+/// addresses do not expire and have every permission tag.
+fn migrate_legacy_addresses(deps: DepsMut, _env: Env) -> Result<(), ContractError> {
+    let addresses: StdResult<Vec<(String, bool)>> = LEGACY_ADDRESS_LIST
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect();
+    for (address, included) in addresses? {
+        LEGACY_ADDRESS_LIST.remove(deps.storage, &address);
+        if included {
+            add_address(
+                deps.storage,
+                &AddressInfo {
+                    address,
+                    expiration: None,
+                    tags: vec![],
+                },
+            )?;
+        }
+    }
+    Ok(())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
-        QueryMsg::IncludesAddress { address } => encode_binary(&query_address(deps, &address)?),
-        QueryMsg::AndrHook(msg) => handle_andr_hook(deps, msg),
+        QueryMsg::IncludesAddress { address, tag } => {
+            encode_binary(&query_address(deps, env, &address, tag.as_deref())?)
+        }
+        QueryMsg::Addresses { start_after, limit } => {
+            encode_binary(&query_addresses(deps, start_after, limit)?)
+        }
+        QueryMsg::MessageRules { start_after, limit } => {
+            encode_binary(&query_message_rules(deps, start_after, limit)?)
+        }
+        QueryMsg::AndrHook(msg) => handle_andr_hook(deps, env, msg),
         QueryMsg::AndrQuery(msg) => handle_andromeda_query(deps, env, msg),
     }
 }
 
-fn handle_andr_hook(deps: Deps, msg: AndromedaHook) -> Result<Binary, ContractError> {
+fn handle_andr_hook(deps: Deps, env: Env, msg: AndromedaHook) -> Result<Binary, ContractError> {
     match msg {
-        AndromedaHook::OnExecute { sender, .. } => {
-            let is_included = includes_address(deps.storage, &sender)?;
+        AndromedaHook::OnExecute {
+            sender, payload, ..
+        } => {
+            // The executed message's name selects its rule and is the permission tag required to
+            // execute it.
+            let message_name = get_message_name(&payload).ok();
+            if load_message_rule(deps.storage, message_name.as_deref())? == MessageRule::Open {
+                return Ok(to_binary(&None::<HookResponse>)?);
+            }
+            let tag = message_name;
+            let is_included = includes_address(deps.storage, &env.block, &sender, tag.as_deref())?;
             let is_inclusive = IS_INCLUSIVE.load(deps.storage)?;
             if is_included != is_inclusive {
                 Err(ContractError::Unauthorized {})
             } else {
-                Ok(to_binary(&None::<Response>)?)
+                Ok(to_binary(&None::<HookResponse>)?)
             }
         }
-        _ => Ok(to_binary(&None::<Response>)?),
+        _ => Ok(to_binary(&None::<HookResponse>)?),
     }
 }
 
@@ -171,22 +337,49 @@ fn handle_andromeda_query(
     match msg {
         AndromedaQuery::Get(data) => {
             let address: String = parse_message(&data)?;
-            encode_binary(&query_address(deps, &address)?)
+            encode_binary(&query_address(deps, env, &address, None)?)
         }
         _ => ADOContract::default().query(deps, env, msg, query),
     }
 }
 
-fn query_address(deps: Deps, address: &str) -> Result<IncludesAddressResponse, ContractError> {
+fn query_address(
+    deps: Deps,
+    env: Env,
+    address: &str,
+    tag: Option<&str>,
+) -> Result<IncludesAddressResponse, ContractError> {
     Ok(IncludesAddressResponse {
-        included: includes_address(deps.storage, address)?,
+        included: includes_address(deps.storage, &env.block, address, tag)?,
+    })
+}
+
+fn query_addresses(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<AddressesResponse, ContractError> {
+    Ok(AddressesResponse {
+        addresses: load_addresses(deps.storage, start_after, limit)?,
+    })
+}
+
+fn query_message_rules(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<MessageRulesResponse, ContractError> {
+    Ok(MessageRulesResponse {
+        default_rule: load_message_rule(deps.storage, None)?,
+        rules: load_message_rules(deps.storage, start_after, limit)?,
     })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::ADDRESS_LIST;
+    use crate::state::ADDRESSES;
+    use andromeda_modules::address_list::MessageRuleInfo;
     use cosmwasm_std::from_binary;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
@@ -239,10 +432,17 @@ mod tests {
         ]);
         assert_eq!(expected, res);
 
-        let whitelisted = ADDRESS_LIST.load(deps.as_ref().storage, address).unwrap();
-        assert!(whitelisted);
+        let whitelisted = ADDRESSES.load(deps.as_ref().storage, address).unwrap();
+        assert_eq!(
+            AddressInfo {
+                address: address.to_string(),
+                expiration: None,
+                tags: vec![],
+            },
+            whitelisted
+        );
 
-        let included = ADDRESS_LIST.load(deps.as_ref().storage, "111").unwrap_err();
+        let included = ADDRESSES.load(deps.as_ref().storage, "111").unwrap_err();
 
         match included {
             cosmwasm_std::StdError::NotFound { .. } => {}
@@ -286,7 +486,7 @@ mod tests {
         ]);
         assert_eq!(expected, res);
 
-        let included_is_err = ADDRESS_LIST.load(deps.as_ref().storage, address).is_err();
+        let included_is_err = ADDRESSES.load(deps.as_ref().storage, address).is_err();
         assert!(included_is_err);
 
         //add address for unregistered operator
@@ -317,6 +517,7 @@ mod tests {
         let msg = QueryMsg::AndrHook(AndromedaHook::OnExecute {
             sender: address.to_string(),
             payload: encode_binary(&"".to_string()).unwrap(),
+            merkle_proof: None,
         });
 
         let res: Option<Response> =
@@ -326,6 +527,7 @@ mod tests {
         let msg = QueryMsg::AndrHook(AndromedaHook::OnExecute {
             sender: "random".to_string(),
             payload: encode_binary(&"".to_string()).unwrap(),
+            merkle_proof: None,
         });
 
         let res_err: ContractError = query(deps.as_ref(), mock_env(), msg).unwrap_err();
@@ -357,6 +559,7 @@ mod tests {
         let msg = QueryMsg::AndrHook(AndromedaHook::OnExecute {
             sender: "random".to_string(),
             payload: encode_binary(&"".to_string()).unwrap(),
+            merkle_proof: None,
         });
 
         let res: Option<Response> =
@@ -366,6 +569,7 @@ mod tests {
         let msg = QueryMsg::AndrHook(AndromedaHook::OnExecute {
             sender: address.to_string(),
             payload: encode_binary(&"".to_string()).unwrap(),
+            merkle_proof: None,
         });
 
         let res_err: ContractError = query(deps.as_ref(), mock_env(), msg).unwrap_err();
@@ -378,9 +582,15 @@ mod tests {
 
         let address = "whitelistee";
 
-        ADDRESS_LIST
-            .save(deps.as_mut().storage, address, &true)
-            .unwrap();
+        add_address(
+            deps.as_mut().storage,
+            &AddressInfo {
+                address: address.to_string(),
+                expiration: None,
+                tags: vec![],
+            },
+        )
+        .unwrap();
 
         let msg = QueryMsg::AndrQuery(AndromedaQuery::Get(Some(encode_binary(&address).unwrap())));
 
@@ -389,4 +599,294 @@ mod tests {
 
         assert_eq!(IncludesAddressResponse { included: true }, res);
     }
+
+    fn query_included(deps: Deps, env: Env, address: &str, tag: Option<&str>) -> bool {
+        let msg = QueryMsg::IncludesAddress {
+            address: address.to_string(),
+            tag: tag.map(String::from),
+        };
+        let res: IncludesAddressResponse = from_binary(&query(deps, env, msg).unwrap()).unwrap();
+        res.included
+    }
+
+    #[test]
+    fn test_add_remove_addresses() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("creator", &[]);
+        init(deps.as_mut(), info.clone());
+
+        let now = env.block.time.nanos() / 1_000_000;
+        let msg = ExecuteMsg::AddAddresses {
+            addresses: vec![
+                AddressEntry {
+                    address: "expiring".to_string(),
+                    expiration: Some(now + 1000),
+                    tags: vec![],
+                },
+                AddressEntry {
+                    address: "minter".to_string(),
+                    expiration: None,
+                    tags: vec!["mint".to_string()],
+                },
+            ],
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert_eq!(
+            Response::new().add_attributes(vec![
+                attr("action", "add_addresses"),
+                attr("addresses", "expiring,minter"),
+            ]),
+            res
+        );
+
+        assert!(query_included(
+            deps.as_ref(),
+            env.clone(),
+            "expiring",
+            Some("bid")
+        ));
+        assert!(query_included(
+            deps.as_ref(),
+            env.clone(),
+            "minter",
+            Some("mint")
+        ));
+        assert!(!query_included(
+            deps.as_ref(),
+            env.clone(),
+            "minter",
+            Some("bid")
+        ));
+        assert!(query_included(deps.as_ref(), env.clone(), "minter", None));
+
+        // The entry expires.
+        let mut later_env = env.clone();
+        later_env.block.time = later_env.block.time.plus_seconds(1);
+        assert!(!query_included(deps.as_ref(), later_env, "expiring", None));
+
+        let msg = ExecuteMsg::AddAddresses {
+            addresses: vec![AddressEntry {
+                address: "expired".to_string(),
+                expiration: Some(now),
+                tags: vec![],
+            }],
+        };
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(ContractError::ExpirationInPast {}, res.unwrap_err());
+
+        let msg = ExecuteMsg::RemoveAddresses {
+            addresses: vec!["expiring".to_string(), "minter".to_string()],
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        assert!(!query_included(
+            deps.as_ref(),
+            env.clone(),
+            "expiring",
+            None
+        ));
+        assert!(!query_included(deps.as_ref(), env, "minter", None));
+    }
+
+    #[test]
+    fn test_query_addresses() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("creator", &[]);
+        init(deps.as_mut(), info.clone());
+
+        let addresses: Vec<AddressEntry> = ["c", "a", "b"]
+            .iter()
+            .map(|address| AddressEntry {
+                address: address.to_string(),
+                expiration: None,
+                tags: vec![],
+            })
+            .collect();
+        let msg = ExecuteMsg::AddAddresses { addresses };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let query_addresses = |start_after: Option<&str>, limit: Option<u32>| -> Vec<String> {
+            let msg = QueryMsg::Addresses {
+                start_after: start_after.map(String::from),
+                limit,
+            };
+            let res: AddressesResponse =
+                from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+            res.addresses.into_iter().map(|info| info.address).collect()
+        };
+        assert_eq!(vec!["a", "b", "c"], query_addresses(None, None));
+        assert_eq!(vec!["a", "b"], query_addresses(None, Some(2)));
+        assert_eq!(vec!["c"], query_addresses(Some("b"), Some(2)));
+    }
+
+    #[test]
+    fn test_execute_hook_tags() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("creator", &[]);
+        init(deps.as_mut(), info.clone());
+
+        let msg = ExecuteMsg::AddAddresses {
+            addresses: vec![AddressEntry {
+                address: "minter".to_string(),
+                expiration: Some(env.block.time.nanos() / 1_000_000 + 1000),
+                tags: vec!["mint".to_string()],
+            }],
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let hook = |env: Env, payload: &str| {
+            let msg = QueryMsg::AndrHook(AndromedaHook::OnExecute {
+                sender: "minter".to_string(),
+                payload: Binary::from(payload.as_bytes()),
+                merkle_proof: None,
+            });
+            query(deps.as_ref(), env, msg)
+        };
+        assert!(hook(env.clone(), r#"{"mint":{"token_id":"1"}}"#).is_ok());
+        assert_eq!(
+            ContractError::Unauthorized {},
+            hook(env.clone(), r#"{"place_bid":{}}"#).unwrap_err()
+        );
+
+        let mut later_env = env;
+        later_env.block.time = later_env.block.time.plus_seconds(1);
+        assert_eq!(
+            ContractError::Unauthorized {},
+            hook(later_env, r#"{"mint":{"token_id":"1"}}"#).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_migrate_legacy_addresses() {
+        let mut deps = mock_dependencies();
+        init(deps.as_mut(), mock_info("creator", &[]));
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.3").unwrap();
+        LEGACY_ADDRESS_LIST
+            .save(deps.as_mut().storage, "whitelistee", &true)
+            .unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        assert!(LEGACY_ADDRESS_LIST
+            .may_load(deps.as_ref().storage, "whitelistee")
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            AddressInfo {
+                address: "whitelistee".to_string(),
+                expiration: None,
+                tags: vec![],
+            },
+            ADDRESSES
+                .load(deps.as_ref().storage, "whitelistee")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_execute_hook_message_rules() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("creator", &[]);
+        init(deps.as_mut(), info.clone());
+
+        let msg = ExecuteMsg::AddAddress {
+            address: "whitelistee".to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::SetMessageRule {
+            message_name: "claim_refund".to_string(),
+            rule: Some(MessageRule::Open),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert_eq!(
+            Response::new().add_attributes(vec![
+                attr("action", "set_message_rule"),
+                attr("message_name", "claim_refund"),
+                attr("rule", "open"),
+            ]),
+            res
+        );
+
+        let hook = |deps: Deps, sender: &str, payload: &str| {
+            let msg = QueryMsg::AndrHook(AndromedaHook::OnExecute {
+                sender: sender.to_string(),
+                payload: Binary::from(payload.as_bytes()),
+                merkle_proof: None,
+            });
+            query(deps, mock_env(), msg)
+        };
+        assert!(hook(deps.as_ref(), "whitelistee", r#"{"purchase":{}}"#).is_ok());
+        assert_eq!(
+            ContractError::Unauthorized {},
+            hook(deps.as_ref(), "anyone", r#"{"purchase":{}}"#).unwrap_err()
+        );
+        assert!(hook(deps.as_ref(), "anyone", r#"{"claim_refund":{}}"#).is_ok());
+
+        // Open every message except `purchase`.
+        let msg = ExecuteMsg::SetDefaultMessageRule {
+            rule: MessageRule::Open,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let msg = ExecuteMsg::SetMessageRule {
+            message_name: "purchase".to_string(),
+            rule: Some(MessageRule::Restricted),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert!(hook(deps.as_ref(), "anyone", r#"{"end_sale":{}}"#).is_ok());
+        assert_eq!(
+            ContractError::Unauthorized {},
+            hook(deps.as_ref(), "anyone", r#"{"purchase":{}}"#).unwrap_err()
+        );
+
+        let msg = QueryMsg::MessageRules {
+            start_after: None,
+            limit: None,
+        };
+        let res: MessageRulesResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(
+            MessageRulesResponse {
+                default_rule: MessageRule::Open,
+                rules: vec![
+                    MessageRuleInfo {
+                        message_name: "claim_refund".to_string(),
+                        rule: MessageRule::Open,
+                    },
+                    MessageRuleInfo {
+                        message_name: "purchase".to_string(),
+                        rule: MessageRule::Restricted,
+                    },
+                ],
+            },
+            res
+        );
+
+        // Removing the rule reverts `purchase` to the default rule.
+        let msg = ExecuteMsg::SetMessageRule {
+            message_name: "purchase".to_string(),
+            rule: None,
+        };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+        assert!(hook(deps.as_ref(), "anyone", r#"{"purchase":{}}"#).is_ok());
+    }
 }
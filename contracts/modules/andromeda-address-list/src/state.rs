@@ -1,28 +1,78 @@
-use cosmwasm_std::{StdResult, Storage};
-use cw_storage_plus::{Item, Map};
+use andromeda_modules::address_list::{AddressInfo, MessageRule, MessageRuleInfo};
+use cosmwasm_std::{BlockInfo, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
 
-pub const ADDRESS_LIST: Map<&str, bool> = Map::new("addresslist");
+/// The address list prior to version 0.2.0, which only stored whether an address was included.
+pub const LEGACY_ADDRESS_LIST: Map<&str, bool> = Map::new("addresslist");
+pub const ADDRESSES: Map<&str, AddressInfo> = Map::new("addresses");
 pub const IS_INCLUSIVE: Item<bool> = Item::new("is_inclusive");
+/// The rules of individual execute messages, keyed by message name.
+pub const MESSAGE_RULES: Map<&str, MessageRule> = Map::new("message_rules");
+pub const DEFAULT_MESSAGE_RULE: Item<MessageRule> = Item::new("default_message_rule");
 
-/// Add an address to the address list.
-pub fn add_address(storage: &mut dyn Storage, addr: &str) -> StdResult<()> {
-    ADDRESS_LIST.save(storage, addr, &true)
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+/// Add an address to the address list, replacing any existing entry.
+pub fn add_address(storage: &mut dyn Storage, info: &AddressInfo) -> StdResult<()> {
+    ADDRESSES.save(storage, &info.address, info)
 }
-/// Remove an address from the address list. Errors if the address is not currently included.
+/// Remove an address from the address list.
 pub fn remove_address(storage: &mut dyn Storage, addr: &str) {
-    // Check if the address is included in the address list before removing
-    if ADDRESS_LIST.has(storage, addr) {
-        ADDRESS_LIST.remove(storage, addr);
-    };
+    ADDRESSES.remove(storage, addr);
+}
+/// Query if a given address is included in the address list at the given block, optionally with
+/// the given permission tag.
+pub fn includes_address(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    addr: &str,
+    tag: Option<&str>,
+) -> StdResult<bool> {
+    Ok(ADDRESSES
+        .may_load(storage, addr)?
+        .map(|info| info.is_included(block, tag))
+        .unwrap_or(false))
 }
-/// Query if a given address is included in the address list.
-pub fn includes_address(storage: &dyn Storage, addr: &str) -> StdResult<bool> {
-    match ADDRESS_LIST.load(storage, addr) {
-        Ok(included) => Ok(included),
-        Err(e) => match e {
-            //If no value for address return false
-            cosmwasm_std::StdError::NotFound { .. } => Ok(false),
-            _ => Err(e),
-        },
+/// Lists the entries of the address list in ascending order of address.
+pub fn load_addresses(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<AddressInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    ADDRESSES
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, info)| info))
+        .collect()
+}
+/// Returns the rule of the message with the given name, falling back to the default rule.
+pub fn load_message_rule(
+    storage: &dyn Storage,
+    message_name: Option<&str>,
+) -> StdResult<MessageRule> {
+    let rule = match message_name {
+        Some(message_name) => MESSAGE_RULES.may_load(storage, message_name)?,
+        None => None,
+    };
+    match rule {
+        Some(rule) => Ok(rule),
+        None => Ok(DEFAULT_MESSAGE_RULE.may_load(storage)?.unwrap_or_default()),
     }
 }
+/// Lists the message rules in ascending order of message name.
+pub fn load_message_rules(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<MessageRuleInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    MESSAGE_RULES
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(message_name, rule)| MessageRuleInfo { message_name, rule }))
+        .collect()
+}
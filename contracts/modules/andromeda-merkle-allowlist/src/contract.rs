@@ -0,0 +1,601 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, ensure, to_binary, Api, Binary, Deps, DepsMut, Env, MessageInfo, Response, SubMsg,
+    Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+
+use crate::state::{CALLERS, MERKLE_ROOT, RESTRICTED_MESSAGES, USES};
+use ado_base::ADOContract;
+use andromeda_modules::merkle_allowlist::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, MerkleProof, MigrateMsg, QueryMsg, UsesResponse,
+    VerifyProofResponse,
+};
+use common::{
+    ado_base::{
+        hooks::{AndromedaHook, HookResponse},
+        AndromedaQuery, InstantiateMsg as BaseInstantiateMsg,
+    },
+    encode_binary,
+    error::ContractError,
+    get_message_name,
+    merkle::{validate_merkle_root, verify_proof},
+};
+use cw_utils::nonpayable;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:andromeda-merkle-allowlist";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &[
+    "update_merkle_root",
+    "update_restricted_messages",
+    "update_callers",
+];
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    validate_merkle_root(&msg.merkle_root)?;
+    MERKLE_ROOT.save(deps.storage, &msg.merkle_root)?;
+    RESTRICTED_MESSAGES.save(deps.storage, &msg.restricted_messages)?;
+    let callers = validate_callers(deps.api, msg.callers)?;
+    CALLERS.save(deps.storage, &callers)?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    ADOContract::default().instantiate(
+        deps.storage,
+        env,
+        deps.api,
+        info,
+        BaseInstantiateMsg {
+            ado_type: "merkle-allowlist".to_string(),
+            ado_version: CONTRACT_VERSION.to_string(),
+            operators: None,
+            modules: None,
+            primitive_contract: None,
+        },
+    )
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
+    match msg {
+        ExecuteMsg::AndrReceive(msg) => {
+            ADOContract::default().execute(deps, env, info, msg, execute)
+        }
+        ExecuteMsg::UpdateMerkleRoot { merkle_root } => {
+            execute_update_merkle_root(deps, info, merkle_root)
+        }
+        ExecuteMsg::UpdateRestrictedMessages {
+            restricted_messages,
+        } => execute_update_restricted_messages(deps, info, restricted_messages),
+        ExecuteMsg::UpdateCallers { callers } => execute_update_callers(deps, info, callers),
+        ExecuteMsg::RecordUse {
+            address,
+            allocation,
+        } => execute_record_use(deps, info, address, allocation),
+    }
+}
+
+fn execute_update_merkle_root(
+    deps: DepsMut,
+    info: MessageInfo,
+    merkle_root: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    validate_merkle_root(&merkle_root)?;
+    MERKLE_ROOT.save(deps.storage, &merkle_root)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_merkle_root"),
+        attr("merkle_root", merkle_root),
+    ]))
+}
+
+fn execute_update_restricted_messages(
+    deps: DepsMut,
+    info: MessageInfo,
+    restricted_messages: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    RESTRICTED_MESSAGES.save(deps.storage, &restricted_messages)?;
+
+    Ok(Response::new().add_attribute("action", "update_restricted_messages"))
+}
+
+fn execute_update_callers(
+    deps: DepsMut,
+    info: MessageInfo,
+    callers: Vec<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure!(
+        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    let callers = validate_callers(deps.api, callers)?;
+    CALLERS.save(deps.storage, &callers)?;
+
+    Ok(Response::new().add_attribute("action", "update_callers"))
+}
+
+fn execute_record_use(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    allocation: Uint128,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let sender = info.sender.as_str();
+    // Contracts instantiated before callers were registered have none.
+    let callers = CALLERS.may_load(deps.storage)?.unwrap_or_default();
+    ensure!(
+        callers.iter().any(|caller| caller == sender)
+            || ADOContract::default().is_owner_or_operator(deps.storage, sender)?,
+        ContractError::Unauthorized {}
+    );
+    let uses = USES
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default()
+        .checked_add(Uint128::new(1))?;
+    ensure!(uses <= allocation, ContractError::AllocationExhausted {});
+    USES.save(deps.storage, &address, &uses)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "record_use"),
+        attr("address", address),
+        attr("uses", uses),
+    ]))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    match msg {
+        QueryMsg::Config {} => encode_binary(&query_config(deps)?),
+        QueryMsg::VerifyProof {
+            address,
+            merkle_proof,
+        } => encode_binary(&query_verify_proof(deps, &address, &merkle_proof)?),
+        QueryMsg::Uses { address } => encode_binary(&query_uses(deps, &address)?),
+        QueryMsg::AndrHook(msg) => handle_andr_hook(deps, env, msg),
+        QueryMsg::AndrQuery(msg) => handle_andromeda_query(deps, env, msg),
+    }
+}
+
+fn handle_andr_hook(deps: Deps, env: Env, msg: AndromedaHook) -> Result<Binary, ContractError> {
+    match msg {
+        AndromedaHook::OnExecute {
+            sender,
+            payload,
+            merkle_proof,
+        } => {
+            let message_name = get_message_name(&payload).ok();
+            if !is_restricted(deps, message_name.as_deref())? {
+                return Ok(to_binary(&None::<HookResponse>)?);
+            }
+            // The proof is passed on by the ADO rather than read from the payload, as ADOs
+            // re-serialize their messages and drop any fields they do not know.
+            let merkle_proof = merkle_proof.ok_or(ContractError::Unauthorized {})?;
+            verify_sender(deps, &sender, &merkle_proof)?;
+
+            // Uses only need to be recorded for capped allocations.
+            let hook_response = match merkle_proof.allocation {
+                Some(allocation) => Some(HookResponse {
+                    msgs: vec![SubMsg::new(WasmMsg::Execute {
+                        contract_addr: env.contract.address.to_string(),
+                        msg: to_binary(&ExecuteMsg::RecordUse {
+                            address: sender,
+                            allocation,
+                        })?,
+                        funds: vec![],
+                    })],
+                    events: vec![],
+                }),
+                None => None,
+            };
+            Ok(to_binary(&hook_response)?)
+        }
+        _ => Ok(to_binary(&None::<HookResponse>)?),
+    }
+}
+
+fn handle_andromeda_query(
+    deps: Deps,
+    env: Env,
+    msg: AndromedaQuery,
+) -> Result<Binary, ContractError> {
+    ADOContract::default().query(deps, env, msg, query)
+}
+
+fn validate_callers(api: &dyn Api, callers: Vec<String>) -> Result<Vec<String>, ContractError> {
+    for caller in callers.iter() {
+        api.addr_validate(caller)?;
+    }
+    Ok(callers)
+}
+
+/// Returns whether the message with the given name requires a proof.
+fn is_restricted(deps: Deps, message_name: Option<&str>) -> Result<bool, ContractError> {
+    Ok(match RESTRICTED_MESSAGES.load(deps.storage)? {
+        Some(restricted_messages) => match message_name {
+            Some(name) => restricted_messages.iter().any(|m| m == name),
+            None => false,
+        },
+        None => true,
+    })
+}
+
+/// Verifies that the proof includes `sender` in the merkle tree and that they have allocation
+/// left.
+fn verify_sender(
+    deps: Deps,
+    sender: &str,
+    merkle_proof: &MerkleProof,
+) -> Result<(), ContractError> {
+    let merkle_root = MERKLE_ROOT.load(deps.storage)?;
+    let leaf = match merkle_proof.allocation {
+        Some(allocation) => format!("{}{}", sender, allocation),
+        None => sender.to_string(),
+    };
+    verify_proof(&leaf, &merkle_proof.proof, &merkle_root)?;
+    if let Some(allocation) = merkle_proof.allocation {
+        let uses = USES.may_load(deps.storage, sender)?.unwrap_or_default();
+        ensure!(uses < allocation, ContractError::AllocationExhausted {});
+    }
+    Ok(())
+}
+
+fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
+    Ok(ConfigResponse {
+        merkle_root: MERKLE_ROOT.load(deps.storage)?,
+        restricted_messages: RESTRICTED_MESSAGES.load(deps.storage)?,
+        callers: CALLERS.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+fn query_verify_proof(
+    deps: Deps,
+    address: &str,
+    merkle_proof: &MerkleProof,
+) -> Result<VerifyProofResponse, ContractError> {
+    Ok(VerifyProofResponse {
+        verified: verify_sender(deps, address, merkle_proof).is_ok(),
+    })
+}
+
+fn query_uses(deps: Deps, address: &str) -> Result<UsesResponse, ContractError> {
+    Ok(UsesResponse {
+        uses: USES.may_load(deps.storage, address)?.unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{from_binary, from_slice, CosmosMsg};
+    use serde::Deserialize;
+
+    const TEST_DATA: &[u8] = include_bytes!("../testdata/allowlist_test_data.json");
+
+    #[derive(Deserialize)]
+    struct TestData {
+        root: String,
+        accounts: Vec<Account>,
+    }
+
+    #[derive(Deserialize)]
+    struct Account {
+        address: String,
+        allocation: Option<Uint128>,
+        proof: Vec<String>,
+    }
+
+    impl Account {
+        fn merkle_proof(&self) -> MerkleProof {
+            MerkleProof {
+                proof: self.proof.clone(),
+                allocation: self.allocation,
+            }
+        }
+    }
+
+    /// A `purchase` message, whose proof is passed alongside it.
+    fn purchase_payload() -> Binary {
+        Binary::from(r#"{"purchase":{"number_of_tokens":1}}"#.as_bytes())
+    }
+
+    fn init(deps: DepsMut, restricted_messages: Option<Vec<String>>) -> TestData {
+        let test_data: TestData = from_slice(TEST_DATA).unwrap();
+        instantiate(
+            deps,
+            mock_env(),
+            mock_info("owner", &[]),
+            InstantiateMsg {
+                merkle_root: test_data.root.clone(),
+                restricted_messages,
+                callers: vec!["ado".to_string()],
+            },
+        )
+        .unwrap();
+        test_data
+    }
+
+    fn on_execute(
+        deps: Deps,
+        sender: &str,
+        payload: Binary,
+        merkle_proof: Option<MerkleProof>,
+    ) -> Result<Binary, ContractError> {
+        let msg = QueryMsg::AndrHook(AndromedaHook::OnExecute {
+            sender: sender.to_string(),
+            payload,
+            merkle_proof,
+        });
+        query(deps, mock_env(), msg)
+    }
+
+    #[test]
+    fn test_instantiate_invalid_root() {
+        let mut deps = mock_dependencies();
+        let res = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            InstantiateMsg {
+                merkle_root: "invalid".to_string(),
+                restricted_messages: None,
+                callers: vec![],
+            },
+        );
+        assert!(matches!(res.unwrap_err(), ContractError::Hex(_)));
+    }
+
+    #[test]
+    fn test_execute_hook() {
+        let mut deps = mock_dependencies();
+        let test_data = init(deps.as_mut(), None);
+        let alice = &test_data.accounts[0];
+
+        let res: Option<HookResponse> = from_binary(
+            &on_execute(
+                deps.as_ref(),
+                &alice.address,
+                purchase_payload(),
+                Some(alice.merkle_proof()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(None, res);
+
+        // Another sender cannot reuse the proof.
+        let res = on_execute(
+            deps.as_ref(),
+            "mallory",
+            purchase_payload(),
+            Some(alice.merkle_proof()),
+        );
+        assert_eq!(ContractError::VerificationFailed {}, res.unwrap_err());
+
+        // Messages without a proof are rejected, even if it is added to the message itself.
+        let payload = Binary::from(
+            format!(
+                r#"{{"purchase":{{"number_of_tokens":1,"merkle_proof":{}}}}}"#,
+                String::from_utf8(to_binary(&alice.merkle_proof()).unwrap().to_vec()).unwrap()
+            )
+            .as_bytes(),
+        );
+        let res = on_execute(deps.as_ref(), "alice", payload, None);
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+    }
+
+    #[test]
+    fn test_execute_hook_restricted_messages() {
+        let mut deps = mock_dependencies();
+        init(deps.as_mut(), Some(vec!["purchase".to_string()]));
+
+        let payload = Binary::from(r#"{"claim_refund":{}}"#.as_bytes());
+        assert!(on_execute(deps.as_ref(), "mallory", payload, None).is_ok());
+
+        let res = on_execute(deps.as_ref(), "mallory", purchase_payload(), None);
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+    }
+
+    #[test]
+    fn test_execute_hook_allocation() {
+        let mut deps = mock_dependencies();
+        let test_data = init(deps.as_mut(), None);
+        let bob = &test_data.accounts[1];
+        let env = mock_env();
+
+        let res: Option<HookResponse> = from_binary(
+            &on_execute(
+                deps.as_ref(),
+                &bob.address,
+                purchase_payload(),
+                Some(bob.merkle_proof()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let record_use = ExecuteMsg::RecordUse {
+            address: "bob".to_string(),
+            allocation: Uint128::new(2),
+        };
+        assert_eq!(
+            Some(HookResponse {
+                msgs: vec![SubMsg::new(WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_binary(&record_use).unwrap(),
+                    funds: vec![],
+                })],
+                events: vec![],
+            }),
+            res
+        );
+        let hook_msgs = res.unwrap().msgs;
+
+        // Only registered callers, i.e. the ADO the module is attached to, may record uses.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bob", &[]),
+            record_use.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        // The ADO forwards the message returned by the hook.
+        let ado = mock_info("ado", &[]);
+        let msg: ExecuteMsg = match &hook_msgs[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => from_binary(msg).unwrap(),
+            _ => panic!("Unexpected message"),
+        };
+        for _ in 0..2 {
+            execute(deps.as_mut(), env.clone(), ado.clone(), msg.clone()).unwrap();
+        }
+        let res = execute(deps.as_mut(), env.clone(), ado, record_use);
+        assert_eq!(ContractError::AllocationExhausted {}, res.unwrap_err());
+
+        let res: UsesResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Uses {
+                    address: "bob".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(Uint128::new(2), res.uses);
+
+        let res = on_execute(
+            deps.as_ref(),
+            &bob.address,
+            purchase_payload(),
+            Some(bob.merkle_proof()),
+        );
+        assert_eq!(ContractError::AllocationExhausted {}, res.unwrap_err());
+
+        let res: VerifyProofResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                env,
+                QueryMsg::VerifyProof {
+                    address: "bob".to_string(),
+                    merkle_proof: bob.merkle_proof(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(!res.verified);
+    }
+
+    #[test]
+    fn test_update_merkle_root() {
+        let mut deps = mock_dependencies();
+        let test_data = init(deps.as_mut(), None);
+        let merkle_root = "0".repeat(64);
+        let msg = ExecuteMsg::UpdateMerkleRoot {
+            merkle_root: merkle_root.clone(),
+        };
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+        assert_eq!(
+            ConfigResponse {
+                merkle_root,
+                restricted_messages: None,
+                callers: vec!["ado".to_string()],
+            },
+            query_config(deps.as_ref()).unwrap()
+        );
+
+        let alice = &test_data.accounts[0];
+        let res = on_execute(
+            deps.as_ref(),
+            &alice.address,
+            purchase_payload(),
+            Some(alice.merkle_proof()),
+        );
+        assert_eq!(ContractError::VerificationFailed {}, res.unwrap_err());
+    }
+
+    #[test]
+    fn test_update_callers() {
+        let mut deps = mock_dependencies();
+        init(deps.as_mut(), None);
+        let msg = ExecuteMsg::UpdateCallers {
+            callers: vec!["other_ado".to_string()],
+        };
+        let record_use = ExecuteMsg::RecordUse {
+            address: "bob".to_string(),
+            allocation: Uint128::new(2),
+        };
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("ado", &[]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+        assert_eq!(
+            vec!["other_ado".to_string()],
+            query_config(deps.as_ref()).unwrap().callers
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("ado", &[]),
+            record_use.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_ado", &[]),
+            record_use,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,2 @@
+pub mod contract;
+mod state;
@@ -0,0 +1,10 @@
+use cosmwasm_std::Uint128;
+use cw_storage_plus::{Item, Map};
+
+pub const MERKLE_ROOT: Item<String> = Item::new("merkle_root");
+/// The names of the execute messages that require a proof, or `None` if every message does.
+pub const RESTRICTED_MESSAGES: Item<Option<Vec<String>>> = Item::new("restricted_messages");
+/// The number of restricted messages each address has executed.
+pub const USES: Map<&str, Uint128> = Map::new("uses");
+/// The addresses of the ADOs that may record uses.
+pub const CALLERS: Item<Vec<String>> = Item::new("callers");
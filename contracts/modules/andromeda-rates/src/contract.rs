@@ -2,11 +2,12 @@ use crate::state::{Config, CONFIG};
 use ado_base::ADOContract;
 use andromeda_modules::rates::{
     calculate_fee, ExecuteMsg, InstantiateMsg, MigrateMsg, PaymentAttribute, PaymentsResponse,
-    QueryMsg, RateInfo,
+    QueryMsg, Rate, RateInfo,
 };
 use common::{
     ado_base::{
-        hooks::{AndromedaHook, OnFundsTransferResponse},
+        hooks::{AndromedaHook, HookResponse, OnFundsTransferResponse},
+        roles::RATE_ADMIN,
         AndromedaMsg, AndromedaQuery, InstantiateMsg as BaseInstantiateMsg,
     },
     deduct_funds, encode_binary,
@@ -15,16 +16,17 @@ use common::{
 };
 use cosmwasm_std::{
     attr, coin, ensure, entry_point, Binary, Coin, Deps, DepsMut, Env, Event, MessageInfo,
-    Response, StdError, SubMsg,
+    Response, SubMsg,
 };
-use cw2::{get_contract_version, set_contract_version};
-use cw20::Cw20Coin; 
+use cw2::set_contract_version;
+use cw20::Cw20Coin;
 use cw_utils::nonpayable;
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-rates";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &["update_rates"];
 
 #[entry_point]
 pub fn instantiate(
@@ -34,8 +36,12 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    for rate_info in msg.rates.iter() {
+        rate_info.validate_denoms()?;
+    }
     let config = Config { rates: msg.rates };
     CONFIG.save(deps.storage, &config)?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -58,6 +64,7 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::AndrReceive(msg) => execute_andr_receive(deps, env, info, msg),
         ExecuteMsg::UpdateRates { rates } => execute_update_rates(deps, info, rates),
@@ -87,9 +94,12 @@ fn execute_update_rates(
     nonpayable(&info)?;
 
     ensure!(
-        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ADOContract::default().is_owner_or_role(deps.storage, RATE_ADMIN, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
+    for rate_info in rates.iter() {
+        rate_info.validate_denoms()?;
+    }
     let mut config = CONFIG.load(deps.storage)?;
     config.rates = rates;
     CONFIG.save(deps.storage, &config)?;
@@ -98,48 +108,17 @@ fn execute_update_rates(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[entry_point]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::AndrQuery(msg) => handle_andromeda_query(deps, env, msg),
-        QueryMsg::AndrHook(msg) => handle_andromeda_hook(deps, msg),
+        QueryMsg::AndrHook(msg) => handle_andromeda_hook(deps, env, msg),
         QueryMsg::Payments {} => encode_binary(&query_payments(deps)?),
     }
 }
@@ -152,18 +131,22 @@ fn handle_andromeda_query(
     match msg {
         AndromedaQuery::Get(data) => {
             let funds: Funds = parse_message(&data)?;
-            encode_binary(&Some(query_deducted_funds(deps, funds)?))
+            encode_binary(&Some(query_deducted_funds(deps, env, None, funds)?))
         }
         _ => ADOContract::default().query(deps, env, msg, query),
     }
 }
 
-fn handle_andromeda_hook(deps: Deps, msg: AndromedaHook) -> Result<Binary, ContractError> {
+fn handle_andromeda_hook(
+    deps: Deps,
+    env: Env,
+    msg: AndromedaHook,
+) -> Result<Binary, ContractError> {
     match msg {
-        AndromedaHook::OnFundsTransfer { amount, .. } => {
-            encode_binary(&query_deducted_funds(deps, amount)?)
+        AndromedaHook::OnFundsTransfer { sender, amount, .. } => {
+            encode_binary(&query_deducted_funds(deps, env, Some(sender), amount)?)
         }
-        _ => Ok(encode_binary(&None::<Response>)?),
+        _ => Ok(encode_binary(&None::<HookResponse>)?),
     }
 }
 
@@ -174,8 +157,12 @@ fn query_payments(deps: Deps) -> Result<PaymentsResponse, ContractError> {
     Ok(PaymentsResponse { payments: rates })
 }
 
+/// Applies every active rate to `funds`. Rates restricted to other denoms are skipped, as are
+/// flat rates of other denoms and rates that `sender` is exempt from.
 fn query_deducted_funds(
     deps: Deps,
+    env: Env,
+    sender: Option<String>,
     funds: Funds,
 ) -> Result<OnFundsTransferResponse, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -186,7 +173,16 @@ fn query_deducted_funds(
         Funds::Cw20(cw20_coin) => (coin(cw20_coin.amount.u128(), cw20_coin.address), false),
     };
     let mut leftover_funds = vec![coin.clone()];
+    let app_contract = ADOContract::default().get_app_contract(deps.storage)?;
     for rate_info in config.rates.iter() {
+        if !rate_info.is_active(&env.block) || !rate_info.applies_to_denom(&coin.denom) {
+            continue;
+        }
+        if let Some(sender) = &sender {
+            if rate_info.is_exempt(deps.api, &deps.querier, app_contract.clone(), sender)? {
+                continue;
+            }
+        }
         let event_name = if rate_info.is_additive {
             "tax"
         } else {
@@ -196,11 +192,26 @@ fn query_deducted_funds(
         if let Some(desc) = &rate_info.description {
             event = event.add_attribute("description", desc);
         }
-        let app_contract = ADOContract::default().get_app_contract(deps.storage)?;
         let rate = rate_info
             .rate
-            .validate(deps.api, &deps.querier, app_contract)?;
-        let fee = calculate_fee(rate, &coin)?;
+            .validate(deps.api, &deps.querier, app_contract.clone())?;
+        // External rates are only known to be flat once they have been resolved.
+        if !rate.applies_to_denom(&coin.denom) {
+            continue;
+        }
+        if let Rate::Tiered(tiered_rate) = &rate {
+            match tiered_rate.get_tier(coin.amount) {
+                Some((tier, _)) => event = event.add_attribute("tier", tier.to_string()),
+                // No tier applies to amounts below the first one.
+                None => continue,
+            }
+        }
+        let is_percent = matches!(rate, Rate::Percent(_) | Rate::Tiered(_));
+        let mut fee = calculate_fee(rate, &coin)?;
+        // A percent rate's minimum can never deduct more than the payment itself.
+        if is_percent && !rate_info.is_additive {
+            fee.amount = fee.amount.min(coin.amount);
+        }
         for reciever in rate_info.recipients.iter() {
             if !rate_info.is_additive {
                 deduct_funds(&mut leftover_funds, &fee)?;
@@ -258,7 +269,10 @@ fn query_deducted_funds(
 mod tests {
     use super::*;
     use crate::contract::{execute, instantiate, query};
-    use andromeda_modules::rates::{InstantiateMsg, PaymentsResponse, QueryMsg, Rate, RateInfo};
+    use andromeda_modules::rates::{
+        InstantiateMsg, PaymentsResponse, PercentRate, QueryMsg, Rate, RateInfo, RateTier,
+        TieredRate,
+    };
     use andromeda_testing::testing::mock_querier::{
         mock_dependencies_custom, MOCK_PRIMITIVE_CONTRACT,
     };
@@ -269,6 +283,7 @@ mod tests {
         coin, coins, from_binary, BankMsg, Coin, CosmosMsg, Decimal, Uint128, WasmMsg,
     };
     use cw20::Cw20ExecuteMsg;
+    use cw_utils::Expiration;
 
     #[test]
     fn test_instantiate_query() {
@@ -282,6 +297,11 @@ mod tests {
                 is_additive: true,
                 description: Some("desc1".to_string()),
                 recipients: vec![Recipient::Addr("".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
             RateInfo {
                 rate: Rate::Flat(Coin {
@@ -291,6 +311,11 @@ mod tests {
                 is_additive: false,
                 description: Some("desc2".to_string()),
                 recipients: vec![Recipient::Addr("".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
         ];
         let msg = InstantiateMsg {
@@ -324,6 +349,11 @@ mod tests {
                 is_additive: true,
                 description: Some("desc1".to_string()),
                 recipients: vec![Recipient::Addr("".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
             RateInfo {
                 rate: Rate::Flat(Coin {
@@ -333,6 +363,11 @@ mod tests {
                 is_additive: false,
                 description: Some("desc2".to_string()),
                 recipients: vec![Recipient::Addr("".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
         ];
         let msg = InstantiateMsg { rates: vec![] };
@@ -348,6 +383,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_update_rates_rate_admin() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = "owner";
+        let msg = InstantiateMsg { rates: vec![] };
+        let _res = instantiate(deps.as_mut(), env.clone(), mock_info(owner, &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::UpdateRates { rates: vec![] };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("rate_admin", &[]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        let grant_msg = ExecuteMsg::AndrReceive(AndromedaMsg::GrantRole {
+            role: RATE_ADMIN.to_string(),
+            address: "rate_admin".to_string(),
+        });
+        let _res = execute(deps.as_mut(), env.clone(), mock_info(owner, &[]), grant_msg).unwrap();
+
+        let res = execute(deps.as_mut(), env, mock_info("rate_admin", &[]), msg).unwrap();
+        assert_eq!(
+            Response::new().add_attributes(vec![attr("action", "update_rates")]),
+            res
+        );
+    }
+
     #[test]
     fn test_query_deducted_funds_native() {
         let mut deps = mock_dependencies_custom(&[]);
@@ -363,12 +428,22 @@ mod tests {
                 is_additive: true,
                 description: Some("desc2".to_string()),
                 recipients: vec![Recipient::Addr("1".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
             RateInfo {
                 rate: Rate::from(Decimal::percent(10)),
                 is_additive: false,
                 description: Some("desc1".to_string()),
                 recipients: vec![Recipient::Addr("2".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
             RateInfo {
                 rate: Rate::External(PrimitivePointer {
@@ -380,6 +455,11 @@ mod tests {
                 is_additive: false,
                 description: Some("desc3".to_string()),
                 recipients: vec![Recipient::Addr("3".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
         ];
         let msg = InstantiateMsg { rates };
@@ -451,12 +531,22 @@ mod tests {
                 is_additive: true,
                 description: Some("desc2".to_string()),
                 recipients: vec![Recipient::Addr("1".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
             RateInfo {
                 rate: Rate::from(Decimal::percent(10)),
                 is_additive: false,
                 description: Some("desc1".to_string()),
                 recipients: vec![Recipient::Addr("2".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
             RateInfo {
                 rate: Rate::External(PrimitivePointer {
@@ -468,6 +558,11 @@ mod tests {
                 is_additive: false,
                 description: Some("desc3".to_string()),
                 recipients: vec![Recipient::Addr("3".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
             },
         ];
         let msg = InstantiateMsg { rates };
@@ -543,4 +638,398 @@ mod tests {
             res
         );
     }
+
+    #[test]
+    fn test_query_deducted_funds_tiered_and_windows() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        let info = mock_info("owner", &[]);
+        let tier = |min_amount: u128, percent: u64| RateTier {
+            min_amount: Uint128::new(min_amount),
+            rate: PercentRate {
+                percent: Decimal::percent(percent),
+                min: None,
+                max: Some(Uint128::new(40)),
+            },
+        };
+        let rates = vec![
+            RateInfo {
+                rate: Rate::Tiered(TieredRate {
+                    tiers: vec![tier(0, 10), tier(1000, 5)],
+                }),
+                is_additive: false,
+                description: None,
+                recipients: vec![Recipient::Addr("1".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
+            },
+            // A discount that has ended.
+            RateInfo {
+                rate: Rate::from(Decimal::percent(1)),
+                is_additive: false,
+                description: None,
+                recipients: vec![Recipient::Addr("2".into())],
+                start: None,
+                end: Some(Expiration::AtHeight(env.block.height)),
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
+            },
+            // A fee that has not started yet.
+            RateInfo {
+                rate: Rate::from(Decimal::percent(20)),
+                is_additive: true,
+                description: None,
+                recipients: vec![Recipient::Addr("3".into())],
+                start: Some(Expiration::AtHeight(env.block.height + 1)),
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
+            },
+        ];
+        let msg = InstantiateMsg { rates };
+        let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let query_funds = |amount: u128| -> OnFundsTransferResponse {
+            from_binary(
+                &query(
+                    deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::AndrHook(AndromedaHook::OnFundsTransfer {
+                        sender: "sender".to_string(),
+                        payload: encode_binary(&"").unwrap(),
+                        amount: Funds::Native(coin(amount, "uusd")),
+                    }),
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "1".into(),
+                    amount: coins(30, "uusd"),
+                }))],
+                leftover_funds: Funds::Native(coin(270, "uusd")),
+                events: vec![Event::new("royalty")
+                    .add_attribute("tier", "0")
+                    .add_attribute("deducted", "30uusd")
+                    .add_attribute("payment", "1<30uusd")],
+            },
+            query_funds(300)
+        );
+
+        // The second tier applies and its fee is capped.
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "1".into(),
+                    amount: coins(40, "uusd"),
+                }))],
+                leftover_funds: Funds::Native(coin(960, "uusd")),
+                events: vec![Event::new("royalty")
+                    .add_attribute("tier", "1")
+                    .add_attribute("deducted", "40uusd")
+                    .add_attribute("payment", "1<40uusd")],
+            },
+            query_funds(1000)
+        );
+    }
+
+    #[test]
+    fn test_query_deducted_funds_scoped() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        let info = mock_info("owner", &[]);
+        let rates = vec![
+            RateInfo {
+                rate: Rate::Flat(coin(10, "uusd")),
+                is_additive: false,
+                description: None,
+                recipients: vec![Recipient::Addr("1".into())],
+                start: None,
+                end: None,
+                denoms: Some(vec!["uusd".to_string()]),
+                exempt_senders: None,
+                exempt_address_list: None,
+            },
+            RateInfo {
+                rate: Rate::from(Decimal::percent(10)),
+                is_additive: true,
+                description: None,
+                recipients: vec![Recipient::Addr("2".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: Some(vec!["partner".to_string()]),
+                exempt_address_list: None,
+            },
+            // The mocked address list includes every address.
+            RateInfo {
+                rate: Rate::from(Decimal::percent(5)),
+                is_additive: true,
+                description: None,
+                recipients: vec![Recipient::Addr("3".into())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: Some(AndrAddress {
+                    identifier: "addresslist_contract_address1".to_string(),
+                }),
+            },
+        ];
+        let msg = InstantiateMsg { rates };
+        let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let query_funds = |sender: &str, amount: Coin| -> OnFundsTransferResponse {
+            from_binary(
+                &query(
+                    deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::AndrHook(AndromedaHook::OnFundsTransfer {
+                        sender: sender.to_string(),
+                        payload: encode_binary(&"").unwrap(),
+                        amount: Funds::Native(amount),
+                    }),
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![
+                    SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "1".into(),
+                        amount: coins(10, "uusd"),
+                    })),
+                    SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "2".into(),
+                        amount: coins(10, "uusd"),
+                    })),
+                ],
+                leftover_funds: Funds::Native(coin(90, "uusd")),
+                events: vec![
+                    Event::new("royalty")
+                        .add_attribute("deducted", "10uusd")
+                        .add_attribute("payment", "1<10uusd"),
+                    Event::new("tax").add_attribute("payment", "2<10uusd"),
+                ],
+            },
+            query_funds("buyer", coin(100, "uusd"))
+        );
+
+        // The flat rate only applies to uusd and the partner is exempt from the other rates.
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![],
+                leftover_funds: Funds::Native(coin(100, "uluna")),
+                events: vec![],
+            },
+            query_funds("partner", coin(100, "uluna"))
+        );
+    }
+
+    #[test]
+    fn test_query_deducted_funds_flat_other_denom() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        let info = mock_info("owner", &[]);
+        let rates = vec![RateInfo {
+            rate: Rate::Flat(coin(10, "uusd")),
+            is_additive: false,
+            description: None,
+            recipients: vec![Recipient::Addr("1".into())],
+            start: None,
+            end: None,
+            denoms: None,
+            exempt_senders: None,
+            exempt_address_list: None,
+        }];
+        let msg = InstantiateMsg { rates };
+        let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let query_funds = |amount: Coin| -> OnFundsTransferResponse {
+            from_binary(
+                &query(
+                    deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::AndrHook(AndromedaHook::OnFundsTransfer {
+                        sender: "buyer".to_string(),
+                        payload: encode_binary(&"").unwrap(),
+                        amount: Funds::Native(amount),
+                    }),
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "1".into(),
+                    amount: coins(10, "uusd"),
+                }))],
+                leftover_funds: Funds::Native(coin(90, "uusd")),
+                events: vec![Event::new("royalty")
+                    .add_attribute("deducted", "10uusd")
+                    .add_attribute("payment", "1<10uusd")],
+            },
+            query_funds(coin(100, "uusd"))
+        );
+
+        // The uusd flat rate is not charged on uluna.
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![],
+                leftover_funds: Funds::Native(coin(100, "uluna")),
+                events: vec![],
+            },
+            query_funds(coin(100, "uluna"))
+        );
+    }
+
+    #[test]
+    fn test_query_deducted_funds_min_capped() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        let info = mock_info("owner", &[]);
+        let percent_rate = |is_additive| RateInfo {
+            rate: Rate::Percent(PercentRate {
+                percent: Decimal::percent(10),
+                min: Some(Uint128::new(150)),
+                max: None,
+            }),
+            is_additive,
+            description: None,
+            recipients: vec![Recipient::Addr("1".into())],
+            start: None,
+            end: None,
+            denoms: None,
+            exempt_senders: None,
+            exempt_address_list: None,
+        };
+        let msg = InstantiateMsg {
+            rates: vec![percent_rate(false)],
+        };
+        let _res = instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let query_funds = |deps: Deps| -> OnFundsTransferResponse {
+            from_binary(
+                &query(
+                    deps,
+                    env.clone(),
+                    QueryMsg::AndrHook(AndromedaHook::OnFundsTransfer {
+                        sender: "buyer".to_string(),
+                        payload: encode_binary(&"").unwrap(),
+                        amount: Funds::Native(coin(100, "uusd")),
+                    }),
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        // The deducted minimum is capped at the payment.
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "1".into(),
+                    amount: coins(100, "uusd"),
+                }))],
+                leftover_funds: Funds::Native(coin(0, "uusd")),
+                events: vec![Event::new("royalty")
+                    .add_attribute("deducted", "100uusd")
+                    .add_attribute("payment", "1<100uusd")],
+            },
+            query_funds(deps.as_ref())
+        );
+
+        // An additive minimum is charged on top of the payment in full.
+        let msg = ExecuteMsg::UpdateRates {
+            rates: vec![percent_rate(true)],
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "1".into(),
+                    amount: coins(150, "uusd"),
+                }))],
+                leftover_funds: Funds::Native(coin(100, "uusd")),
+                events: vec![Event::new("tax").add_attribute("payment", "1<150uusd")],
+            },
+            query_funds(deps.as_ref())
+        );
+    }
+
+    #[test]
+    fn test_flat_rate_denom_not_included() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        let info = mock_info("owner", &[]);
+        let rate_info = |denoms: Vec<&str>| RateInfo {
+            rate: Rate::Flat(coin(10, "uatom")),
+            is_additive: false,
+            description: None,
+            recipients: vec![Recipient::Addr("1".into())],
+            start: None,
+            end: None,
+            denoms: Some(denoms.into_iter().map(String::from).collect()),
+            exempt_senders: None,
+            exempt_address_list: None,
+        };
+        let expected_err = ContractError::FlatRateDenomNotIncluded {
+            denom: "uatom".to_string(),
+        };
+
+        let msg = InstantiateMsg {
+            rates: vec![rate_info(vec!["uusd"])],
+        };
+        let err = instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert_eq!(expected_err, err);
+
+        let msg = InstantiateMsg {
+            rates: vec![rate_info(vec!["uusd", "uatom"])],
+        };
+        instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::UpdateRates {
+            rates: vec![rate_info(vec!["uusd"])],
+        };
+        let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+        assert_eq!(expected_err, err);
+
+        // The uatom flat rate is still not charged on uusd payments.
+        let res: OnFundsTransferResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                env,
+                QueryMsg::AndrHook(AndromedaHook::OnFundsTransfer {
+                    sender: "buyer".to_string(),
+                    payload: encode_binary(&"").unwrap(),
+                    amount: Funds::Native(coin(100, "uusd")),
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            OnFundsTransferResponse {
+                msgs: vec![],
+                leftover_funds: Funds::Native(coin(100, "uusd")),
+                events: vec![],
+            },
+            res
+        );
+    }
 }
@@ -2,7 +2,8 @@ use std::env::current_dir;
 use std::fs::create_dir_all;
 
 use andromeda_modules::receipt::{
-    ContractInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg, ReceiptResponse,
+    ContractInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg, ReceiptHooksResponse,
+    ReceiptResponse, ReceiptsResponse,
 };
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
@@ -17,5 +18,7 @@ fn main() {
     export_schema(&schema_for!(QueryMsg), &out_dir);
 
     export_schema(&schema_for!(ReceiptResponse), &out_dir);
+    export_schema(&schema_for!(ReceiptsResponse), &out_dir);
+    export_schema(&schema_for!(ReceiptHooksResponse), &out_dir);
     export_schema(&schema_for!(ContractInfoResponse), &out_dir);
 }
@@ -1,31 +1,40 @@
 use crate::state::{
-    can_mint_receipt, increment_num_receipt, read_receipt, store_config, store_receipt, CONFIG,
+    can_mint_receipt, edit_receipt, increment_num_receipt, load_receipts, read_receipt,
+    set_receipt_hooks, store_config, store_receipt, ReceiptFilter, ADO_RECEIPT_HOOKS, CONFIG,
+    ENABLED_RECEIPT_HOOKS, LEGACY_RECEIPT, NUM_RECEIPT,
 };
-use ado_base::state::ADOContract;
+use ado_base::{migrate::MigrationStep, state::ADOContract};
 use andromeda_modules::receipt::{
     generate_receipt_message, Config, ContractInfoResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
-    QueryMsg, Receipt, ReceiptResponse,
+    QueryMsg, Receipt, ReceiptHook, ReceiptHooks, ReceiptHooksResponse, ReceiptInfo,
+    ReceiptResponse, ReceiptsResponse,
 };
 use common::{
     ado_base::{
-        hooks::{AndromedaHook, OnFundsTransferResponse},
+        hooks::{AndromedaHook, HookResponse, OnFundsTransferResponse},
         InstantiateMsg as BaseInstantiateMsg,
     },
     encode_binary,
     error::ContractError,
-    parse_message,
+    get_message_name, parse_message,
 };
 use cosmwasm_std::{
-    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response, StdError,
+    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, Event, MessageInfo, Order, Response,
     Uint128,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw_utils::nonpayable;
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-receipt";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &["set_receipt_hooks"];
+
+const MIGRATION_STEPS: [MigrationStep; 1] = [MigrationStep {
+    version: "0.2.0",
+    migrate: migrate_legacy_receipts,
+}];
 
 #[entry_point]
 pub fn instantiate(
@@ -36,6 +45,7 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     store_config(deps.storage, &Config { minter: msg.minter })?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -58,31 +68,68 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::AndrReceive(msg) => {
             ADOContract::default().execute(deps, env, info, msg, execute)
         }
-        ExecuteMsg::StoreReceipt { receipt } => execute_store_receipt(deps, info, receipt),
+        ExecuteMsg::StoreReceipt {
+            receipt,
+            sender,
+            hook,
+        } => execute_store_receipt(deps, env, info, receipt, sender, hook),
         ExecuteMsg::EditReceipt {
             receipt,
             receipt_id,
         } => execute_edit_receipt(deps, info, receipt_id, receipt),
+        ExecuteMsg::SetReceiptHooks { ado, hooks } => {
+            execute_set_receipt_hooks(deps, info, ado, hooks)
+        }
     }
 }
 
 fn execute_store_receipt(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     receipt: Receipt,
+    sender: Option<String>,
+    hook: Option<ReceiptHook>,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
 
-    ensure!(
-        can_mint_receipt(deps.storage, info.sender.as_ref())?,
-        ContractError::Unauthorized {}
-    );
+    match hook {
+        // ADOs that enabled receipts for a hook may store them without being the minter. The
+        // module cannot tell which ADO queried the hook, so other ADOs' receipts are skipped
+        // rather than rejected, which would fail their messages.
+        Some(hook) => {
+            let hooks = ADO_RECEIPT_HOOKS
+                .may_load(deps.storage, info.sender.as_str())?
+                .unwrap_or_default();
+            if !hooks.includes(hook) {
+                return Ok(Response::new().add_attributes(vec![
+                    attr("action", "skip_receipt"),
+                    attr("ado", info.sender),
+                ]));
+            }
+        }
+        None => ensure!(
+            can_mint_receipt(deps.storage, info.sender.as_ref())?,
+            ContractError::Unauthorized {}
+        ),
+    }
     let receipt_id = increment_num_receipt(deps.storage)?;
-    store_receipt(deps.storage, receipt_id, &receipt)?;
+    store_receipt(
+        deps.storage,
+        &ReceiptInfo {
+            receipt_id,
+            receipt,
+            sender,
+            ado: info.sender.to_string(),
+            height: env.block.height,
+            time: env.block.time,
+        },
+    )?;
     Ok(Response::new().add_attributes(vec![
         attr("action", "mint_receipt"),
         attr("receipt_id", receipt_id.to_string()),
@@ -99,8 +146,7 @@ fn execute_edit_receipt(
         can_mint_receipt(deps.storage, info.sender.as_ref())?,
         ContractError::Unauthorized {}
     );
-    read_receipt(deps.storage, receipt_id)?;
-    store_receipt(deps.storage, receipt_id, &receipt)?;
+    edit_receipt(deps.storage, receipt_id, receipt)?;
 
     Ok(Response::new().add_attributes(vec![
         attr("action", "edit_receipt"),
@@ -109,42 +155,46 @@ fn execute_edit_receipt(
     ]))
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
+fn execute_set_receipt_hooks(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado: String,
+    hooks: ReceiptHooks,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
     ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
+        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ContractError::Unauthorized {}
     );
+    deps.api.addr_validate(&ado)?;
+    set_receipt_hooks(deps.storage, &ado, hooks)?;
 
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_receipt_hooks"),
+        attr("ado", ado),
+        attr("on_execute", hooks.on_execute.to_string()),
+        attr("on_transfer", hooks.on_transfer.to_string()),
+    ]))
+}
 
-    Ok(Response::default())
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &MIGRATION_STEPS)
 }
 
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+/// Receipts stored prior to version 0.2.0 stay where they are, but the receipt count was not
+/// persisted, so it is set past their IDs for new receipts not to shadow them.
+fn migrate_legacy_receipts(deps: DepsMut, _env: Env) -> Result<(), ContractError> {
+    let last_id = LEGACY_RECEIPT
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    if let Some(last_id) = last_id {
+        NUM_RECEIPT.save(deps.storage, &Uint128::new(last_id))?;
+    }
+    Ok(())
 }
 
 #[entry_point]
@@ -152,28 +202,134 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
     match msg {
         QueryMsg::AndrQuery(msg) => ADOContract::default().query(deps, env, msg, query),
         QueryMsg::Receipt { receipt_id } => encode_binary(&query_receipt(deps, receipt_id)?),
+        QueryMsg::Receipts { start_after, limit } => encode_binary(&query_receipts(
+            deps,
+            ReceiptFilter::All,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ReceiptsBySender {
+            sender,
+            start_after,
+            limit,
+        } => encode_binary(&query_receipts(
+            deps,
+            ReceiptFilter::Sender(sender),
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ReceiptsByAdo {
+            ado,
+            start_after,
+            limit,
+        } => encode_binary(&query_receipts(
+            deps,
+            ReceiptFilter::Ado(ado),
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ReceiptsByHeight {
+            min_height,
+            max_height,
+            start_after,
+            limit,
+        } => encode_binary(&query_receipts(
+            deps,
+            ReceiptFilter::Height {
+                min_height,
+                max_height,
+            },
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ReceiptsByTime {
+            min_time,
+            max_time,
+            start_after,
+            limit,
+        } => encode_binary(&query_receipts(
+            deps,
+            ReceiptFilter::Time {
+                min_time: min_time.map(|time| time.nanos()),
+                max_time: max_time.map(|time| time.nanos()),
+            },
+            start_after,
+            limit,
+        )?),
         QueryMsg::ContractInfo {} => encode_binary(&query_config(deps)?),
-        QueryMsg::AndrHook(msg) => handle_andr_hook(env, msg),
+        QueryMsg::ReceiptHooks { ado } => encode_binary(&query_receipt_hooks(deps, ado)?),
+        QueryMsg::AndrHook(msg) => handle_andr_hook(deps, env, msg),
     }
 }
 
-fn handle_andr_hook(env: Env, msg: AndromedaHook) -> Result<Binary, ContractError> {
+fn handle_andr_hook(deps: Deps, env: Env, msg: AndromedaHook) -> Result<Binary, ContractError> {
     match msg {
         AndromedaHook::OnFundsTransfer {
-            sender: _,
+            sender,
             payload,
             amount,
         } => {
             let events: Vec<Event> = parse_message(&Some(payload))?;
-            let msg = generate_receipt_message(env.contract.address.to_string(), events)?;
+            let msg = generate_receipt_message(
+                env.contract.address.to_string(),
+                Some(sender),
+                events,
+                None,
+            )?;
             encode_binary(&Some(OnFundsTransferResponse {
                 msgs: vec![msg],
                 leftover_funds: amount,
                 events: vec![],
             }))
         }
-        _ => Ok(encode_binary(&None::<Response>)?),
+        AndromedaHook::OnExecute {
+            sender, payload, ..
+        } => {
+            let event = Event::new("execute")
+                .add_attribute(
+                    "message_name",
+                    get_message_name(&payload).unwrap_or_default(),
+                )
+                .add_attribute("msg", String::from_utf8_lossy(payload.as_slice()));
+            hook_receipt_response(deps, env, ReceiptHook::OnExecute, sender, event)
+        }
+        AndromedaHook::OnTransfer {
+            token_id,
+            sender,
+            recipient,
+        } => {
+            let event = Event::new("transfer")
+                .add_attribute("token_id", token_id)
+                .add_attribute("recipient", recipient);
+            hook_receipt_response(deps, env, ReceiptHook::OnTransfer, sender, event)
+        }
+    }
+}
+
+/// Returns a message storing a receipt of `event` if any ADO records receipts for `hook`.
+fn hook_receipt_response(
+    deps: Deps,
+    env: Env,
+    hook: ReceiptHook,
+    sender: String,
+    event: Event,
+) -> Result<Binary, ContractError> {
+    let enabled = ENABLED_RECEIPT_HOOKS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if !enabled.includes(hook) {
+        return encode_binary(&None::<HookResponse>);
     }
+    let msg = generate_receipt_message(
+        env.contract.address.to_string(),
+        Some(sender),
+        vec![event],
+        Some(hook),
+    )?;
+    encode_binary(&Some(HookResponse {
+        msgs: vec![msg],
+        events: vec![],
+    }))
 }
 
 fn query_receipt(deps: Deps, receipt_id: Uint128) -> Result<ReceiptResponse, ContractError> {
@@ -181,6 +337,25 @@ fn query_receipt(deps: Deps, receipt_id: Uint128) -> Result<ReceiptResponse, Con
     Ok(ReceiptResponse { receipt })
 }
 
+fn query_receipts(
+    deps: Deps,
+    filter: ReceiptFilter,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> Result<ReceiptsResponse, ContractError> {
+    Ok(ReceiptsResponse {
+        receipts: load_receipts(deps.storage, filter, start_after, limit)?,
+    })
+}
+
+fn query_receipt_hooks(deps: Deps, ado: String) -> Result<ReceiptHooksResponse, ContractError> {
+    Ok(ReceiptHooksResponse {
+        hooks: ADO_RECEIPT_HOOKS
+            .may_load(deps.storage, &ado)?
+            .unwrap_or_default(),
+    })
+}
+
 fn query_config(deps: Deps) -> Result<ContractInfoResponse, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -197,7 +372,7 @@ mod tests {
     use cosmwasm_std::{
         coin, from_binary,
         testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR},
-        CosmosMsg, Event, SubMsg, WasmMsg,
+        CosmosMsg, Event, SubMsg, Timestamp, WasmMsg,
     };
 
     #[test]
@@ -233,6 +408,8 @@ mod tests {
 
         let msg = ExecuteMsg::StoreReceipt {
             receipt: Receipt { events: vec![] },
+            sender: None,
+            hook: None,
         };
 
         let res_unauth = execute(deps.as_mut(), env.clone(), unauth_info, msg.clone()).unwrap_err();
@@ -249,6 +426,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_store_receipt_paused() {
+        let owner = "creator";
+        let mut deps = mock_dependencies();
+        let info = mock_info(owner, &[]);
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            InstantiateMsg {
+                minter: owner.to_string(),
+            },
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause { messages: None });
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::StoreReceipt {
+            receipt: Receipt { events: vec![] },
+            sender: None,
+            hook: None,
+        };
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            ContractError::Paused {
+                msg: "all".to_string()
+            },
+            res
+        );
+
+        // Admin messages are not paused.
+        let msg = ExecuteMsg::SetReceiptHooks {
+            ado: "ado".to_string(),
+            hooks: ReceiptHooks {
+                on_execute: true,
+                on_transfer: false,
+            },
+        };
+        assert!(execute(deps.as_mut(), mock_env(), info, msg).is_ok());
+    }
+
     #[test]
     fn test_edit_receipt() {
         let owner = "creator";
@@ -271,6 +491,8 @@ mod tests {
             receipt: Receipt {
                 events: vec![Event::new("test")],
             },
+            sender: None,
+            hook: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info.clone(), store_msg).unwrap();
         assert_eq!(
@@ -328,6 +550,8 @@ mod tests {
 
         let msg = ExecuteMsg::StoreReceipt {
             receipt: Receipt { events: vec![] },
+            sender: None,
+            hook: None,
         };
 
         let msg =
@@ -375,7 +599,9 @@ mod tests {
                 msgs: vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
                     contract_addr: MOCK_CONTRACT_ADDR.to_string(),
                     msg: encode_binary(&ExecuteMsg::StoreReceipt {
-                        receipt: Receipt { events }
+                        receipt: Receipt { events },
+                        sender: Some("sender".to_string()),
+                        hook: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -386,4 +612,324 @@ mod tests {
             res
         );
     }
+
+    fn store(deps: DepsMut, ado: &str, sender: Option<&str>, height: u64) {
+        let mut env = mock_env();
+        env.block.height = height;
+        env.block.time = Timestamp::from_seconds(height * 5);
+        let msg = ExecuteMsg::StoreReceipt {
+            receipt: Receipt {
+                events: vec![Event::new(format!("{}", height))],
+            },
+            sender: sender.map(String::from),
+            hook: None,
+        };
+        execute(deps, env, mock_info(ado, &[]), msg).unwrap();
+    }
+
+    fn query_ids(deps: Deps, msg: QueryMsg) -> Vec<u128> {
+        let res: ReceiptsResponse = from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap();
+        res.receipts
+            .into_iter()
+            .map(|receipt_info| receipt_info.receipt_id.u128())
+            .collect()
+    }
+
+    #[test]
+    fn test_query_receipt_history() {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            InstantiateMsg {
+                minter: "creator".to_string(),
+            },
+        )
+        .unwrap();
+        ADOContract::default()
+            .execute_update_operators(
+                deps.as_mut(),
+                mock_info("creator", &[]),
+                vec!["ado1".to_string(), "ado2".to_string()],
+            )
+            .unwrap();
+
+        store(deps.as_mut(), "ado1", Some("alice"), 10);
+        store(deps.as_mut(), "ado2", Some("bob"), 10);
+        store(deps.as_mut(), "ado1", Some("bob"), 11);
+        store(deps.as_mut(), "creator", None, 12);
+
+        let res: ReceiptsResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Receipts {
+                    start_after: None,
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![ReceiptInfo {
+                receipt_id: Uint128::new(1),
+                receipt: Receipt {
+                    events: vec![Event::new("10")],
+                },
+                sender: Some("alice".to_string()),
+                ado: "ado1".to_string(),
+                height: 10,
+                time: Timestamp::from_seconds(50),
+            }],
+            res.receipts
+        );
+        let msg = QueryMsg::Receipts {
+            start_after: Some(Uint128::new(1)),
+            limit: None,
+        };
+        assert_eq!(vec![2, 3, 4], query_ids(deps.as_ref(), msg));
+
+        let by_sender = |start_after: Option<u128>| QueryMsg::ReceiptsBySender {
+            sender: "bob".to_string(),
+            start_after: start_after.map(Uint128::new),
+            limit: None,
+        };
+        assert_eq!(vec![2, 3], query_ids(deps.as_ref(), by_sender(None)));
+        assert_eq!(vec![3], query_ids(deps.as_ref(), by_sender(Some(2))));
+
+        let msg = QueryMsg::ReceiptsByAdo {
+            ado: "ado1".to_string(),
+            start_after: None,
+            limit: None,
+        };
+        assert_eq!(vec![1, 3], query_ids(deps.as_ref(), msg));
+
+        let by_height =
+            |min_height, max_height, start_after: Option<u128>, limit| QueryMsg::ReceiptsByHeight {
+                min_height,
+                max_height,
+                start_after: start_after.map(Uint128::new),
+                limit,
+            };
+        assert_eq!(
+            vec![1, 2, 3],
+            query_ids(deps.as_ref(), by_height(None, Some(11), None, None))
+        );
+        assert_eq!(
+            vec![3, 4],
+            query_ids(deps.as_ref(), by_height(Some(11), None, None, None))
+        );
+        assert_eq!(
+            vec![1, 2],
+            query_ids(deps.as_ref(), by_height(Some(10), None, None, Some(2)))
+        );
+        assert_eq!(
+            vec![3],
+            query_ids(deps.as_ref(), by_height(Some(10), Some(11), Some(2), None))
+        );
+
+        let msg = QueryMsg::ReceiptsByTime {
+            min_time: Some(Timestamp::from_seconds(55)),
+            max_time: Some(Timestamp::from_seconds(60)),
+            start_after: None,
+            limit: None,
+        };
+        assert_eq!(vec![3, 4], query_ids(deps.as_ref(), msg));
+        let msg = QueryMsg::ReceiptsByTime {
+            min_time: None,
+            max_time: Some(Timestamp::from_seconds(55)),
+            start_after: Some(Uint128::new(1)),
+            limit: None,
+        };
+        assert_eq!(vec![2, 3], query_ids(deps.as_ref(), msg));
+
+        // Editing a receipt keeps where and when it was stored.
+        let msg = ExecuteMsg::EditReceipt {
+            receipt_id: Uint128::new(3),
+            receipt: Receipt { events: vec![] },
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        assert_eq!(vec![2, 3], query_ids(deps.as_ref(), by_sender(None)));
+    }
+
+    #[test]
+    fn test_migrate_legacy_receipts() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            InstantiateMsg {
+                minter: "creator".to_string(),
+            },
+        )
+        .unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.2").unwrap();
+        let legacy_receipt = Receipt {
+            events: vec![Event::new("legacy")],
+        };
+        LEGACY_RECEIPT
+            .save(deps.as_mut().storage, 1, &legacy_receipt)
+            .unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        store(deps.as_mut(), "creator", None, 12);
+        let res: ReceiptResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Receipt {
+                    receipt_id: Uint128::new(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(legacy_receipt, res.receipt);
+
+        let msg = QueryMsg::Receipts {
+            start_after: None,
+            limit: None,
+        };
+        assert_eq!(vec![2], query_ids(deps.as_ref(), msg));
+
+        // Legacy receipts are not listed but can be started after, as can missing receipts.
+        let by_height = |start_after| QueryMsg::ReceiptsByHeight {
+            min_height: None,
+            max_height: None,
+            start_after: Some(Uint128::new(start_after)),
+            limit: None,
+        };
+        assert_eq!(vec![2], query_ids(deps.as_ref(), by_height(1)));
+        assert!(query_ids(deps.as_ref(), by_height(5)).is_empty());
+        let msg = QueryMsg::ReceiptsByTime {
+            min_time: None,
+            max_time: None,
+            start_after: Some(Uint128::new(1)),
+            limit: None,
+        };
+        assert_eq!(vec![2], query_ids(deps.as_ref(), msg));
+    }
+
+    #[test]
+    fn test_hook_receipts() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            InstantiateMsg {
+                minter: "creator".to_string(),
+            },
+        )
+        .unwrap();
+
+        let transfer_hook = QueryMsg::AndrHook(AndromedaHook::OnTransfer {
+            token_id: "1".to_string(),
+            sender: "owner".to_string(),
+            recipient: "recipient".to_string(),
+        });
+        let execute_hook = QueryMsg::AndrHook(AndromedaHook::OnExecute {
+            sender: "owner".to_string(),
+            payload: encode_binary(&"burn").unwrap(),
+            merkle_proof: None,
+        });
+        let res: Option<HookResponse> =
+            from_binary(&query(deps.as_ref(), env.clone(), transfer_hook.clone()).unwrap())
+                .unwrap();
+        assert_eq!(None, res);
+
+        let msg = ExecuteMsg::SetReceiptHooks {
+            ado: "ado1".to_string(),
+            hooks: ReceiptHooks {
+                on_execute: false,
+                on_transfer: true,
+            },
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+        execute(deps.as_mut(), env.clone(), mock_info("creator", &[]), msg).unwrap();
+
+        let res: ReceiptHooksResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ReceiptHooks {
+                    ado: "ado1".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(res.hooks.on_transfer && !res.hooks.on_execute);
+
+        let res: Option<HookResponse> =
+            from_binary(&query(deps.as_ref(), env.clone(), execute_hook).unwrap()).unwrap();
+        assert_eq!(None, res);
+
+        let events = vec![Event::new("transfer")
+            .add_attribute("token_id", "1")
+            .add_attribute("recipient", "recipient")];
+        let store_msg = ExecuteMsg::StoreReceipt {
+            receipt: Receipt {
+                events: events.clone(),
+            },
+            sender: Some("owner".to_string()),
+            hook: Some(ReceiptHook::OnTransfer),
+        };
+        let res: Option<HookResponse> =
+            from_binary(&query(deps.as_ref(), env.clone(), transfer_hook).unwrap()).unwrap();
+        assert_eq!(
+            Some(HookResponse {
+                msgs: vec![SubMsg::new(WasmMsg::Execute {
+                    contract_addr: MOCK_CONTRACT_ADDR.to_string(),
+                    msg: encode_binary(&store_msg).unwrap(),
+                    funds: vec![],
+                })],
+                events: vec![],
+            }),
+            res
+        );
+
+        // The receipt is only stored for the ADO that enabled it.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("ado2", &[]),
+            store_msg.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            Response::new()
+                .add_attributes(vec![attr("action", "skip_receipt"), attr("ado", "ado2"),]),
+            res
+        );
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("ado1", &[]),
+            store_msg,
+        )
+        .unwrap();
+
+        let msg = QueryMsg::ReceiptsByAdo {
+            ado: "ado1".to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let res: ReceiptsResponse = from_binary(&query(deps.as_ref(), env, msg).unwrap()).unwrap();
+        assert_eq!(1, res.receipts.len());
+        assert_eq!(Receipt { events }, res.receipts[0].receipt);
+        assert_eq!(Some("owner".to_string()), res.receipts[0].sender);
+    }
 }
@@ -1,12 +1,55 @@
 use ado_base::state::ADOContract;
-use andromeda_modules::receipt::{Config, Receipt};
+use andromeda_modules::receipt::{Config, Receipt, ReceiptHooks, ReceiptInfo};
 use common::error::ContractError;
-use cosmwasm_std::{StdResult, Storage, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 pub const CONFIG: Item<Config> = Item::new("config");
-const RECEIPT: Map<u128, Receipt> = Map::new("receipt");
-const NUM_RECEIPT: Item<Uint128> = Item::new("num_receipt");
+/// The receipts stored prior to version 0.2.0, which are kept readable but are not indexed.
+pub const LEGACY_RECEIPT: Map<u128, Receipt> = Map::new("receipt");
+pub const NUM_RECEIPT: Item<Uint128> = Item::new("num_receipt");
+/// The hooks for which each ADO records receipts.
+pub const ADO_RECEIPT_HOOKS: Map<&str, ReceiptHooks> = Map::new("ado_receipt_hooks");
+/// The hooks for which any ADO records receipts.
+pub const ENABLED_RECEIPT_HOOKS: Item<ReceiptHooks> = Item::new("enabled_receipt_hooks");
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+pub struct ReceiptIndexes<'a> {
+    /// Receipts without a sender are indexed under the empty string.
+    pub sender: MultiIndex<'a, String, ReceiptInfo, u128>,
+    pub ado: MultiIndex<'a, String, ReceiptInfo, u128>,
+    pub height: MultiIndex<'a, u64, ReceiptInfo, u128>,
+    /// Receipts indexed by the nanoseconds of their block time.
+    pub time: MultiIndex<'a, u64, ReceiptInfo, u128>,
+}
+
+impl<'a> IndexList<ReceiptInfo> for ReceiptIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<ReceiptInfo>> + '_> {
+        let v: Vec<&dyn Index<ReceiptInfo>> =
+            vec![&self.sender, &self.ado, &self.height, &self.time];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn receipts<'a>() -> IndexedMap<'a, u128, ReceiptInfo, ReceiptIndexes<'a>> {
+    let indexes = ReceiptIndexes {
+        sender: MultiIndex::new(
+            |r: &ReceiptInfo| r.sender.clone().unwrap_or_default(),
+            "receipts",
+            "receipts__sender",
+        ),
+        ado: MultiIndex::new(|r: &ReceiptInfo| r.ado.clone(), "receipts", "receipts__ado"),
+        height: MultiIndex::new(|r: &ReceiptInfo| r.height, "receipts", "receipts__height"),
+        time: MultiIndex::new(
+            |r: &ReceiptInfo| r.time.nanos(),
+            "receipts",
+            "receipts__time",
+        ),
+    };
+    IndexedMap::new("receipts", indexes)
+}
 
 pub fn store_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
     CONFIG.save(storage, config)
@@ -17,29 +60,170 @@ pub fn can_mint_receipt(storage: &dyn Storage, addr: &str) -> Result<bool, Contr
     Ok(ADOContract::default().is_owner_or_operator(storage, addr)? || addr.eq(&config.minter))
 }
 
-// increase receipt ID
+/// Sets the hooks for which `ado` records receipts and updates the hooks enabled for any ADO.
+pub fn set_receipt_hooks(
+    storage: &mut dyn Storage,
+    ado: &str,
+    hooks: ReceiptHooks,
+) -> StdResult<()> {
+    if hooks == ReceiptHooks::default() {
+        ADO_RECEIPT_HOOKS.remove(storage, ado);
+    } else {
+        ADO_RECEIPT_HOOKS.save(storage, ado, &hooks)?;
+    }
+    let enabled = ADO_RECEIPT_HOOKS
+        .range(storage, None, None, Order::Ascending)
+        .try_fold(ReceiptHooks::default(), |enabled, item| {
+            let (_, hooks) = item?;
+            StdResult::Ok(ReceiptHooks {
+                on_execute: enabled.on_execute || hooks.on_execute,
+                on_transfer: enabled.on_transfer || hooks.on_transfer,
+            })
+        })?;
+    ENABLED_RECEIPT_HOOKS.save(storage, &enabled)
+}
+
+/// Increments the receipt count and returns it as the ID of the next receipt.
 pub fn increment_num_receipt(storage: &mut dyn Storage) -> StdResult<Uint128> {
-    let mut receipt_count = NUM_RECEIPT.load(storage).unwrap_or_default();
-    //Changed type conversion from explicit to implicit. [AKP-01] (Delete when reviewed)
-    //Added checked_add function to make sure that no overflow occurs [ACP-02] (Delete when reviewed)
-    let res = receipt_count.checked_add(Uint128::from(1u128));
-    //Check that no overflow, else panic.
-    match res {
-        Err(error) => panic!("Problem adding: {:?}", error),
-        _ => receipt_count = res.unwrap(),
-    };
+    let receipt_count = NUM_RECEIPT
+        .may_load(storage)?
+        .unwrap_or_default()
+        .checked_add(Uint128::from(1u128))?;
+    NUM_RECEIPT.save(storage, &receipt_count)?;
     Ok(receipt_count)
 }
 
-pub fn store_receipt(
+pub fn store_receipt(storage: &mut dyn Storage, receipt_info: &ReceiptInfo) -> StdResult<()> {
+    receipts().save(storage, receipt_info.receipt_id.u128(), receipt_info)
+}
+
+/// Replaces the receipt with the given ID, keeping where and when it was stored.
+pub fn edit_receipt(
     storage: &mut dyn Storage,
     receipt_id: Uint128,
-    receipt: &Receipt,
+    receipt: Receipt,
 ) -> StdResult<()> {
-    RECEIPT.save(storage, receipt_id.u128(), receipt)
+    match receipts().may_load(storage, receipt_id.u128())? {
+        Some(receipt_info) => store_receipt(
+            storage,
+            &ReceiptInfo {
+                receipt,
+                ..receipt_info
+            },
+        ),
+        None => {
+            LEGACY_RECEIPT.load(storage, receipt_id.u128())?;
+            LEGACY_RECEIPT.save(storage, receipt_id.u128(), &receipt)
+        }
+    }
 }
+
 pub fn read_receipt(storage: &dyn Storage, receipt_id: Uint128) -> StdResult<Receipt> {
-    RECEIPT.load(storage, receipt_id.u128())
+    match receipts().may_load(storage, receipt_id.u128())? {
+        Some(receipt_info) => Ok(receipt_info.receipt),
+        None => LEGACY_RECEIPT.load(storage, receipt_id.u128()),
+    }
+}
+
+/// The receipts to query, selected by one of the indexes.
+pub enum ReceiptFilter {
+    All,
+    Sender(String),
+    Ado(String),
+    Height {
+        min_height: Option<u64>,
+        max_height: Option<u64>,
+    },
+    /// Block times in nanoseconds.
+    Time {
+        min_time: Option<u64>,
+        max_time: Option<u64>,
+    },
+}
+
+/// Lists the receipts matching `filter` in ascending order of ID. Receipts stored prior to
+/// version 0.2.0 are not indexed and so are never listed, but `start_after` may be the ID of one.
+pub fn load_receipts(
+    storage: &dyn Storage,
+    filter: ReceiptFilter,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ReceiptInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after.map(|id| id.u128());
+    let start = start_after.map(Bound::exclusive);
+    let receipts = receipts();
+    let iter = match filter {
+        ReceiptFilter::All => receipts.range(storage, start, None, Order::Ascending),
+        ReceiptFilter::Sender(sender) => {
+            receipts
+                .idx
+                .sender
+                .prefix(sender)
+                .range(storage, start, None, Order::Ascending)
+        }
+        ReceiptFilter::Ado(ado) => {
+            receipts
+                .idx
+                .ado
+                .prefix(ado)
+                .range(storage, start, None, Order::Ascending)
+        }
+        ReceiptFilter::Height {
+            min_height,
+            max_height,
+        } => range_by_block(
+            storage,
+            &receipts.idx.height,
+            |r| r.height,
+            min_height,
+            max_height,
+            start_after,
+        )?,
+        ReceiptFilter::Time { min_time, max_time } => range_by_block(
+            storage,
+            &receipts.idx.time,
+            |r| r.time.nanos(),
+            min_time,
+            max_time,
+            start_after,
+        )?,
+    };
+    iter.take(limit)
+        .map(|item| item.map(|(_, receipt_info)| receipt_info))
+        .collect()
+}
+
+type ReceiptIter<'c> = Box<dyn Iterator<Item = StdResult<(u128, ReceiptInfo)>> + 'c>;
+
+/// Ranges over the receipts stored between `min` and `max`, inclusive, of an index by block
+/// height or time. As these never decrease with the receipt ID, the first receipt after
+/// `start_after` locates where to resume in the index, even if `start_after` is a legacy receipt
+/// or does not exist.
+fn range_by_block<'c>(
+    storage: &'c dyn Storage,
+    index: &MultiIndex<'c, u64, ReceiptInfo, u128>,
+    key: fn(&ReceiptInfo) -> u64,
+    min: Option<u64>,
+    max: Option<u64>,
+    start_after: Option<u128>,
+) -> StdResult<ReceiptIter<'c>> {
+    let min_key = min.unwrap_or_default();
+    let start = match start_after {
+        Some(id) => {
+            let next = receipts()
+                .range(storage, Some(Bound::exclusive(id)), None, Order::Ascending)
+                .next()
+                .transpose()?;
+            match next {
+                Some((next_id, next)) => Bound::inclusive((key(&next).max(min_key), next_id)),
+                None => return Ok(Box::new(std::iter::empty())),
+            }
+        }
+        None => Bound::inclusive((min_key, 0u128)),
+    };
+    let end = max.map(|max| Bound::inclusive((max, u128::MAX)));
+    Ok(index.range(storage, Some(start), end, Order::Ascending))
 }
 
 #[cfg(test)]
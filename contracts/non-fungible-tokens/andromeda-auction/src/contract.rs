@@ -17,16 +17,18 @@ use common::{
 };
 use cosmwasm_std::{
     attr, coins, ensure, entry_point, from_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin,
-    CosmosMsg, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, QueryRequest, Response, StdError,
-    Storage, SubMsg, Uint128, WasmMsg, WasmQuery,
+    CosmosMsg, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, QueryRequest, Response, Storage,
+    SubMsg, Uint128, WasmMsg, WasmQuery,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, Expiration, OwnerOfResponse};
 use cw_utils::nonpayable;
-use semver::Version;
 
 const CONTRACT_NAME: &str = "crates.io:andromeda_auction";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Cancelling returns the token and the highest bid, so it remains executable while every user
+/// message is paused.
+const ADMIN_MESSAGES: &[&str] = &["cancel_auction"];
 
 #[entry_point]
 pub fn instantiate(
@@ -37,6 +39,7 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     NEXT_AUCTION_ID.save(deps.storage, &Uint128::from(1u128))?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -66,17 +69,18 @@ pub fn execute(
         return contract.execute(deps, env, info, andr_msg, execute);
     };
 
-    contract.module_hook::<Response>(
+    let hook_response = contract.module_hook_response(
         deps.storage,
         deps.api,
         deps.querier,
         AndromedaHook::OnExecute {
             sender: info.sender.to_string(),
             payload: encode_binary(&msg)?,
+            merkle_proof: None,
         },
     )?;
 
-    match msg {
+    let res = match msg {
         ExecuteMsg::AndrReceive(msg) => {
             ADOContract::default().execute(deps, env, info, msg, execute)
         }
@@ -113,7 +117,10 @@ pub fn execute(
             token_id,
             token_address,
         } => execute_claim(deps, env, info, token_id, token_address),
-    }
+    }?;
+    Ok(res
+        .add_submessages(hook_response.msgs)
+        .add_events(hook_response.events))
 }
 
 fn handle_receive_cw721(
@@ -520,7 +527,7 @@ fn purchase_token(
 
     let remaining_amount = remainder.try_get_coin()?;
 
-    let tax_amount = get_tax_amount(&msgs, state.high_bidder_amount, remaining_amount.amount);
+    let tax_amount = get_tax_amount(&msgs, state.high_bidder_amount, remaining_amount.amount)?;
 
     // Calculate total tax
     total_tax_amount += tax_amount;
@@ -691,41 +698,10 @@ fn query_owner_of(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg(test)]
@@ -737,7 +713,7 @@ mod tests {
     use crate::state::AuctionInfo;
     use andromeda_non_fungible_tokens::auction::{Cw721HookMsg, ExecuteMsg, InstantiateMsg};
     use andromeda_testing::testing::mock_querier::{MOCK_RATES_CONTRACT, MOCK_RATES_RECIPIENT};
-    use common::ado_base::modules::Module;
+    use common::ado_base::{modules::Module, AndromedaMsg};
     use common::app::AndrAddress;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
     use cosmwasm_std::{attr, coin, coins, from_binary, BankMsg, CosmosMsg, Response, Timestamp};
@@ -1618,6 +1594,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn execute_place_bid_paused() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let mut env = mock_env();
+        let info = mock_info("owner", &[]);
+        let msg = InstantiateMsg { modules: None };
+        let _res = instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        start_auction(deps.as_mut(), None, None);
+
+        let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause {
+            messages: Some(vec!["place_bid".to_string()]),
+        });
+        let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let msg = ExecuteMsg::PlaceBid {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+        };
+        env.block.time = Timestamp::from_seconds(150);
+        let info = mock_info("sender", &coins(100, "uusd".to_string()));
+        let res = execute(deps.as_mut(), env.clone(), info, msg);
+        assert_eq!(
+            ContractError::Paused {
+                msg: "place_bid".to_string()
+            },
+            res.unwrap_err()
+        );
+
+        // Claiming is unaffected.
+        env.block.time = Timestamp::from_seconds(250);
+        let msg = ExecuteMsg::Claim {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+        };
+        let info = mock_info("any_user", &[]);
+        let _res = execute(deps.as_mut(), env, info, msg).unwrap();
+    }
+
+    #[test]
+    fn execute_pause_all() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let mut env = mock_env();
+        let info = mock_info("owner", &[]);
+        let msg = InstantiateMsg { modules: None };
+        let _res = instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        start_auction(deps.as_mut(), None, None);
+
+        let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause { messages: None });
+        let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        // User messages are paused for the owner as well.
+        let msg = ExecuteMsg::PlaceBid {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+        };
+        env.block.time = Timestamp::from_seconds(150);
+        let info = mock_info("owner", &coins(100, "uusd".to_string()));
+        let res = execute(deps.as_mut(), env.clone(), info, msg);
+        assert_eq!(
+            ContractError::Paused {
+                msg: "all".to_string()
+            },
+            res.unwrap_err()
+        );
+
+        // The auction can still be cancelled.
+        let msg = ExecuteMsg::CancelAuction {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+        };
+        let info = mock_info(MOCK_TOKEN_OWNER, &[]);
+        let _res = execute(deps.as_mut(), env, info, msg).unwrap();
+    }
+
     #[test]
     fn execute_claim_no_bids() {
         let mut deps = mock_dependencies_custom(&[]);
@@ -1723,6 +1775,7 @@ mod tests {
                 identifier: MOCK_RATES_CONTRACT.to_owned(),
             },
             is_mutable: true,
+            priority: None,
         };
         let msg = InstantiateMsg {
             modules: Some(vec![module]),
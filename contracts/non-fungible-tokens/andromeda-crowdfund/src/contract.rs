@@ -18,8 +18,7 @@ use common::{
     rates::get_tax_amount,
     Funds,
 };
-use cw2::{get_contract_version, set_contract_version};
-use semver::Version;
+use cw2::set_contract_version;
 
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
@@ -37,6 +36,8 @@ const DEFAULT_LIMIT: u32 = 50;
 pub(crate) const MAX_MINT_LIMIT: u32 = 100;
 const CONTRACT_NAME: &str = "crates.io:andromeda-crowdfund";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Owner messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &["mint", "start_sale"];
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -55,6 +56,7 @@ pub fn instantiate(
     )?;
     SALE_CONDUCTED.save(deps.storage, &false)?;
     NUMBER_OF_TOKENS_AVAILABLE.save(deps.storage, &Uint128::zero())?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -108,17 +110,23 @@ pub fn execute(
         return contract.execute(deps, env, info, andr_msg, execute);
     };
 
-    contract.module_hook::<Response>(
+    let merkle_proof = match &msg {
+        ExecuteMsg::Purchase { merkle_proof, .. }
+        | ExecuteMsg::PurchaseByTokenId { merkle_proof, .. } => merkle_proof.clone(),
+        _ => None,
+    };
+    let hook_response = contract.module_hook_response(
         deps.storage,
         deps.api,
         deps.querier,
         AndromedaHook::OnExecute {
             sender: info.sender.to_string(),
             payload: encode_binary(&msg)?,
+            merkle_proof,
         },
     )?;
 
-    match msg {
+    let res = match msg {
         ExecuteMsg::AndrReceive(msg) => contract.execute(deps, env, info, msg, execute),
         ExecuteMsg::Mint(mint_msgs) => execute_mint(deps, env, info, mint_msgs),
         ExecuteMsg::StartSale {
@@ -137,15 +145,18 @@ pub fn execute(
             max_amount_per_wallet,
             recipient,
         ),
-        ExecuteMsg::Purchase { number_of_tokens } => {
-            execute_purchase(deps, env, info, number_of_tokens)
-        }
-        ExecuteMsg::PurchaseByTokenId { token_id } => {
+        ExecuteMsg::Purchase {
+            number_of_tokens, ..
+        } => execute_purchase(deps, env, info, number_of_tokens),
+        ExecuteMsg::PurchaseByTokenId { token_id, .. } => {
             execute_purchase_by_token_id(deps, env, info, token_id)
         }
         ExecuteMsg::ClaimRefund {} => execute_claim_refund(deps, env, info),
         ExecuteMsg::EndSale { limit } => execute_end_sale(deps, env, info, limit),
-    }
+    }?;
+    Ok(res
+        .add_submessages(hook_response.msgs)
+        .add_events(hook_response.events))
 }
 
 fn execute_mint(
@@ -454,7 +465,7 @@ fn purchase_tokens(
     for token_id in token_ids {
         let remaining_amount = remainder.try_get_coin()?;
 
-        let tax_amount = get_tax_amount(&msgs, state.price.amount, remaining_amount.amount);
+        let tax_amount = get_tax_amount(&msgs, state.price.amount, remaining_amount.amount)?;
 
         let purchase = Purchase {
             token_id: token_id.clone(),
@@ -836,39 +847,8 @@ fn query_is_token_available(deps: Deps, id: String) -> bool {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
@@ -108,6 +108,7 @@ fn test_instantiate() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
 
     let res = init(deps.as_mut(), Some(modules));
@@ -572,6 +573,7 @@ fn test_purchase_sale_not_started() {
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
 
     let info = mock_info("sender", &[]);
@@ -580,6 +582,7 @@ fn test_purchase_sale_not_started() {
 
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[0].to_owned(),
+        merkle_proof: None,
     };
 
     let res = execute(deps.as_mut(), mock_env(), info, msg);
@@ -611,6 +614,7 @@ fn test_purchase_sale_not_ended() {
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
 
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg);
@@ -618,6 +622,7 @@ fn test_purchase_sale_not_ended() {
 
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[0].to_owned(),
+        merkle_proof: None,
     };
 
     let res = execute(deps.as_mut(), mock_env(), info, msg);
@@ -651,12 +656,14 @@ fn test_purchase_no_funds() {
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg);
     assert_eq!(ContractError::InsufficientFunds {}, res.unwrap_err());
 
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[0].to_owned(),
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info, msg);
     assert_eq!(ContractError::InsufficientFunds {}, res.unwrap_err());
@@ -689,12 +696,14 @@ fn test_purchase_wrong_denom() {
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg);
     assert_eq!(ContractError::InsufficientFunds {}, res.unwrap_err());
 
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[0].to_owned(),
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info, msg);
     assert_eq!(ContractError::InsufficientFunds {}, res.unwrap_err());
@@ -709,6 +718,7 @@ fn test_purchase_not_enough_for_price() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     init(deps.as_mut(), Some(modules));
 
@@ -734,12 +744,14 @@ fn test_purchase_not_enough_for_price() {
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg);
     assert_eq!(ContractError::InsufficientFunds {}, res.unwrap_err());
 
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[0].to_owned(),
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info, msg);
     assert_eq!(ContractError::InsufficientFunds {}, res.unwrap_err());
@@ -754,6 +766,7 @@ fn test_purchase_not_enough_for_tax() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     init(deps.as_mut(), Some(modules));
 
@@ -783,6 +796,7 @@ fn test_purchase_not_enough_for_tax() {
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg);
     assert_eq!(ContractError::InsufficientFunds {}, res.unwrap_err());
@@ -797,6 +811,7 @@ fn test_purchase_not_enough_for_tax() {
 
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[0].to_owned(),
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info, msg);
     assert_eq!(ContractError::InsufficientFunds {}, res.unwrap_err());
@@ -811,6 +826,7 @@ fn test_purchase_by_token_id_not_available() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     init(deps.as_mut(), Some(modules));
 
@@ -836,6 +852,7 @@ fn test_purchase_by_token_id_not_available() {
 
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[1].to_owned(),
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info, msg);
     assert_eq!(ContractError::TokenNotAvailable {}, res.unwrap_err());
@@ -850,6 +867,7 @@ fn test_purchase_by_token_id() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     init(deps.as_mut(), Some(modules));
 
@@ -874,6 +892,7 @@ fn test_purchase_by_token_id() {
     // Purchase a token.
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[0].to_owned(),
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
     assert_eq!(
@@ -898,6 +917,7 @@ fn test_purchase_by_token_id() {
     // Purchase a second one.
     let msg = ExecuteMsg::PurchaseByTokenId {
         token_id: MOCK_TOKENS_FOR_SALE[1].to_owned(),
+        merkle_proof: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info, msg);
 
@@ -913,6 +933,7 @@ fn test_multiple_purchases() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     init(deps.as_mut(), Some(modules));
 
@@ -955,6 +976,7 @@ fn test_multiple_purchases() {
     // Purchase 2 tokens
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(2),
+        merkle_proof: None,
     };
 
     let mut state = State {
@@ -998,6 +1020,7 @@ fn test_multiple_purchases() {
     // Purchase max number of tokens.
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
 
     let info = mock_info("sender", &coins(300u128, "uusd"));
@@ -1033,6 +1056,7 @@ fn test_multiple_purchases() {
     // Try to purchase an additional token when limit has already been reached.
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
 
     let res = execute(deps.as_mut(), mock_env(), info, msg);
@@ -1042,6 +1066,7 @@ fn test_multiple_purchases() {
     // User 2 tries to purchase 2 but only 1 is left.
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(2),
+        merkle_proof: None,
     };
 
     let info = mock_info("user2", &coins(300, "uusd"));
@@ -1079,6 +1104,7 @@ fn test_multiple_purchases() {
     // User 2 tries to purchase again.
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
 
     let info = mock_info("user2", &coins(150, "uusd"));
@@ -1096,6 +1122,7 @@ fn test_purchase_more_than_allowed_per_wallet() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     init(deps.as_mut(), Some(modules));
 
@@ -1108,6 +1135,7 @@ fn test_purchase_more_than_allowed_per_wallet() {
     // Try to purchase 4
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(4),
+        merkle_proof: None,
     };
 
     let state = State {
@@ -1194,6 +1222,7 @@ fn test_integration_conditions_not_met() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     init(deps.as_mut(), Some(modules));
 
@@ -1227,23 +1256,27 @@ fn test_integration_conditions_not_met() {
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let info = mock_info("A", &coins(150, "uusd"));
     let _res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let info = mock_info("B", &coins(150, "uusd"));
     let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let info = mock_info("C", &coins(150, "uusd"));
     let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -1372,6 +1405,7 @@ fn test_integration_conditions_met() {
             identifier: MOCK_RATES_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     init(deps.as_mut(), Some(modules));
     let mut env = mock_env();
@@ -1396,29 +1430,34 @@ fn test_integration_conditions_met() {
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let info = mock_info("A", &coins(150, "uusd"));
     let _res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let info = mock_info("B", &coins(150, "uusd"));
     let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let info = mock_info("C", &coins(150, "uusd"));
     let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: Some(1),
+        merkle_proof: None,
     };
     let info = mock_info("D", &coins(150, "uusd"));
     let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -1742,6 +1781,7 @@ fn test_validate_andr_addresses_nonexisting_module() {
         modules: Some(vec![Module {
             module_type: "address_list".to_string(),
             is_mutable: true,
+            priority: None,
             address: AndrAddress {
                 identifier: "z".to_string(),
             },
@@ -1830,6 +1870,7 @@ fn test_addresslist() {
             identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
     let msg = InstantiateMsg {
         token_address: AndrAddress {
@@ -1858,6 +1899,7 @@ fn test_addresslist() {
     // Not whitelisted user
     let msg = ExecuteMsg::Purchase {
         number_of_tokens: None,
+        merkle_proof: None,
     };
     let info = mock_info("not_whitelisted", &[]);
     let res = execute(deps.as_mut(), mock_env(), info, msg);
@@ -10,7 +10,7 @@ use andromeda_non_fungible_tokens::{
 };
 use common::{
     ado_base::{
-        hooks::{AndromedaHook, OnFundsTransferResponse},
+        hooks::{AndromedaHook, HookResponse, OnFundsTransferResponse},
         InstantiateMsg as BaseInstantiateMsg,
     },
     encode_binary,
@@ -24,11 +24,10 @@ use cosmwasm_std::{
     ensure, has_coins, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
     QuerierWrapper, QueryRequest, Response, StdError, Storage, SubMsg, Uint128, WasmMsg, WasmQuery,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw721::{Expiration, OwnerOfResponse};
 use cw_storage_plus::Bound;
 use cw_utils::nonpayable;
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-cw721-bids";
@@ -69,6 +68,7 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    ADOContract::default().ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::PlaceBid {
             token_id,
@@ -155,7 +155,7 @@ fn execute_place_bid(
     )?
     .unwrap();
     let remaining_amount = res.leftover_funds.try_get_coin()?;
-    let tax_amount = get_tax_amount(&res.msgs, bid_amount, remaining_amount.amount);
+    let tax_amount = get_tax_amount(&res.msgs, bid_amount, remaining_amount.amount)?;
     let bid = Bid {
         purchaser: purchaser.to_owned(),
         denom: coin.denom.clone(),
@@ -288,41 +288,8 @@ fn get_token_owner(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -345,7 +312,7 @@ fn handle_andr_hook(deps: Deps, env: Env, msg: AndromedaHook) -> Result<Binary,
             sender,
             recipient,
         } => {
-            let mut resp: Response = Response::new();
+            let mut resp = HookResponse::default();
             let bid = bids().may_load(deps.storage, &token_id)?;
             if let Some(bid) = bid {
                 if bid.purchaser == recipient {
@@ -364,13 +331,13 @@ fn handle_andr_hook(deps: Deps, env: Env, msg: AndromedaHook) -> Result<Binary,
                             recipient: sender,
                         })?,
                     });
-                    resp = resp.add_message(msg);
+                    resp.msgs.push(SubMsg::new(msg));
                 }
             }
 
             Ok(encode_binary(&resp)?)
         }
-        _ => Ok(encode_binary(&None::<Response>)?),
+        _ => Ok(encode_binary(&None::<HookResponse>)?),
     }
 }
 
@@ -7,7 +7,10 @@ use andromeda_testing::testing::mock_querier::{
     bank_sub_msg, mock_dependencies_custom, MOCK_CW721_CONTRACT, MOCK_RATES_RECIPIENT,
     MOCK_TOKEN_TRANSFER_AGREEMENT,
 };
-use common::{ado_base::hooks::AndromedaHook, error::ContractError};
+use common::{
+    ado_base::hooks::{AndromedaHook, HookResponse},
+    error::ContractError,
+};
 use cosmwasm_std::{
     coins, from_binary,
     testing::{mock_env, mock_info},
@@ -403,7 +406,7 @@ fn test_on_transfer_hook() {
         recipient: purchaser,
     });
 
-    let res: Response = from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+    let res: HookResponse = from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
 
     let msg: CosmosMsg = CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: mock_env().contract.address.to_string(),
@@ -414,5 +417,11 @@ fn test_on_transfer_hook() {
         })
         .unwrap(),
     });
-    assert_eq!(Response::new().add_message(msg), res);
+    assert_eq!(
+        HookResponse {
+            msgs: vec![SubMsg::new(msg)],
+            events: vec![],
+        },
+        res
+    );
 }
@@ -2,7 +2,7 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, ensure, has_coins, to_binary, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut,
-    Empty, Env, MessageInfo, QuerierWrapper, Response, StdError, Storage, SubMsg, Uint128,
+    Empty, Env, MessageInfo, QuerierWrapper, Response, Storage, SubMsg, Uint128,
 };
 
 use crate::state::{is_archived, ANDR_MINTER, ARCHIVED, TRANSFER_AGREEMENTS};
@@ -10,12 +10,12 @@ use ado_base::state::ADOContract;
 use andromeda_non_fungible_tokens::cw721::{
     ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, TokenExtension, TransferAgreement,
 };
-use cw2::{get_contract_version, set_contract_version};
-use semver::Version;
+use cw2::set_contract_version;
 
 use common::{
     ado_base::{
         hooks::{AndromedaHook, OnFundsTransferResponse},
+        roles::MINTER,
         AndromedaMsg, InstantiateMsg as BaseInstantiateMsg,
     },
     encode_binary,
@@ -113,17 +113,18 @@ pub fn execute(
         );
     }
 
-    contract.module_hook::<Response>(
+    let hook_response = contract.module_hook_response(
         execute_env.deps.storage,
         execute_env.deps.api,
         execute_env.deps.querier,
         AndromedaHook::OnExecute {
             sender: execute_env.info.sender.to_string(),
             payload: encode_binary(&msg)?,
+            merkle_proof: None,
         },
     )?;
 
-    match msg {
+    let res = match msg {
         ExecuteMsg::Mint(_) => execute_mint(execute_env, msg),
         ExecuteMsg::BatchMint { tokens } => execute_batch_mint(execute_env, tokens),
         ExecuteMsg::TransferNft {
@@ -142,7 +143,10 @@ pub fn execute(
             execute_env.info,
             msg.into(),
         )?),
-    }
+    }?;
+    Ok(res
+        .add_submessages(hook_response.msgs)
+        .add_events(hook_response.events))
 }
 
 fn execute_mint(env: ExecuteEnv, msg: ExecuteMsg) -> Result<Response, ContractError> {
@@ -158,6 +162,7 @@ fn execute_mint(env: ExecuteEnv, msg: ExecuteMsg) -> Result<Response, ContractEr
         )?)?;
         save_minter(&cw721_contract, deps.storage, &addr)?;
     }
+    let info = get_mint_info(&cw721_contract, deps.storage, info)?;
 
     Ok(cw721_contract.execute(deps, env, info, msg.into())?)
 }
@@ -183,6 +188,7 @@ fn execute_batch_mint(
         )?)?;
         save_minter(&cw721_contract, deps.storage, &addr)?;
     }
+    let info = get_mint_info(&cw721_contract, deps.storage, info)?;
     for msg in tokens_to_mint {
         let mint_resp = cw721_contract.mint(deps.branch(), env.clone(), info.clone(), msg)?;
         resp = resp
@@ -193,6 +199,22 @@ fn execute_batch_mint(
     Ok(resp)
 }
 
+/// Holders of the minter role mint on behalf of the configured minter.
+fn get_mint_info(
+    cw721_contract: &AndrCW721Contract,
+    storage: &dyn Storage,
+    info: MessageInfo,
+) -> Result<MessageInfo, ContractError> {
+    if ADOContract::default().has_role(storage, MINTER, info.sender.as_str()) {
+        Ok(MessageInfo {
+            sender: cw721_contract.minter.load(storage)?,
+            funds: info.funds,
+        })
+    } else {
+        Ok(info)
+    }
+}
+
 fn save_minter(
     cw721_contract: &AndrCW721Contract,
     storage: &mut dyn Storage,
@@ -208,7 +230,7 @@ fn execute_transfer(
 ) -> Result<Response, ContractError> {
     let ExecuteEnv { deps, info, env } = env;
     let base_contract = ADOContract::default();
-    let responses = base_contract.module_hook::<Response>(
+    let hook_response = base_contract.module_hook_response(
         deps.storage,
         deps.api,
         deps.querier,
@@ -218,15 +240,9 @@ fn execute_transfer(
             recipient: recipient.clone(),
         },
     )?;
-    // Reduce all responses into one.
-    let mut resp = responses
-        .into_iter()
-        .reduce(|resp, r| {
-            resp.add_submessages(r.messages)
-                .add_events(r.events)
-                .add_attributes(r.attributes)
-        })
-        .unwrap_or_else(Response::new);
+    let mut resp = Response::new()
+        .add_submessages(hook_response.msgs)
+        .add_events(hook_response.events);
 
     let contract = AndrCW721Contract::default();
     let mut token = contract.tokens.load(deps.storage, &token_id)?;
@@ -253,7 +269,7 @@ fn execute_transfer(
             })?,
         )?;
         let remaining_amount = remainder.try_get_coin()?;
-        let tax_amount = get_tax_amount(&msgs, agreement_amount.amount, remaining_amount.amount);
+        let tax_amount = get_tax_amount(&msgs, agreement_amount.amount, remaining_amount.amount)?;
         msgs.push(SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
             to_address: token.owner.to_string(),
             amount: vec![remaining_amount],
@@ -470,39 +486,6 @@ fn handle_andr_hook(deps: Deps, msg: AndromedaHook) -> Result<Binary, ContractEr
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
@@ -9,6 +9,7 @@ use common::{
     ado_base::{
         hooks::{AndromedaHook, OnFundsTransferResponse},
         modules::{Module, ADDRESS_LIST, BIDS, RATES, RECEIPT},
+        roles::MINTER as MINTER_ROLE,
         AndromedaMsg, AndromedaQuery,
     },
     app::AndrAddress,
@@ -594,6 +595,7 @@ fn test_modules() {
                 identifier: MOCK_RECEIPT_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
         Module {
             module_type: RATES.to_owned(),
@@ -601,6 +603,7 @@ fn test_modules() {
                 identifier: MOCK_RATES_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
         Module {
             module_type: ADDRESS_LIST.to_owned(),
@@ -608,6 +611,7 @@ fn test_modules() {
                 identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
     ];
 
@@ -680,6 +684,8 @@ fn test_modules() {
             receipt: Receipt {
                 events: vec![Event::new("Royalty"), Event::new("Tax")],
             },
+            sender: Some("purchaser".to_string()),
+            hook: None,
         })
         .unwrap(),
         funds: vec![],
@@ -706,7 +712,7 @@ fn test_modules() {
 
     // Test the hook.
     let msg = QueryMsg::AndrHook(AndromedaHook::OnFundsTransfer {
-        sender: "sender".to_string(),
+        sender: "purchaser".to_string(),
         payload: to_binary(&token_id).unwrap(),
         amount: Funds::Native(coin(100, "uusd")),
     });
@@ -734,6 +740,7 @@ fn test_transfer_with_offer() {
             identifier: MOCK_BIDS_CONTRACT.to_owned(),
         },
         is_mutable: false,
+        priority: None,
     }];
 
     let mut deps = mock_dependencies_custom(&coins(100, "uusd"));
@@ -795,6 +802,7 @@ fn test_update_app_contract() {
                 identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
         Module {
             module_type: RATES.to_owned(),
@@ -802,6 +810,7 @@ fn test_update_app_contract() {
                 identifier: "b".to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
     ];
 
@@ -842,6 +851,7 @@ fn test_update_app_contract_invalid_minter() {
                 identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
         Module {
             module_type: RATES.to_owned(),
@@ -849,6 +859,7 @@ fn test_update_app_contract_invalid_minter() {
                 identifier: "b".to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
     ];
 
@@ -888,6 +899,7 @@ fn test_update_app_contract_invalid_module() {
                 identifier: MOCK_ADDRESSLIST_CONTRACT.to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
         Module {
             module_type: RATES.to_owned(),
@@ -895,6 +907,7 @@ fn test_update_app_contract_invalid_module() {
                 identifier: "k".to_owned(),
             },
             is_mutable: false,
+            priority: None,
         },
     ];
 
@@ -978,3 +991,66 @@ fn test_batch_mint() {
         i += 1;
     }
 }
+
+#[test]
+fn test_mint_minter_role() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone(), None);
+
+    let msg = ExecuteMsg::Mint(Box::new(MintMsg {
+        token_id: "1".to_string(),
+        owner: "owner".to_string(),
+        token_uri: None,
+        extension: TokenExtension {
+            name: "name".to_string(),
+            publisher: "owner".to_string(),
+            description: None,
+            attributes: vec![],
+            image: String::from(""),
+            image_data: None,
+            external_url: None,
+            animation_url: None,
+            youtube_url: None,
+        },
+    }));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("role_holder", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let grant_msg = ExecuteMsg::AndrReceive(AndromedaMsg::GrantRole {
+        role: MINTER_ROLE.to_string(),
+        address: "role_holder".to_string(),
+    });
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MINTER, &[]),
+        grant_msg,
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("role_holder", &[]),
+        msg,
+    )
+    .unwrap();
+    let query_resp = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::OwnerOf {
+            token_id: "1".to_string(),
+            include_expired: None,
+        },
+    )
+    .unwrap();
+    let resp: OwnerOfResponse = from_binary(&query_resp).unwrap();
+    assert_eq!("owner", resp.owner);
+}
@@ -15,15 +15,21 @@ use common::{
 };
 use cosmwasm_std::{attr, entry_point, Binary, Storage};
 use cosmwasm_std::{
-    ensure, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QueryRequest, Response, StdError,
-    Uint128, WasmMsg, WasmQuery,
+    ensure, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QueryRequest, Response, Uint128,
+    WasmMsg, WasmQuery,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw_utils::nonpayable;
-use semver::Version;
 
 const CONTRACT_NAME: &str = "crates.io:andromeda-gumball";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Refilling messages that remain executable while every user message is paused.
+const ADMIN_MESSAGES: &[&str] = &[
+    "mint",
+    "set_sale_details",
+    "update_required_coin",
+    "switch_status",
+];
 
 pub(crate) const MAX_MINT_LIMIT: u32 = 100;
 
@@ -42,6 +48,7 @@ pub fn instantiate(
     STATUS.save(deps.storage, &false)?;
     RANDOMNESS_PROVIDER.save(deps.storage, &msg.randomness_source)?;
     REQUIRED_COIN.save(deps.storage, &msg.required_coin)?;
+    ADOContract::default().register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
     ADOContract::default().instantiate(
         deps.storage,
         env,
@@ -65,6 +72,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
+    contract.ensure_execute_not_paused(deps.storage, &msg)?;
     match msg {
         ExecuteMsg::AndrReceive(msg) => contract.execute(deps, env, info, msg, execute),
         ExecuteMsg::Mint(mint_msg) => execute_mint(deps, env, info, mint_msg),
@@ -332,41 +340,10 @@ fn execute_buy(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
-}
-
-fn from_semver(err: semver::Error) -> StdError {
-    StdError::generic_err(format!("Semver: {}", err))
+    contract.register_admin_messages(deps.storage, ADMIN_MESSAGES)?;
+    contract.migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -398,7 +375,7 @@ fn query_state(deps: Deps) -> Result<State, ContractError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use common::ado_base::recipient::Recipient;
+    use common::ado_base::{recipient::Recipient, AndromedaMsg};
     use common::app::AndrAddress;
     use cosmwasm_std::coin;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
@@ -975,4 +952,49 @@ mod tests {
         let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
         assert_eq!(err, ContractError::OutOfNFTs {});
     }
+
+    #[test]
+    fn test_buy_paused() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("owner", &[]);
+        let msg = InstantiateMsg {
+            andromeda_cw721_contract: AndrAddress {
+                identifier: "cw721_contract".to_string(),
+            },
+            randomness_source: "terrand".to_string(),
+            required_coin: "uusd".to_string(),
+        };
+        instantiate(deps.as_mut(), env, info, msg).unwrap();
+        let info = mock_info("owner", &[]);
+        let msg = ExecuteMsg::SetSaleDetails {
+            price: coin(10, "uusd"),
+            max_amount_per_wallet: Some(Uint128::from(1_u64)),
+            recipient: Recipient::Addr("me".to_string()),
+        };
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Pause { messages: None });
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        // Refilling and opening the sale are still allowed while paused.
+        mint(deps.as_mut(), "token_id").unwrap();
+        let msg = ExecuteMsg::SwitchStatus {};
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        let info = mock_info("anyone", &[coin(10, "uusd")]);
+        let msg = ExecuteMsg::Buy {};
+        let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Paused {
+                msg: "all".to_string()
+            }
+        );
+
+        // Unpausing does not change the sale status.
+        let msg = ExecuteMsg::AndrReceive(AndromedaMsg::Unpause { messages: None });
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+        assert!(STATUS.load(&deps.storage).unwrap());
+    }
 }
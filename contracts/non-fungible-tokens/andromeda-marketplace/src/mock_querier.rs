@@ -1,6 +1,6 @@
 use andromeda_app::app::QueryMsg as AppQueryMsg;
 use common::{
-    ado_base::hooks::{AndromedaHook, HookMsg, OnFundsTransferResponse},
+    ado_base::hooks::{AndromedaHook, HookMsg, HookResponse, OnFundsTransferResponse},
     Funds,
 };
 use cosmwasm_std::{
@@ -167,9 +167,9 @@ impl WasmMockQuerier {
     fn handle_addresslist_query(&self, msg: &Binary) -> QuerierResult {
         match from_binary(msg).unwrap() {
             HookMsg::AndrHook(hook_msg) => match hook_msg {
-                AndromedaHook::OnExecute { sender, payload: _ } => {
+                AndromedaHook::OnExecute { sender, .. } => {
                     let whitelisted_addresses = ["sender"];
-                    let response: Response = Response::default();
+                    let response = HookResponse::default();
                     if whitelisted_addresses.contains(&sender.as_str()) {
                         SystemResult::Ok(ContractResult::Ok(to_binary(&response).unwrap()))
                     } else {
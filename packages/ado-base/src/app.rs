@@ -1,6 +1,8 @@
-use cosmwasm_std::{ensure, Addr, Api, Deps, QuerierWrapper, Storage};
+use cosmwasm_std::{ensure, from_slice, Addr, Api, Binary, Deps, QuerierWrapper, Storage};
 use schemars::JsonSchema;
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 use crate::ADOContract;
 use common::{app::AndrAddress, error::ContractError};
@@ -54,15 +56,9 @@ impl<'a> ADOContract<'a> {
         identifier: String,
         app_contract: Addr,
     ) -> Result<(), ContractError> {
-        // If the address passes this check then it doesn't refer to a app component by
-        // name.
-        if api.addr_validate(&identifier).is_err() {
-            ensure!(
-                self.component_exists(querier, identifier.clone(), app_contract)?,
-                ContractError::InvalidComponent { name: identifier }
-            );
-        }
-        Ok(())
+        validate_component_reference(api, identifier, |name| {
+            self.component_exists(querier, name.to_string(), app_contract)
+        })
     }
 
     fn component_exists(
@@ -74,3 +70,165 @@ impl<'a> ADOContract<'a> {
         Ok(querier.query_wasm_smart(app_contract, &AppQueryMsg::ComponentExists { name })?)
     }
 }
+
+/// Ensures that `identifier` is either an address or the name of a component for which
+/// `component_exists` holds.
+pub fn validate_component_reference(
+    api: &dyn Api,
+    identifier: String,
+    component_exists: impl FnOnce(&str) -> Result<bool, ContractError>,
+) -> Result<(), ContractError> {
+    // If the address passes this check then it doesn't refer to a app component by
+    // name.
+    if api.addr_validate(&identifier).is_err() {
+        ensure!(
+            component_exists(&identifier)?,
+            ContractError::InvalidComponent { name: identifier }
+        );
+    }
+    Ok(())
+}
+
+/// Extracts the identifiers of the `AndrAddress`es in a JSON message, including those in
+/// messages nested as base64 binaries. Messages that are not valid JSON have no identifiers.
+pub fn parse_identifiers(msg: &Binary) -> Vec<String> {
+    let mut identifiers: Vec<String> = vec![];
+    if let Ok(value) = from_slice::<JsonValue>(msg) {
+        collect_identifiers(&value, &mut identifiers);
+    }
+    identifiers
+}
+
+fn collect_identifiers(value: &JsonValue, identifiers: &mut Vec<String>) {
+    match value {
+        JsonValue::Object(fields) => {
+            for (key, value) in fields {
+                match value {
+                    JsonValue::String(identifier) if key == "identifier" => {
+                        identifiers.push(identifier.clone())
+                    }
+                    value => collect_identifiers(value, identifiers),
+                }
+            }
+        }
+        JsonValue::Array(values) => {
+            for value in values {
+                collect_identifiers(value, identifiers);
+            }
+        }
+        // Strings may be base64 encoded messages, such as the `msg` of a `Send`.
+        JsonValue::String(text) => {
+            if let Ok(msg) = Binary::from_base64(text) {
+                if let Ok(value @ (JsonValue::Object(_) | JsonValue::Array(_))) =
+                    from_slice::<JsonValue>(&msg)
+                {
+                    collect_identifiers(&value, identifiers);
+                }
+            }
+        }
+        JsonValue::Other => {}
+    }
+}
+
+/// A JSON value, keeping only what is needed to find identifiers.
+enum JsonValue {
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+    Other,
+}
+
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonValueVisitor)
+    }
+}
+
+struct JsonValueVisitor;
+
+impl<'de> Visitor<'de> for JsonValueVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(value.to_string()))
+    }
+
+    fn visit_bool<E: de::Error>(self, _value: bool) -> Result<JsonValue, E> {
+        Ok(JsonValue::Other)
+    }
+
+    fn visit_i64<E: de::Error>(self, _value: i64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Other)
+    }
+
+    fn visit_u64<E: de::Error>(self, _value: u64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Other)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Other)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonValue, A::Error> {
+        let mut values = vec![];
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(JsonValue::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonValue, A::Error> {
+        let mut fields = vec![];
+        while let Some((JsonKey(key), value)) = map.next_entry()? {
+            fields.push((key, value));
+        }
+        Ok(JsonValue::Object(fields))
+    }
+}
+
+/// The key of a JSON object, which `serde_json_wasm` can only deserialize as a `str`.
+struct JsonKey(String);
+
+impl<'de> Deserialize<'de> for JsonKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_str(JsonValueVisitor)
+            .and_then(|value| match value {
+                JsonValue::String(key) => Ok(JsonKey(key)),
+                _ => Err(de::Error::custom("expected a string key")),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_identifiers() {
+        let nested = Binary::from(br#"{"recipient":{"identifier":"nested"}}"#);
+        let msg = format!(
+            r#"{{
+                "token": {{ "identifier": "token" }},
+                "note": "\"identifier\":\"text\"",
+                "send": {{ "msg": "{}" }},
+                "list": [{{ "identifier": "quoted \"name\"" }}]
+            }}"#,
+            nested.to_base64()
+        );
+        assert_eq!(
+            vec![
+                "token".to_string(),
+                "nested".to_string(),
+                r#"quoted "name""#.to_string()
+            ],
+            parse_identifiers(&Binary::from(msg.as_bytes()))
+        );
+
+        assert!(parse_identifiers(&Binary::from(br#"{"identifier":"#)).is_empty());
+    }
+}
@@ -12,14 +12,17 @@ impl<'a> ADOContract<'a> {
 
     /// Helper function to query if a given address is the current contract owner.
     ///
-    /// Returns a boolean value indicating if the given address is the contract owner.
+    /// Returns a boolean value indicating if the given address is the contract owner. Always false
+    /// once ownership has been renounced.
     pub fn is_contract_owner(
         &self,
         storage: &dyn Storage,
         addr: &str,
     ) -> Result<bool, ContractError> {
-        let owner = self.owner.load(storage)?;
-        Ok(addr == owner)
+        match self.owner.may_load(storage)? {
+            Some(owner) => Ok(addr == owner),
+            None => Ok(false),
+        }
     }
 
     pub fn is_owner_or_operator(
@@ -29,4 +32,22 @@ impl<'a> ADOContract<'a> {
     ) -> Result<bool, ContractError> {
         Ok(self.is_contract_owner(storage, addr)? || self.is_operator(storage, addr))
     }
+
+    /// Helper function to query if a given address has been granted `role`.
+    ///
+    /// Returns a boolean value indicating if the given address holds the role.
+    pub fn has_role(&self, storage: &dyn Storage, role: &str, addr: &str) -> bool {
+        self.roles.has(storage, (role, addr))
+    }
+
+    /// Helper function used to gate an execute message behind a role. The contract owner is
+    /// implicitly allowed to perform any role-gated action.
+    pub fn is_owner_or_role(
+        &self,
+        storage: &dyn Storage,
+        role: &str,
+        addr: &str,
+    ) -> Result<bool, ContractError> {
+        Ok(self.is_contract_owner(storage, addr)? || self.has_role(storage, role, addr))
+    }
 }
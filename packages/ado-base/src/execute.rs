@@ -3,6 +3,7 @@ use common::{
     ado_base::{modules::Module, AndromedaMsg, ExecuteMsg, InstantiateMsg},
     app::AndrAddress,
     error::ContractError,
+    expiration::expiration_from_milliseconds,
     parse_message,
 };
 use cosmwasm_std::{
@@ -57,16 +58,52 @@ impl<'a> ADOContract<'a> {
                     !self.is_nested::<ExecuteMsg>(&data),
                     ContractError::NestedAndromedaMsg {}
                 );
+                if let Some(data) = &data {
+                    self.ensure_not_paused(deps.storage, data)?;
+                }
                 let received: E = parse_message(&data)?;
                 (execute_function)(deps, env, info, received)
             }
-            AndromedaMsg::UpdateOwner { address } => self.execute_update_owner(deps, info, address),
+            AndromedaMsg::ProxyReceive { origin, msg } => {
+                ensure!(
+                    self.get_app_contract(deps.storage)? == Some(info.sender),
+                    ContractError::Unauthorized {}
+                );
+                let origin_info = MessageInfo {
+                    sender: deps.api.addr_validate(&origin)?,
+                    funds: info.funds,
+                };
+                self.execute(
+                    deps,
+                    env,
+                    origin_info,
+                    AndromedaMsg::Receive(Some(msg)),
+                    execute_function,
+                )
+            }
+            AndromedaMsg::ProposeOwnership {
+                address,
+                expiration,
+            } => self.execute_propose_ownership(deps, env, info, address, expiration),
+            AndromedaMsg::AcceptOwnership {} => self.execute_accept_ownership(deps, env, info),
+            AndromedaMsg::CancelOwnershipProposal {} => {
+                self.execute_cancel_ownership_proposal(deps, info)
+            }
+            AndromedaMsg::RenounceOwnership {} => self.execute_renounce_ownership(deps, info),
             AndromedaMsg::UpdateOperators { operators } => {
                 self.execute_update_operators(deps, info, operators)
             }
             AndromedaMsg::UpdateAppContract { address } => {
                 self.execute_update_app_contract(deps, info, address, None)
             }
+            AndromedaMsg::GrantRole { role, address } => {
+                self.execute_grant_role(deps, info, role, address)
+            }
+            AndromedaMsg::RevokeRole { role, address } => {
+                self.execute_revoke_role(deps, info, role, address)
+            }
+            AndromedaMsg::Pause { messages } => self.execute_pause(deps, info, messages),
+            AndromedaMsg::Unpause { messages } => self.execute_unpause(deps, info, messages),
             #[cfg(feature = "withdraw")]
             AndromedaMsg::Withdraw {
                 recipient,
@@ -86,6 +123,10 @@ impl<'a> ADOContract<'a> {
                 self.validate_module_address(deps.storage, deps.api, &deps.querier, &module)?;
                 self.execute_alter_module(deps, info, module_idx, module)
             }
+            #[cfg(feature = "modules")]
+            AndromedaMsg::ReorderModules { module_idxs } => {
+                self.execute_reorder_modules(deps, info, module_idxs)
+            }
             #[cfg(feature = "primitive")]
             AndromedaMsg::RefreshAddress { contract } => {
                 self.execute_refresh_address(deps, contract)
@@ -119,26 +160,110 @@ impl<'a> ADOContract<'a> {
 }
 
 impl<'a> ADOContract<'a> {
-    /// Updates the current contract owner. **Only executable by the current contract owner.**
-    pub fn execute_update_owner(
+    /// Proposes `new_owner` as the next contract owner. Ownership is only transferred once the
+    /// proposed owner accepts. **Only executable by the current contract owner.**
+    pub fn execute_propose_ownership(
         &self,
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
         new_owner: String,
+        expiration: Option<u64>,
     ) -> Result<Response, ContractError> {
         ensure!(
             self.is_contract_owner(deps.storage, info.sender.as_str())?,
             ContractError::Unauthorized {}
         );
         let new_owner_addr = deps.api.addr_validate(&new_owner)?;
-        self.owner.save(deps.storage, &new_owner_addr)?;
+        self.pending_owner.save(deps.storage, &new_owner_addr)?;
+        match expiration {
+            Some(expiration) => {
+                let expiration = expiration_from_milliseconds(expiration)?;
+                ensure!(
+                    !expiration.is_expired(&env.block),
+                    ContractError::ExpirationInPast {}
+                );
+                self.pending_owner_expiration
+                    .save(deps.storage, &expiration)?;
+            }
+            None => self.pending_owner_expiration.remove(deps.storage),
+        }
 
         Ok(Response::new().add_attributes(vec![
-            attr("action", "update_owner"),
+            attr("action", "propose_ownership"),
             attr("value", new_owner),
         ]))
     }
 
+    /// Transfers ownership to the sender. **Only executable by the pending owner before the
+    /// proposal expires.**
+    pub fn execute_accept_ownership(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        let pending_owner = self
+            .pending_owner
+            .may_load(deps.storage)?
+            .ok_or(ContractError::NoOwnershipProposal {})?;
+        ensure!(info.sender == pending_owner, ContractError::Unauthorized {});
+        if let Some(expiration) = self.pending_owner_expiration.may_load(deps.storage)? {
+            ensure!(
+                !expiration.is_expired(&env.block),
+                ContractError::OwnershipProposalExpired {}
+            );
+        }
+        self.owner.save(deps.storage, &pending_owner)?;
+        self.clear_ownership_proposal(deps.storage);
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "accept_ownership"),
+            attr("value", pending_owner),
+        ]))
+    }
+
+    /// Removes the pending ownership proposal. **Only executable by the current contract owner.**
+    pub fn execute_cancel_ownership_proposal(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        ensure!(
+            self.pending_owner.may_load(deps.storage)?.is_some(),
+            ContractError::NoOwnershipProposal {}
+        );
+        self.clear_ownership_proposal(deps.storage);
+
+        Ok(Response::new().add_attribute("action", "cancel_ownership_proposal"))
+    }
+
+    /// Removes the contract owner along with any pending proposal, leaving the contract without
+    /// an owner. **Only executable by the current contract owner.**
+    pub fn execute_renounce_ownership(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        self.owner.remove(deps.storage);
+        self.clear_ownership_proposal(deps.storage);
+
+        Ok(Response::new().add_attribute("action", "renounce_ownership"))
+    }
+
+    fn clear_ownership_proposal(&self, storage: &mut dyn Storage) {
+        self.pending_owner.remove(storage);
+        self.pending_owner_expiration.remove(storage);
+    }
+
     pub fn execute_update_operators(
         &self,
         deps: DepsMut,
@@ -165,6 +290,58 @@ impl<'a> ADOContract<'a> {
         Ok(Response::new().add_attributes(vec![attr("action", "update_operators")]))
     }
 
+    /// Grants `role` to `address`. **Only executable by the current contract owner.**
+    pub fn execute_grant_role(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        role: String,
+        address: String,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        ensure!(!role.is_empty(), ContractError::InvalidRole { role });
+        let addr = deps.api.addr_validate(&address)?;
+        self.roles
+            .save(deps.storage, (&role, addr.as_str()), &true)?;
+        self.address_roles
+            .save(deps.storage, (addr.as_str(), &role), &true)?;
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "grant_role"),
+            attr("role", role),
+            attr("address", address),
+        ]))
+    }
+
+    /// Revokes `role` from `address`. **Only executable by the current contract owner.**
+    pub fn execute_revoke_role(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        role: String,
+        address: String,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        ensure!(
+            self.has_role(deps.storage, &role, &address),
+            ContractError::InvalidRole { role }
+        );
+        self.roles.remove(deps.storage, (&role, &address));
+        self.address_roles.remove(deps.storage, (&address, &role));
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "revoke_role"),
+            attr("role", role),
+            attr("address", address),
+        ]))
+    }
+
     pub fn execute_update_app_contract(
         &self,
         deps: DepsMut,
@@ -197,10 +374,13 @@ impl<'a> ADOContract<'a> {
 mod tests {
     use super::*;
     use crate::mock_querier::{mock_dependencies_custom, MOCK_APP_CONTRACT};
-    use common::ado_base::modules::Module;
+    use common::ado_base::{
+        modules::Module,
+        roles::{MINTER, PAUSER},
+    };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Uint64,
+        to_binary, Addr, Uint64,
     };
 
     fn dummy_function(
@@ -246,6 +426,7 @@ mod tests {
                 identifier: "z".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         let msg = AndromedaMsg::RegisterModule { module };
@@ -283,6 +464,8 @@ mod tests {
                         },
 
                         is_mutable: true,
+
+                        priority: None,
                     }]),
                     operators: None,
                     primitive_contract: None,
@@ -301,6 +484,7 @@ mod tests {
                 identifier: "z".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         let msg = AndromedaMsg::AlterModule {
@@ -378,6 +562,7 @@ mod tests {
                     modules: Some(vec![Module {
                         module_type: "address_list".to_string(),
                         is_mutable: true,
+                        priority: None,
                         address: AndrAddress {
                             identifier: "z".to_string(),
                         },
@@ -401,4 +586,345 @@ mod tests {
             res.unwrap_err()
         );
     }
+
+    #[test]
+    fn test_grant_and_revoke_role() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+
+        let info = mock_info("owner", &[]);
+        let deps_mut = deps.as_mut();
+        contract
+            .instantiate(
+                deps_mut.storage,
+                mock_env(),
+                deps_mut.api,
+                info.clone(),
+                InstantiateMsg {
+                    ado_type: "type".to_string(),
+                    ado_version: "version".to_string(),
+                    modules: None,
+                    operators: None,
+                    primitive_contract: None,
+                },
+            )
+            .unwrap();
+
+        let msg = AndromedaMsg::GrantRole {
+            role: MINTER.to_string(),
+            address: "minter".to_string(),
+        };
+        let res = contract
+            .execute(deps.as_mut(), mock_env(), info.clone(), msg, dummy_function)
+            .unwrap();
+        assert_eq!(
+            Response::new().add_attributes(vec![
+                attr("action", "grant_role"),
+                attr("role", MINTER),
+                attr("address", "minter"),
+            ]),
+            res
+        );
+        assert!(contract.has_role(deps.as_ref().storage, MINTER, "minter"));
+        assert_eq!(
+            vec![MINTER.to_string()],
+            contract
+                .query_roles(deps.as_ref(), Some("minter".to_string()), None, None)
+                .unwrap()
+                .roles
+        );
+        assert!(contract
+            .is_owner_or_role(deps.as_ref().storage, MINTER, "owner")
+            .unwrap());
+        assert!(!contract
+            .is_owner_or_role(deps.as_ref().storage, PAUSER, "minter")
+            .unwrap());
+
+        let msg = AndromedaMsg::RevokeRole {
+            role: MINTER.to_string(),
+            address: "minter".to_string(),
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), info, msg, dummy_function)
+            .unwrap();
+        assert!(!contract.has_role(deps.as_ref().storage, MINTER, "minter"));
+        assert!(contract
+            .query_roles(deps.as_ref(), Some("minter".to_string()), None, None)
+            .unwrap()
+            .roles
+            .is_empty());
+    }
+
+    #[test]
+    fn test_grant_role_unauthorized() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .roles
+            .save(deps.as_mut().storage, (PAUSER, "pauser"), &true)
+            .unwrap();
+
+        // Role holders cannot grant roles themselves.
+        let msg = AndromedaMsg::GrantRole {
+            role: PAUSER.to_string(),
+            address: "anyone".to_string(),
+        };
+        let res = contract.execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("pauser", &[]),
+            msg,
+            dummy_function,
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+    }
+
+    #[test]
+    fn test_revoke_role_not_granted() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let msg = AndromedaMsg::RevokeRole {
+            role: PAUSER.to_string(),
+            address: "pauser".to_string(),
+        };
+        let res = contract.execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            msg,
+            dummy_function,
+        );
+        assert_eq!(
+            ContractError::InvalidRole {
+                role: PAUSER.to_string()
+            },
+            res.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_ownership_transfer() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let msg = AndromedaMsg::ProposeOwnership {
+            address: "new_owner".to_string(),
+            expiration: None,
+        };
+        let res = contract.execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("new_owner", &[]),
+            msg.clone(),
+            dummy_function,
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("owner", &[]),
+                msg,
+                dummy_function,
+            )
+            .unwrap();
+        // Ownership does not change until the proposal is accepted.
+        assert!(contract
+            .is_contract_owner(deps.as_ref().storage, "owner")
+            .unwrap());
+
+        let res = contract.execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            AndromedaMsg::AcceptOwnership {},
+            dummy_function,
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        let res = contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("new_owner", &[]),
+                AndromedaMsg::AcceptOwnership {},
+                dummy_function,
+            )
+            .unwrap();
+        assert_eq!(
+            Response::new().add_attributes(vec![
+                attr("action", "accept_ownership"),
+                attr("value", "new_owner"),
+            ]),
+            res
+        );
+        assert!(contract
+            .is_contract_owner(deps.as_ref().storage, "new_owner")
+            .unwrap());
+        assert_eq!(
+            None,
+            contract
+                .query_pending_owner(deps.as_ref())
+                .unwrap()
+                .pending_owner
+        );
+    }
+
+    #[test]
+    fn test_accept_ownership_expired() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let mut env = mock_env();
+        let expiration = env.block.time.plus_seconds(100).nanos() / 1_000_000;
+        let msg = AndromedaMsg::ProposeOwnership {
+            address: "new_owner".to_string(),
+            expiration: Some(expiration),
+        };
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                msg,
+                dummy_function,
+            )
+            .unwrap();
+
+        env.block.time = env.block.time.plus_seconds(101);
+        let res = contract.execute(
+            deps.as_mut(),
+            env,
+            mock_info("new_owner", &[]),
+            AndromedaMsg::AcceptOwnership {},
+            dummy_function,
+        );
+        assert_eq!(ContractError::OwnershipProposalExpired {}, res.unwrap_err());
+    }
+
+    #[test]
+    fn test_cancel_ownership_proposal() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .pending_owner
+            .save(deps.as_mut().storage, &Addr::unchecked("new_owner"))
+            .unwrap();
+
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("owner", &[]),
+                AndromedaMsg::CancelOwnershipProposal {},
+                dummy_function,
+            )
+            .unwrap();
+
+        let res = contract.execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("new_owner", &[]),
+            AndromedaMsg::AcceptOwnership {},
+            dummy_function,
+        );
+        assert_eq!(ContractError::NoOwnershipProposal {}, res.unwrap_err());
+    }
+
+    #[test]
+    fn test_renounce_ownership() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("owner", &[]),
+                AndromedaMsg::RenounceOwnership {},
+                dummy_function,
+            )
+            .unwrap();
+
+        assert!(!contract
+            .is_contract_owner(deps.as_ref().storage, "owner")
+            .unwrap());
+        assert_eq!(
+            "",
+            contract.query_contract_owner(deps.as_ref()).unwrap().owner
+        );
+    }
+
+    #[test]
+    fn test_proxy_receive() {
+        fn sender_function(
+            _deps: DepsMut,
+            _env: Env,
+            info: MessageInfo,
+            _msg: AndromedaMsg,
+        ) -> Result<Response, ContractError> {
+            Ok(Response::new().add_attribute("sender", info.sender))
+        }
+
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .app_contract
+            .save(deps.as_mut().storage, &Addr::unchecked("app_contract"))
+            .unwrap();
+
+        let msg = AndromedaMsg::ProxyReceive {
+            origin: "member".to_string(),
+            msg: to_binary(&AndromedaMsg::AcceptOwnership {}).unwrap(),
+        };
+        let res = contract.execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member", &[]),
+            msg.clone(),
+            sender_function,
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        let res = contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("app_contract", &[]),
+                msg,
+                sender_function,
+            )
+            .unwrap();
+        assert_eq!(Response::new().add_attribute("sender", "member"), res);
+    }
 }
@@ -1,6 +1,11 @@
 use crate::ADOContract;
-use common::{ado_base::query_get, encode_binary, error::ContractError};
+use common::{
+    ado_base::{ado_type::split_versioned_ado_type, query_get},
+    encode_binary,
+    error::ContractError,
+};
 use cosmwasm_std::{Binary, CosmosMsg, QuerierWrapper, ReplyOn, Storage, SubMsg, WasmMsg};
+use semver::Version;
 
 impl<'a> ADOContract<'a> {
     pub fn generate_instantiate_msg(
@@ -33,15 +38,27 @@ impl<'a> ADOContract<'a> {
         }
     }
 
-    fn get_code_id(
+    /// Queries the factory for the code ID stored under `name`. A name of the form
+    /// `ado_type@version` resolves to that version of the ADO type rather than the latest.
+    pub fn get_code_id(
         &self,
-        storage: &mut dyn Storage,
+        storage: &dyn Storage,
         querier: &QuerierWrapper,
         name: &str,
     ) -> Result<u64, ContractError> {
+        let key = match split_versioned_ado_type(name) {
+            (ado_type, Some(version)) => {
+                let version: Version =
+                    version.parse().map_err(|_| ContractError::InvalidVersion {
+                        version: version.to_string(),
+                    })?;
+                format!("{}@{}", ado_type, version)
+            }
+            (_, None) => name.to_string(),
+        };
         // Do we want to cache the factory address?
         let factory_address = self.get_address_from_primitive(storage, querier, "factory")?;
-        let code_id: u64 = query_get(Some(encode_binary(&name)?), factory_address, querier)?;
+        let code_id: u64 = query_get(Some(encode_binary(&key)?), factory_address, querier)?;
         Ok(code_id)
     }
 }
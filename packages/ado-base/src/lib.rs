@@ -3,10 +3,12 @@ mod auth;
 mod execute;
 #[cfg(feature = "instantiate")]
 mod instantiate;
+pub mod migrate;
 #[cfg(test)]
 mod mock_querier;
 #[cfg(feature = "modules")]
 pub mod modules;
+mod pause;
 #[cfg(feature = "primitive")]
 pub mod primitive;
 mod query;
@@ -0,0 +1,286 @@
+use crate::ADOContract;
+use common::{
+    ado_base::migration::{MigrationHistoryResponse, MigrationRecord},
+    error::ContractError,
+};
+use cosmwasm_std::{ensure, Deps, DepsMut, Env, Order, Response, StdError};
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+
+type MigrateFunction = fn(DepsMut, Env) -> Result<(), ContractError>;
+
+/// A state migration that must be run when upgrading a contract from a version below `version`.
+pub struct MigrationStep {
+    pub version: &'static str,
+    pub migrate: MigrateFunction,
+}
+
+impl<'a> ADOContract<'a> {
+    /// Migrates the contract to `contract_version`, running every step in `steps` whose version
+    /// is greater than the stored version and no greater than `contract_version`. `steps` must be
+    /// in ascending version order. Downgrades and migrations from a different contract are
+    /// rejected.
+    pub fn migrate(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        contract_name: &str,
+        contract_version: &str,
+        steps: &[MigrationStep],
+    ) -> Result<Response, ContractError> {
+        // New version
+        let version: Version = contract_version.parse().map_err(from_semver)?;
+
+        // Old version
+        let stored = get_contract_version(deps.storage)?;
+        let storage_version: Version = stored.version.parse().map_err(from_semver)?;
+
+        ensure!(
+            stored.contract == contract_name,
+            ContractError::CannotMigrate {
+                previous_contract: stored.contract,
+            }
+        );
+
+        // New version has to be newer/greater than the old version
+        ensure!(
+            storage_version < version,
+            ContractError::CannotMigrate {
+                previous_contract: stored.version,
+            }
+        );
+
+        // Validate every step before running any of them.
+        let step_versions = steps
+            .iter()
+            .map(|step| step.version.parse().map_err(from_semver))
+            .collect::<Result<Vec<Version>, StdError>>()?;
+        for (step, pair) in steps.iter().skip(1).zip(step_versions.windows(2)) {
+            ensure!(
+                pair[0] < pair[1],
+                ContractError::InvalidMigration {
+                    msg: format!("Step {} is out of order", step.version),
+                }
+            );
+        }
+        for (step, step_version) in steps.iter().zip(step_versions.iter()) {
+            ensure!(
+                step_version <= &version,
+                ContractError::InvalidMigration {
+                    msg: format!("Step {} is newer than {}", step.version, contract_version),
+                }
+            );
+        }
+
+        let mut steps_run: Vec<String> = vec![];
+        for (step, step_version) in steps.iter().zip(step_versions.iter()) {
+            if step_version > &storage_version {
+                (step.migrate)(deps.branch(), env.clone())?;
+                steps_run.push(step.version.to_string());
+            }
+        }
+
+        set_contract_version(deps.storage, contract_name, contract_version)?;
+
+        // Update the ADOContract's version
+        self.execute_update_version(deps.branch())?;
+
+        let idx = self
+            .migration_history
+            .keys(deps.storage, None, None, Order::Descending)
+            .next()
+            .transpose()?
+            .map_or(0, |idx| idx + 1);
+        self.migration_history.save(
+            deps.storage,
+            idx,
+            &MigrationRecord {
+                from_version: stored.version.clone(),
+                to_version: contract_version.to_string(),
+                steps: steps_run.clone(),
+                block_height: env.block.height,
+            },
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("action", "migrate")
+            .add_attribute("from_version", stored.version)
+            .add_attribute("to_version", contract_version)
+            .add_attribute("steps", steps_run.join(",")))
+    }
+
+    pub fn query_migration_history(
+        &self,
+        deps: Deps,
+    ) -> Result<MigrationHistoryResponse, ContractError> {
+        let migrations: Result<Vec<MigrationRecord>, _> = self
+            .migration_history
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, record)| record))
+            .collect();
+        Ok(MigrationHistoryResponse {
+            migrations: migrations?,
+        })
+    }
+}
+
+fn from_semver(err: semver::Error) -> StdError {
+    StdError::generic_err(format!("Semver: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cw_storage_plus::Item;
+
+    const CONTRACT_NAME: &str = "crates.io:andromeda-test";
+    const VALUE: Item<u64> = Item::new("value");
+
+    fn double_value(deps: DepsMut, _env: Env) -> Result<(), ContractError> {
+        let value = VALUE.load(deps.storage)?;
+        VALUE.save(deps.storage, &(value * 2))?;
+        Ok(())
+    }
+
+    fn increment_value(deps: DepsMut, _env: Env) -> Result<(), ContractError> {
+        VALUE.update(deps.storage, |value| -> Result<u64, ContractError> {
+            Ok(value + 1)
+        })?;
+        Ok(())
+    }
+
+    const STEPS: [MigrationStep; 2] = [
+        MigrationStep {
+            version: "0.2.0",
+            migrate: double_value,
+        },
+        MigrationStep {
+            version: "0.3.0",
+            migrate: increment_value,
+        },
+    ];
+
+    #[test]
+    fn test_migrate_runs_pending_steps() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.2.0").unwrap();
+        VALUE.save(deps.as_mut().storage, &10).unwrap();
+
+        let res = contract
+            .migrate(deps.as_mut(), mock_env(), CONTRACT_NAME, "0.3.1", &STEPS)
+            .unwrap();
+        assert_eq!(
+            Response::new()
+                .add_attribute("action", "migrate")
+                .add_attribute("from_version", "0.2.0")
+                .add_attribute("to_version", "0.3.1")
+                .add_attribute("steps", "0.3.0"),
+            res
+        );
+        // Only the 0.3.0 step should have been run.
+        assert_eq!(11, VALUE.load(deps.as_ref().storage).unwrap());
+        assert_eq!(
+            "0.3.1",
+            get_contract_version(deps.as_ref().storage).unwrap().version
+        );
+        assert_eq!(
+            MigrationHistoryResponse {
+                migrations: vec![MigrationRecord {
+                    from_version: "0.2.0".to_string(),
+                    to_version: "0.3.1".to_string(),
+                    steps: vec!["0.3.0".to_string()],
+                    block_height: mock_env().block.height,
+                }]
+            },
+            contract.query_migration_history(deps.as_ref()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_migrate_all_steps() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.0").unwrap();
+        VALUE.save(deps.as_mut().storage, &10).unwrap();
+
+        contract
+            .migrate(deps.as_mut(), mock_env(), CONTRACT_NAME, "0.3.0", &STEPS)
+            .unwrap();
+        assert_eq!(21, VALUE.load(deps.as_ref().storage).unwrap());
+
+        contract
+            .migrate(deps.as_mut(), mock_env(), CONTRACT_NAME, "0.4.0", &STEPS)
+            .unwrap();
+        assert_eq!(21, VALUE.load(deps.as_ref().storage).unwrap());
+        assert_eq!(
+            2,
+            contract
+                .query_migration_history(deps.as_ref())
+                .unwrap()
+                .migrations
+                .len()
+        );
+    }
+
+    #[test]
+    fn test_migrate_downgrade() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.2.0").unwrap();
+
+        let res =
+            ADOContract::default().migrate(deps.as_mut(), mock_env(), CONTRACT_NAME, "0.1.0", &[]);
+        assert_eq!(
+            ContractError::CannotMigrate {
+                previous_contract: "0.2.0".to_string()
+            },
+            res.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_migrate_different_contract() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, "crates.io:other", "0.1.0").unwrap();
+
+        let res =
+            ADOContract::default().migrate(deps.as_mut(), mock_env(), CONTRACT_NAME, "0.2.0", &[]);
+        assert_eq!(
+            ContractError::CannotMigrate {
+                previous_contract: "crates.io:other".to_string()
+            },
+            res.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_migrate_steps_out_of_order() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.0").unwrap();
+        let steps = [
+            MigrationStep {
+                version: "0.3.0",
+                migrate: increment_value,
+            },
+            MigrationStep {
+                version: "0.2.0",
+                migrate: double_value,
+            },
+        ];
+
+        let res = ADOContract::default().migrate(
+            deps.as_mut(),
+            mock_env(),
+            CONTRACT_NAME,
+            "0.3.0",
+            &steps,
+        );
+        assert_eq!(
+            ContractError::InvalidMigration {
+                msg: "Step 0.2.0 is out of order".to_string()
+            },
+            res.unwrap_err()
+        );
+    }
+}
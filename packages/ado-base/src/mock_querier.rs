@@ -1,12 +1,16 @@
 use common::{
-    ado_base::{AndromedaQuery, QueryMsg},
+    ado_base::{
+        hooks::{AndromedaHook, HookMsg, HookResponse, OnFundsTransferResponse},
+        AndromedaQuery, QueryMsg,
+    },
     primitive::{GetValueResponse, Primitive},
+    Funds,
 };
 use cosmwasm_std::{
-    from_binary, from_slice,
+    coin, from_binary, from_slice,
     testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR},
-    to_binary, Binary, Coin, ContractResult, OwnedDeps, Querier, QuerierResult, QueryRequest,
-    SystemError, SystemResult, WasmQuery,
+    to_binary, BankMsg, Binary, Coin, ContractResult, CosmosMsg, Event, OwnedDeps, Querier,
+    QuerierResult, QueryRequest, SubMsg, SystemError, SystemResult, WasmQuery,
 };
 use cw20::{BalanceResponse, Cw20QueryMsg};
 use schemars::JsonSchema;
@@ -15,6 +19,9 @@ use serde::{Deserialize, Serialize};
 pub const MOCK_CW20_CONTRACT: &str = "cw20_contract";
 pub const MOCK_PRIMITIVE_CONTRACT: &str = "primitive_contract";
 pub const MOCK_APP_CONTRACT: &str = "app_contract";
+pub const MOCK_MODULE_CONTRACT: &str = "module_contract";
+pub const MOCK_FLAT_MODULE_CONTRACT: &str = "flat_module_contract";
+pub const MOCK_TAX_MODULE_CONTRACT: &str = "tax_module_contract";
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -65,6 +72,9 @@ impl WasmMockQuerier {
                     MOCK_CW20_CONTRACT => self.handle_cw20_query(msg),
                     MOCK_PRIMITIVE_CONTRACT => self.handle_primitive_query(msg),
                     MOCK_APP_CONTRACT => self.handle_app_query(msg),
+                    MOCK_MODULE_CONTRACT => self.handle_module_query(msg),
+                    MOCK_FLAT_MODULE_CONTRACT => self.handle_flat_module_query(msg),
+                    MOCK_TAX_MODULE_CONTRACT => self.handle_tax_module_query(msg),
                     _ => panic!("Unsupported query for contract: {}", contract_addr),
                 }
             }
@@ -118,6 +128,78 @@ impl WasmMockQuerier {
         }
     }
 
+    fn handle_module_query(&self, msg: &Binary) -> QuerierResult {
+        match from_binary(msg).unwrap() {
+            HookMsg::AndrHook(AndromedaHook::OnExecute { sender, .. }) => {
+                let response = HookResponse {
+                    msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                        to_address: sender.clone(),
+                        amount: vec![],
+                    }))],
+                    events: vec![Event::new("module_hook").add_attribute("sender", sender)],
+                };
+                SystemResult::Ok(ContractResult::Ok(to_binary(&Some(response)).unwrap()))
+            }
+            // Takes 10% of the funds.
+            HookMsg::AndrHook(AndromedaHook::OnFundsTransfer { amount, .. }) => {
+                let amount = amount.try_get_coin().unwrap();
+                let fee = amount.amount.multiply_ratio(1u128, 10u128).u128();
+                Self::funds_transfer_response("percent", amount, fee, true)
+            }
+            _ => SystemResult::Ok(ContractResult::Ok(
+                to_binary(&None::<HookResponse>).unwrap(),
+            )),
+        }
+    }
+
+    fn handle_flat_module_query(&self, msg: &Binary) -> QuerierResult {
+        match from_binary(msg).unwrap() {
+            // Takes 10 of the funds.
+            HookMsg::AndrHook(AndromedaHook::OnFundsTransfer { amount, .. }) => {
+                Self::funds_transfer_response("flat", amount.try_get_coin().unwrap(), 10, true)
+            }
+            _ => SystemResult::Ok(ContractResult::Ok(
+                to_binary(&None::<HookResponse>).unwrap(),
+            )),
+        }
+    }
+
+    fn handle_tax_module_query(&self, msg: &Binary) -> QuerierResult {
+        match from_binary(msg).unwrap() {
+            // Charges 10% of the funds on top of them.
+            HookMsg::AndrHook(AndromedaHook::OnFundsTransfer { amount, .. }) => {
+                let amount = amount.try_get_coin().unwrap();
+                let fee = amount.amount.multiply_ratio(1u128, 10u128).u128();
+                Self::funds_transfer_response("tax", amount, fee, false)
+            }
+            _ => SystemResult::Ok(ContractResult::Ok(
+                to_binary(&None::<HookResponse>).unwrap(),
+            )),
+        }
+    }
+
+    fn funds_transfer_response(
+        name: &str,
+        amount: Coin,
+        fee: u128,
+        is_deducted: bool,
+    ) -> QuerierResult {
+        let leftover = if is_deducted {
+            amount.amount.u128() - fee
+        } else {
+            amount.amount.u128()
+        };
+        let response = OnFundsTransferResponse {
+            msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: name.to_string(),
+                amount: vec![coin(fee, &amount.denom)],
+            }))],
+            events: vec![Event::new(name)],
+            leftover_funds: Funds::Native(coin(leftover, amount.denom)),
+        };
+        SystemResult::Ok(ContractResult::Ok(to_binary(&Some(response)).unwrap()))
+    }
+
     pub fn new(base: MockQuerier) -> Self {
         WasmMockQuerier { base }
     }
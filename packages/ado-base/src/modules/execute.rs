@@ -49,6 +49,25 @@ impl<'a> ADOContract<'a> {
             .add_attribute("module_idx", module_idx))
     }
 
+    /// A wrapper for `fn reorder_modules`. Immutable modules may be reordered as this does not
+    /// change which modules are registered.
+    pub(crate) fn execute_reorder_modules(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        module_idxs: Vec<Uint64>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_owner_or_operator(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        self.reorder_modules(deps.storage, &module_idxs)?;
+        let order: Vec<String> = module_idxs.iter().map(|idx| idx.to_string()).collect();
+        Ok(Response::default()
+            .add_attribute("action", "reorder_modules")
+            .add_attribute("order", order.join(",")))
+    }
+
     /// A wrapper for `fn deregister_module`.
     pub(crate) fn execute_deregister_module(
         &self,
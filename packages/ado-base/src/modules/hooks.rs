@@ -1,10 +1,14 @@
-use cosmwasm_std::{to_binary, Api, Binary, Event, QuerierWrapper, StdError, Storage, SubMsg};
+use cosmwasm_std::{
+    to_binary, Api, Binary, Event, QuerierWrapper, StdError, Storage, SubMsg, Uint64,
+};
 use serde::de::DeserializeOwned;
 
 use crate::modules::ADOContract;
 use common::{
     ado_base::{
-        hooks::{AndromedaHook, HookMsg, OnFundsTransferResponse},
+        hooks::{
+            AndromedaHook, HookMsg, HookResponse, ModuleFundsTransfer, OnFundsTransferResponse,
+        },
         modules::{Module, RECEIPT},
     },
     error::ContractError,
@@ -12,7 +16,8 @@ use common::{
 };
 
 impl<'a> ADOContract<'a> {
-    /// Sends the provided hook message to all registered modules
+    /// Sends the provided hook message to all registered modules. `OnExecute` hooks are rejected
+    /// if the executed message is paused.
     pub fn module_hook<T: DeserializeOwned>(
         &self,
         storage: &dyn Storage,
@@ -20,6 +25,9 @@ impl<'a> ADOContract<'a> {
         querier: QuerierWrapper,
         hook_msg: AndromedaHook,
     ) -> Result<Vec<T>, ContractError> {
+        if let AndromedaHook::OnExecute { payload, .. } = &hook_msg {
+            self.ensure_not_paused(storage, payload)?;
+        }
         let addresses: Vec<String> = self.load_module_addresses(storage, api, &querier)?;
         let mut resp: Vec<T> = Vec::new();
         for addr in addresses {
@@ -32,7 +40,31 @@ impl<'a> ADOContract<'a> {
         Ok(resp)
     }
 
-    /// Sends the provided hook message to all registered modules
+    /// Sends the provided `OnExecute` or `OnTransfer` hook message to all registered modules and
+    /// merges the sub-messages and events they return. The calling ADO is expected to append them
+    /// to its own response.
+    pub fn module_hook_response(
+        &self,
+        storage: &dyn Storage,
+        api: &dyn Api,
+        querier: QuerierWrapper,
+        hook_msg: AndromedaHook,
+    ) -> Result<HookResponse, ContractError> {
+        let responses = self.module_hook::<HookResponse>(storage, api, querier, hook_msg)?;
+        Ok(responses
+            .into_iter()
+            .fold(HookResponse::default(), |mut resp, mod_resp| {
+                resp.msgs.extend(mod_resp.msgs);
+                resp.events.extend(mod_resp.events);
+                resp
+            }))
+    }
+
+    /// Sends an `OnFundsTransfer` hook to all registered modules in order. Each module is given
+    /// the funds left over by the previous one. Among modules of equal priority, those that
+    /// deduct funds are always run before those that do not, so additive rates are calculated on
+    /// the amount that remains after every deduction. A receipt module is always run last and is
+    /// given the events of every other module as its payload.
     pub fn on_funds_transfer(
         &self,
         storage: &dyn Storage,
@@ -42,51 +74,120 @@ impl<'a> ADOContract<'a> {
         amount: Funds,
         msg: Binary,
     ) -> Result<(Vec<SubMsg>, Vec<Event>, Funds), ContractError> {
-        let modules: Vec<Module> = self.load_modules(storage)?;
-        let mut remainder = amount;
+        let breakdown =
+            self.funds_transfer_breakdown(storage, api, querier, sender, amount.clone(), msg)?;
+        let remainder = breakdown
+            .last()
+            .map(|module| module.leftover_funds.clone())
+            .unwrap_or(amount);
         let mut msgs: Vec<SubMsg> = Vec::new();
         let mut events: Vec<Event> = Vec::new();
-        let mut receipt_module_address: Option<String> = None;
-        for module in modules {
-            let app_contract = self.get_app_contract(storage)?;
-            let module_address = module.address.get_address(api, querier, app_contract)?;
-            if module.module_type == RECEIPT {
-                // If receipt module exists we want to make sure we do it last.
-                receipt_module_address = Some(module_address);
-                continue;
-            }
-            let mod_resp: Option<OnFundsTransferResponse> = hook_query(
+        for module in breakdown {
+            msgs.extend(module.msgs);
+            events.extend(module.events);
+        }
+
+        Ok((msgs, events, remainder))
+    }
+
+    /// Runs the `OnFundsTransfer` hook of every module and returns the response of each module
+    /// that supports it, in the order in which they were run.
+    pub(crate) fn funds_transfer_breakdown(
+        &self,
+        storage: &dyn Storage,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        sender: String,
+        amount: Funds,
+        msg: Binary,
+    ) -> Result<Vec<ModuleFundsTransfer>, ContractError> {
+        let app_contract = self.get_app_contract(storage)?;
+        // If a receipt module exists we want to make sure we do it last.
+        let (receipt_modules, modules): (Vec<_>, Vec<_>) = self
+            .load_modules_with_idx(storage)?
+            .into_iter()
+            .partition(|(_idx, module)| module.module_type == RECEIPT);
+        let query = |address: &str, payload: Binary, amount: Funds| {
+            hook_query::<OnFundsTransferResponse>(
                 querier,
                 AndromedaHook::OnFundsTransfer {
-                    payload: msg.clone(),
+                    payload,
                     sender: sender.clone(),
-                    amount: remainder.clone(),
+                    amount,
                 },
-                module_address,
-            )?;
-            if let Some(mod_resp) = mod_resp {
-                remainder = mod_resp.leftover_funds;
-                msgs = [msgs, mod_resp.msgs].concat();
-                events = [events, mod_resp.events].concat();
+                address.to_owned(),
+            )
+        };
+        let mut remainder = amount;
+        let mut breakdown: Vec<ModuleFundsTransfer> = Vec::new();
+        let mut modules = modules.into_iter().peekable();
+        while let Some(first) = modules.next() {
+            // Modules of equal priority are run together, deducting modules first.
+            let priority = first.1.priority;
+            let mut group = vec![first];
+            while let Some(next) = modules.next_if(|(_idx, module)| module.priority == priority) {
+                group.push(next);
+            }
+            let mut non_deducting = vec![];
+            for (idx, module) in group {
+                let address = module
+                    .address
+                    .get_address(api, querier, app_contract.clone())?;
+                let input = remainder.clone();
+                if let Some(mod_resp) = query(&address, msg.clone(), input.clone())? {
+                    if mod_resp.leftover_funds == input {
+                        non_deducting.push((idx, module, address, input, mod_resp));
+                    } else {
+                        remainder = mod_resp.leftover_funds.clone();
+                        breakdown.push(module_funds_transfer(idx, module, address, mod_resp));
+                    }
+                }
+            }
+            for (idx, module, address, input, mod_resp) in non_deducting {
+                // The response is only outdated if a module after it deducted funds.
+                let mod_resp = if input == remainder {
+                    Some(mod_resp)
+                } else {
+                    query(&address, msg.clone(), remainder.clone())?
+                };
+                if let Some(mod_resp) = mod_resp {
+                    remainder = mod_resp.leftover_funds.clone();
+                    breakdown.push(module_funds_transfer(idx, module, address, mod_resp));
+                }
             }
         }
-        if let Some(receipt_module_address) = receipt_module_address {
-            let mod_resp: Option<OnFundsTransferResponse> = hook_query(
-                querier,
-                AndromedaHook::OnFundsTransfer {
-                    payload: to_binary(&events)?,
-                    sender,
-                    amount: remainder.clone(),
-                },
-                receipt_module_address,
-            )?;
-            if let Some(mod_resp) = mod_resp {
-                msgs = [msgs, mod_resp.msgs].concat();
-                events = [events, mod_resp.events].concat();
+        for (idx, module) in receipt_modules {
+            let address = module
+                .address
+                .get_address(api, querier, app_contract.clone())?;
+            let events: Vec<Event> = breakdown
+                .iter()
+                .flat_map(|module| module.events.clone())
+                .collect();
+            if let Some(mut mod_resp) = query(&address, to_binary(&events)?, remainder.clone())? {
+                // Receipts do not alter the funds.
+                mod_resp.leftover_funds = remainder.clone();
+                breakdown.push(module_funds_transfer(idx, module, address, mod_resp));
             }
         }
 
-        Ok((msgs, events, remainder))
+        Ok(breakdown)
+    }
+}
+
+fn module_funds_transfer(
+    idx: u64,
+    module: Module,
+    address: String,
+    mod_resp: OnFundsTransferResponse,
+) -> ModuleFundsTransfer {
+    ModuleFundsTransfer {
+        module_idx: Uint64::from(idx),
+        module_type: module.module_type,
+        address,
+        msgs: mod_resp.msgs,
+        events: mod_resp.events,
+        leftover_funds: mod_resp.leftover_funds,
     }
 }
 
@@ -123,8 +224,16 @@ fn hook_query<T: DeserializeOwned>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use common::error::ContractError;
-    use cosmwasm_std::Response;
+    use crate::mock_querier::{
+        mock_dependencies_custom, MOCK_FLAT_MODULE_CONTRACT, MOCK_MODULE_CONTRACT,
+        MOCK_TAX_MODULE_CONTRACT,
+    };
+    use common::{
+        ado_base::modules::{Module, ADDRESS_LIST, RATES, RECEIPT},
+        app::AndrAddress,
+        error::ContractError,
+    };
+    use cosmwasm_std::{coin, Addr, BankMsg, CosmosMsg, Response};
 
     #[test]
     fn test_process_module_response() {
@@ -143,4 +252,202 @@ mod tests {
             res
         );
     }
+
+    #[test]
+    fn test_module_hook_response() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .ado_type
+            .save(deps.as_mut().storage, &"cw721".to_string())
+            .unwrap();
+        for module_type in [ADDRESS_LIST, RECEIPT] {
+            let module = Module {
+                module_type: module_type.to_owned(),
+                address: AndrAddress {
+                    identifier: MOCK_MODULE_CONTRACT.to_owned(),
+                },
+                is_mutable: false,
+                priority: None,
+            };
+            contract
+                .execute_register_module(deps.as_mut().storage, "owner", module, false)
+                .unwrap();
+        }
+
+        let msg = SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "sender".to_string(),
+            amount: vec![],
+        }));
+        let event = Event::new("module_hook").add_attribute("sender", "sender");
+
+        let res = contract
+            .module_hook_response(
+                &deps.storage,
+                &deps.api,
+                deps.as_ref().querier,
+                AndromedaHook::OnExecute {
+                    sender: "sender".to_string(),
+                    payload: to_binary(&true).unwrap(),
+                    merkle_proof: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            HookResponse {
+                msgs: vec![msg.clone(), msg],
+                events: vec![event.clone(), event],
+            },
+            res
+        );
+
+        // Modules that do not support the hook are ignored.
+        let res = contract
+            .module_hook_response(
+                &deps.storage,
+                &deps.api,
+                deps.as_ref().querier,
+                AndromedaHook::OnTransfer {
+                    token_id: "1".to_string(),
+                    sender: "sender".to_string(),
+                    recipient: "recipient".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(HookResponse::default(), res);
+    }
+
+    #[test]
+    fn test_on_funds_transfer_order() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        // The flat module is registered last but has the highest priority.
+        for (address, priority) in [
+            (MOCK_MODULE_CONTRACT, None),
+            (MOCK_FLAT_MODULE_CONTRACT, Some(0)),
+        ] {
+            let module = Module {
+                module_type: RATES.to_owned(),
+                address: AndrAddress {
+                    identifier: address.to_owned(),
+                },
+                is_mutable: false,
+                priority,
+            };
+            contract
+                .execute_register_module(deps.as_mut().storage, "owner", module, false)
+                .unwrap();
+        }
+
+        let breakdown = contract
+            .query_funds_transfer_breakdown(
+                deps.as_ref(),
+                "sender".to_string(),
+                Funds::Native(coin(100, "uusd")),
+            )
+            .unwrap()
+            .modules;
+        let flat_msg = SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "flat".to_string(),
+            amount: vec![coin(10, "uusd")],
+        }));
+        let percent_msg = SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "percent".to_string(),
+            amount: vec![coin(9, "uusd")],
+        }));
+        assert_eq!(
+            vec![
+                ModuleFundsTransfer {
+                    module_idx: Uint64::new(2),
+                    module_type: RATES.to_owned(),
+                    address: MOCK_FLAT_MODULE_CONTRACT.to_owned(),
+                    msgs: vec![flat_msg.clone()],
+                    events: vec![Event::new("flat")],
+                    leftover_funds: Funds::Native(coin(90, "uusd")),
+                },
+                ModuleFundsTransfer {
+                    module_idx: Uint64::new(1),
+                    module_type: RATES.to_owned(),
+                    address: MOCK_MODULE_CONTRACT.to_owned(),
+                    msgs: vec![percent_msg.clone()],
+                    events: vec![Event::new("percent")],
+                    leftover_funds: Funds::Native(coin(81, "uusd")),
+                },
+            ],
+            breakdown
+        );
+
+        let (msgs, events, remainder) = contract
+            .on_funds_transfer(
+                &deps.storage,
+                &deps.api,
+                &deps.as_ref().querier,
+                "sender".to_string(),
+                Funds::Native(coin(100, "uusd")),
+                Binary::default(),
+            )
+            .unwrap();
+        assert_eq!(vec![flat_msg, percent_msg], msgs);
+        assert_eq!(vec![Event::new("flat"), Event::new("percent")], events);
+        assert_eq!(Funds::Native(coin(81, "uusd")), remainder);
+    }
+
+    #[test]
+    fn test_on_funds_transfer_deducting_modules_first() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        // The tax module does not deduct funds so it is run after the percent module even
+        // though it was registered first.
+        for address in [MOCK_TAX_MODULE_CONTRACT, MOCK_MODULE_CONTRACT] {
+            let module = Module {
+                module_type: RATES.to_owned(),
+                address: AndrAddress {
+                    identifier: address.to_owned(),
+                },
+                is_mutable: false,
+                priority: None,
+            };
+            contract
+                .execute_register_module(deps.as_mut().storage, "owner", module, false)
+                .unwrap();
+        }
+
+        let (msgs, events, remainder) = contract
+            .on_funds_transfer(
+                &deps.storage,
+                &deps.api,
+                &deps.as_ref().querier,
+                "sender".to_string(),
+                Funds::Native(coin(100, "uusd")),
+                Binary::default(),
+            )
+            .unwrap();
+        assert_eq!(
+            vec![
+                SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "percent".to_string(),
+                    amount: vec![coin(10, "uusd")],
+                })),
+                SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "tax".to_string(),
+                    amount: vec![coin(9, "uusd")],
+                })),
+            ],
+            msgs
+        );
+        assert_eq!(vec![Event::new("percent"), Event::new("tax")], events);
+        assert_eq!(Funds::Native(coin(90, "uusd")), remainder);
+    }
 }
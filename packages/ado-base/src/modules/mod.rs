@@ -1,7 +1,7 @@
 use std::convert::TryInto;
 
 use crate::state::ADOContract;
-use cosmwasm_std::{Api, Order, QuerierWrapper, Response, Storage, Uint64};
+use cosmwasm_std::{ensure, Api, Order, QuerierWrapper, Response, Storage, Uint64};
 use cw_storage_plus::Bound;
 
 use common::{ado_base::modules::Module, error::ContractError};
@@ -91,21 +91,60 @@ impl<'a> ADOContract<'a> {
         Ok(())
     }
 
-    /// Loads all registered modules in Vector form
+    /// Loads all registered modules in Vector form, in the order in which they are run.
     pub(crate) fn load_modules(&self, storage: &dyn Storage) -> Result<Vec<Module>, ContractError> {
+        Ok(self
+            .load_modules_with_idx(storage)?
+            .into_iter()
+            .map(|(_idx, module)| module)
+            .collect())
+    }
+
+    /// Loads all registered modules along with their indices. Modules are sorted by ascending
+    /// priority, with modules without a priority last, and then by their index.
+    pub(crate) fn load_modules_with_idx(
+        &self,
+        storage: &dyn Storage,
+    ) -> Result<Vec<(u64, Module)>, ContractError> {
         let module_idx = self.module_idx.may_load(storage)?.unwrap_or(1);
         let min = Some(Bound::inclusive("1"));
-        let modules: Vec<Module> = self
+        let mut modules: Vec<(u64, Module)> = self
             .module_info
             .range(storage, min, None, Order::Ascending)
             .take(module_idx.try_into().unwrap())
             .flatten()
-            .map(|(_vec, module)| module)
+            .filter_map(|(idx, module)| idx.parse().ok().map(|idx| (idx, module)))
             .collect();
+        modules.sort_by_key(|(idx, module)| (module.priority.unwrap_or(u64::MAX), *idx));
 
         Ok(modules)
     }
 
+    /// Sets the priority of every module to its position in `module_idxs`.
+    fn reorder_modules(
+        &self,
+        storage: &mut dyn Storage,
+        module_idxs: &[Uint64],
+    ) -> Result<(), ContractError> {
+        let mut registered: Vec<u64> = self
+            .load_modules_with_idx(storage)?
+            .into_iter()
+            .map(|(idx, _module)| idx)
+            .collect();
+        registered.sort_unstable();
+        let mut ordered: Vec<u64> = module_idxs.iter().map(|idx| idx.u64()).collect();
+        ordered.sort_unstable();
+        ensure!(registered == ordered, ContractError::InvalidModuleOrder {});
+
+        for (priority, idx) in module_idxs.iter().enumerate() {
+            let idx_str = idx.to_string();
+            let mut module = self.module_info.load(storage, &idx_str)?;
+            module.priority = Some(priority as u64);
+            self.module_info.save(storage, &idx_str, &module)?;
+        }
+        Ok(())
+    }
+
     /// Loads all registered module addresses in Vector form
     fn load_module_addresses(
         &self,
@@ -156,6 +195,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
         let deps_mut = deps.as_mut();
         ADOContract::default()
@@ -187,6 +227,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
         let deps_mut = deps.as_mut();
         ADOContract::default()
@@ -229,6 +270,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
         let deps_mut = deps.as_mut();
         ADOContract::default()
@@ -270,6 +312,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
         ADOContract::default()
             .owner
@@ -297,6 +340,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
 
         ADOContract::default()
@@ -319,6 +363,7 @@ mod tests {
                 identifier: "other_address".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
 
         let res = ADOContract::default()
@@ -351,6 +396,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         ADOContract::default()
@@ -373,6 +419,7 @@ mod tests {
                 identifier: "other_address".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
 
         let res =
@@ -391,6 +438,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
 
         ADOContract::default()
@@ -418,6 +466,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
 
         ADOContract::default()
@@ -475,6 +524,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
 
         ADOContract::default()
@@ -513,6 +563,7 @@ mod tests {
                 identifier: "address".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         ADOContract::default()
@@ -560,6 +611,7 @@ mod tests {
                         identifier: "address".to_string(),
                     },
                     is_mutable: true,
+                    priority: None,
                 },
             )
             .unwrap();
@@ -575,6 +627,7 @@ mod tests {
                         identifier: "a".to_string(),
                     },
                     is_mutable: true,
+                    priority: None,
                 },
             )
             .unwrap();
@@ -588,4 +641,98 @@ mod tests {
             module_addresses
         );
     }
+
+    #[test]
+    fn test_load_modules_order() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        contract
+            .module_idx
+            .save(deps.as_mut().storage, &11)
+            .unwrap();
+        for (idx, priority) in [("1", None), ("2", Some(5)), ("3", Some(1)), ("10", None)] {
+            let module = Module {
+                module_type: "rates".to_string(),
+                address: AndrAddress {
+                    identifier: format!("address{}", idx),
+                },
+                is_mutable: true,
+                priority,
+            };
+            contract
+                .module_info
+                .save(deps.as_mut().storage, idx, &module)
+                .unwrap();
+        }
+
+        let order: Vec<u64> = contract
+            .load_modules_with_idx(deps.as_ref().storage)
+            .unwrap()
+            .into_iter()
+            .map(|(idx, _module)| idx)
+            .collect();
+        assert_eq!(vec![3, 2, 1, 10], order);
+    }
+
+    #[test]
+    fn test_execute_reorder_modules() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        for idx in 1..=3 {
+            let module = Module {
+                module_type: "rates".to_string(),
+                address: AndrAddress {
+                    identifier: format!("address{}", idx),
+                },
+                is_mutable: false,
+                priority: None,
+            };
+            contract
+                .execute_register_module(deps.as_mut().storage, "owner", module, false)
+                .unwrap();
+        }
+
+        let res = contract.execute_reorder_modules(
+            deps.as_mut(),
+            mock_info("sender", &[]),
+            vec![Uint64::new(3), Uint64::new(1), Uint64::new(2)],
+        );
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        for module_idxs in [vec![3, 1], vec![3, 1, 1], vec![3, 1, 2, 4]] {
+            let res = contract.execute_reorder_modules(
+                deps.as_mut(),
+                mock_info("owner", &[]),
+                module_idxs.into_iter().map(Uint64::new).collect(),
+            );
+            assert_eq!(ContractError::InvalidModuleOrder {}, res.unwrap_err());
+        }
+
+        let res = contract
+            .execute_reorder_modules(
+                deps.as_mut(),
+                mock_info("owner", &[]),
+                vec![Uint64::new(3), Uint64::new(1), Uint64::new(2)],
+            )
+            .unwrap();
+        assert_eq!(
+            Response::default()
+                .add_attribute("action", "reorder_modules")
+                .add_attribute("order", "3,1,2"),
+            res
+        );
+
+        let modules = contract
+            .load_modules_with_idx(deps.as_ref().storage)
+            .unwrap();
+        let order: Vec<(u64, Option<u64>)> = modules
+            .into_iter()
+            .map(|(idx, module)| (idx, module.priority))
+            .collect();
+        assert_eq!(vec![(3, Some(0)), (1, Some(1)), (2, Some(2))], order);
+    }
 }
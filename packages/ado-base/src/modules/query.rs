@@ -1,6 +1,11 @@
 use crate::{modules::Module, ADOContract};
-use common::error::ContractError;
-use cosmwasm_std::{Deps, Order, Uint64};
+use common::{
+    ado_base::hooks::{FundsTransferBreakdownResponse, SimulateFundsTransferResponse},
+    error::ContractError,
+    rates::get_tax_amount,
+    Funds,
+};
+use cosmwasm_std::{Binary, Deps, Order, Uint64};
 use cw_storage_plus::Bound;
 
 impl<'a> ADOContract<'a> {
@@ -22,14 +27,63 @@ impl<'a> ADOContract<'a> {
             .collect();
         Ok(module_ids?)
     }
+
+    /// Queries what each module would take from a funds transfer of `amount` by `sender`.
+    pub fn query_funds_transfer_breakdown(
+        &self,
+        deps: Deps,
+        sender: String,
+        amount: Funds,
+    ) -> Result<FundsTransferBreakdownResponse, ContractError> {
+        let modules = self.funds_transfer_breakdown(
+            deps.storage,
+            deps.api,
+            &deps.querier,
+            sender,
+            amount,
+            Binary::default(),
+        )?;
+        Ok(FundsTransferBreakdownResponse { modules })
+    }
+
+    /// Queries the payments and tax that a funds transfer of `amount` by `sender` would result in.
+    pub fn query_simulate_funds_transfer(
+        &self,
+        deps: Deps,
+        sender: String,
+        amount: Funds,
+    ) -> Result<SimulateFundsTransferResponse, ContractError> {
+        let (payments, events, leftover_funds) = self.on_funds_transfer(
+            deps.storage,
+            deps.api,
+            &deps.querier,
+            sender,
+            amount.clone(),
+            Binary::default(),
+        )?;
+        let total_tax = get_tax_amount(&payments, amount.amount(), leftover_funds.amount())?;
+        Ok(SimulateFundsTransferResponse {
+            payments,
+            events,
+            leftover_funds,
+            total_tax,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::Module;
-    use common::app::AndrAddress;
-    use cosmwasm_std::{testing::mock_dependencies, Addr};
+    use crate::{
+        mock_querier::{
+            mock_dependencies_custom, MOCK_FLAT_MODULE_CONTRACT, MOCK_TAX_MODULE_CONTRACT,
+        },
+        modules::Module,
+    };
+    use common::{ado_base::modules::RATES, app::AndrAddress};
+    use cosmwasm_std::{
+        coin, coins, testing::mock_dependencies, Addr, BankMsg, Event, SubMsg, Uint128,
+    };
 
     #[test]
     fn test_query_module() {
@@ -47,6 +101,7 @@ mod tests {
                 identifier: "address1".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
 
         let module2 = Module {
@@ -55,6 +110,7 @@ mod tests {
                 identifier: "address2".to_string(),
             },
             is_mutable: true,
+            priority: None,
         };
 
         contract
@@ -84,4 +140,55 @@ mod tests {
         let res = contract.query_module_ids(deps.as_ref()).unwrap();
         assert_eq!(vec![String::from("1"), String::from("2")], res);
     }
+
+    #[test]
+    fn test_query_simulate_funds_transfer() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        for address in [MOCK_FLAT_MODULE_CONTRACT, MOCK_TAX_MODULE_CONTRACT] {
+            let module = Module {
+                module_type: RATES.to_owned(),
+                address: AndrAddress {
+                    identifier: address.to_owned(),
+                },
+                is_mutable: false,
+                priority: None,
+            };
+            contract
+                .execute_register_module(deps.as_mut().storage, "owner", module, false)
+                .unwrap();
+        }
+
+        let res = contract
+            .query_simulate_funds_transfer(
+                deps.as_ref(),
+                "sender".to_string(),
+                Funds::Native(coin(100, "uusd")),
+            )
+            .unwrap();
+
+        // The flat fee is deducted from the funds and the tax is charged on the remainder.
+        assert_eq!(
+            SimulateFundsTransferResponse {
+                payments: vec![
+                    SubMsg::new(BankMsg::Send {
+                        to_address: "flat".to_string(),
+                        amount: coins(10, "uusd"),
+                    }),
+                    SubMsg::new(BankMsg::Send {
+                        to_address: "tax".to_string(),
+                        amount: coins(9, "uusd"),
+                    }),
+                ],
+                events: vec![Event::new("flat"), Event::new("tax")],
+                leftover_funds: Funds::Native(coin(90, "uusd")),
+                total_tax: Uint128::new(9),
+            },
+            res
+        );
+    }
 }
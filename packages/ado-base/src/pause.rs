@@ -0,0 +1,344 @@
+use crate::ADOContract;
+use common::{
+    ado_base::{pause::PausedResponse, roles::PAUSER},
+    error::ContractError,
+    get_message_name,
+};
+use cosmwasm_std::{
+    attr, ensure, to_binary, Binary, Deps, DepsMut, MessageInfo, Order, Response, Storage,
+};
+use serde::Serialize;
+
+/// The serialized name of the `AndrReceive` execute message.
+const ANDR_RECEIVE: &str = "andr_receive";
+
+impl<'a> ADOContract<'a> {
+    /// Pauses `messages`, or every user message if `None`. **Only executable by the contract
+    /// owner or a pauser.**
+    pub fn execute_pause(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        messages: Option<Vec<String>>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_owner_or_role(deps.storage, PAUSER, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        match messages {
+            None => self.paused.save(deps.storage, &true)?,
+            Some(messages) => {
+                for message in messages.iter() {
+                    self.paused_messages.save(deps.storage, message, &true)?;
+                }
+            }
+        }
+
+        Ok(Response::new().add_attributes(vec![attr("action", "pause")]))
+    }
+
+    /// Unpauses `messages`, or everything if `None`. **Only executable by the contract owner or a
+    /// pauser.**
+    pub fn execute_unpause(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        messages: Option<Vec<String>>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_owner_or_role(deps.storage, PAUSER, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let messages = match messages {
+            Some(messages) => messages,
+            None => {
+                self.paused.remove(deps.storage);
+                self.paused_messages
+                    .keys(deps.storage, None, None, Order::Ascending)
+                    .collect::<Result<Vec<String>, _>>()?
+            }
+        };
+        for message in messages.iter() {
+            self.paused_messages.remove(deps.storage, message);
+        }
+
+        Ok(Response::new().add_attributes(vec![attr("action", "unpause")]))
+    }
+
+    /// Registers `messages` as administrative messages of the ADO, e.g. `cancel_auction`. They
+    /// remain executable while every user message is paused, but can still be paused
+    /// individually.
+    pub fn register_admin_messages(
+        &self,
+        storage: &mut dyn Storage,
+        messages: &[&str],
+    ) -> Result<(), ContractError> {
+        for message in messages {
+            self.admin_messages.save(storage, message, &true)?;
+        }
+        Ok(())
+    }
+
+    /// Returns whether the message with the serialized name `name` is paused.
+    pub fn is_paused(&self, storage: &dyn Storage, name: &str) -> Result<bool, ContractError> {
+        if self.paused_messages.has(storage, name) {
+            return Ok(true);
+        }
+        Ok(self.paused.may_load(storage)?.unwrap_or(false)
+            && !self.admin_messages.has(storage, name))
+    }
+
+    /// Errors if `msg` is paused. Administrative messages registered with
+    /// `register_admin_messages` are only rejected if they were paused individually, so that the
+    /// ADO can still be administered and recovered while every user message is paused.
+    pub fn ensure_not_paused(
+        &self,
+        storage: &dyn Storage,
+        msg: &Binary,
+    ) -> Result<(), ContractError> {
+        // Avoid parsing the message if nothing has been paused.
+        if !self.has_paused(storage)? {
+            return Ok(());
+        }
+        self.ensure_name_not_paused(storage, get_message_name(msg)?)
+    }
+
+    /// Errors if the execute message `msg` is paused. ADOs call this at the top of `execute` so
+    /// that their messages are paused whether or not they are passed to `module_hook`.
+    /// `AndrReceive` itself is never paused, the message it wraps is checked when received.
+    pub fn ensure_execute_not_paused<T: Serialize>(
+        &self,
+        storage: &dyn Storage,
+        msg: &T,
+    ) -> Result<(), ContractError> {
+        if !self.has_paused(storage)? {
+            return Ok(());
+        }
+        let name = get_message_name(&to_binary(msg)?)?;
+        if name == ANDR_RECEIVE {
+            return Ok(());
+        }
+        self.ensure_name_not_paused(storage, name)
+    }
+
+    fn has_paused(&self, storage: &dyn Storage) -> Result<bool, ContractError> {
+        Ok(self.paused.may_load(storage)?.unwrap_or(false)
+            || self
+                .paused_messages
+                .keys(storage, None, None, Order::Ascending)
+                .next()
+                .is_some())
+    }
+
+    fn ensure_name_not_paused(
+        &self,
+        storage: &dyn Storage,
+        name: String,
+    ) -> Result<(), ContractError> {
+        let paused = self.paused.may_load(storage)?.unwrap_or(false);
+        ensure!(
+            !paused || self.admin_messages.has(storage, &name),
+            ContractError::Paused {
+                msg: "all".to_string()
+            }
+        );
+        ensure!(
+            !self.paused_messages.has(storage, &name),
+            ContractError::Paused { msg: name }
+        );
+        Ok(())
+    }
+
+    pub fn query_paused(&self, deps: Deps) -> Result<PausedResponse, ContractError> {
+        let paused = self.paused.may_load(deps.storage)?.unwrap_or(false);
+        let messages: Result<Vec<String>, _> = self
+            .paused_messages
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect();
+        Ok(PausedResponse {
+            paused,
+            messages: messages?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::ado_base::AndromedaMsg;
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_info},
+        Addr,
+    };
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum TestMsg {
+        AndrReceive(AndromedaMsg),
+        PlaceBid {},
+        Claim {},
+        CancelAuction {},
+    }
+
+    #[test]
+    fn test_pause_unauthorized() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let res = contract.execute_pause(deps.as_mut(), mock_info("anyone", &[]), None);
+        assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+        contract
+            .roles
+            .save(deps.as_mut().storage, (PAUSER, "pauser"), &true)
+            .unwrap();
+        contract
+            .execute_pause(deps.as_mut(), mock_info("pauser", &[]), None)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_pause_all() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .register_admin_messages(deps.as_mut().storage, &["cancel_auction"])
+            .unwrap();
+        let msg = to_binary(&TestMsg::Claim {}).unwrap();
+        let admin_msg = to_binary(&TestMsg::CancelAuction {}).unwrap();
+
+        contract
+            .execute_pause(deps.as_mut(), mock_info("owner", &[]), None)
+            .unwrap();
+        assert_eq!(
+            ContractError::Paused {
+                msg: "all".to_string()
+            },
+            contract
+                .ensure_not_paused(deps.as_ref().storage, &msg)
+                .unwrap_err()
+        );
+        assert!(contract.is_paused(deps.as_ref().storage, "claim").unwrap());
+        // Admin messages are still allowed.
+        contract
+            .ensure_not_paused(deps.as_ref().storage, &admin_msg)
+            .unwrap();
+        assert!(!contract
+            .is_paused(deps.as_ref().storage, "cancel_auction")
+            .unwrap());
+
+        // Unless they are paused individually.
+        contract
+            .execute_pause(
+                deps.as_mut(),
+                mock_info("owner", &[]),
+                Some(vec!["cancel_auction".to_string()]),
+            )
+            .unwrap();
+        assert_eq!(
+            ContractError::Paused {
+                msg: "cancel_auction".to_string()
+            },
+            contract
+                .ensure_not_paused(deps.as_ref().storage, &admin_msg)
+                .unwrap_err()
+        );
+
+        contract
+            .execute_unpause(deps.as_mut(), mock_info("owner", &[]), None)
+            .unwrap();
+        contract
+            .ensure_not_paused(deps.as_ref().storage, &msg)
+            .unwrap();
+        contract
+            .ensure_not_paused(deps.as_ref().storage, &admin_msg)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_pause_messages() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        contract
+            .execute_pause(
+                deps.as_mut(),
+                mock_info("owner", &[]),
+                Some(vec!["place_bid".to_string()]),
+            )
+            .unwrap();
+        assert_eq!(
+            PausedResponse {
+                paused: false,
+                messages: vec!["place_bid".to_string()]
+            },
+            contract.query_paused(deps.as_ref()).unwrap()
+        );
+
+        let place_bid = to_binary(&TestMsg::PlaceBid {}).unwrap();
+        let claim = to_binary(&TestMsg::Claim {}).unwrap();
+        assert_eq!(
+            ContractError::Paused {
+                msg: "place_bid".to_string()
+            },
+            contract
+                .ensure_not_paused(deps.as_ref().storage, &place_bid)
+                .unwrap_err()
+        );
+        contract
+            .ensure_not_paused(deps.as_ref().storage, &claim)
+            .unwrap();
+
+        contract
+            .execute_unpause(
+                deps.as_mut(),
+                mock_info("owner", &[]),
+                Some(vec!["place_bid".to_string()]),
+            )
+            .unwrap();
+        contract
+            .ensure_not_paused(deps.as_ref().storage, &place_bid)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_ensure_execute_not_paused() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .execute_pause(deps.as_mut(), mock_info("owner", &[]), None)
+            .unwrap();
+
+        assert_eq!(
+            ContractError::Paused {
+                msg: "all".to_string()
+            },
+            contract
+                .ensure_execute_not_paused(deps.as_ref().storage, &TestMsg::Claim {})
+                .unwrap_err()
+        );
+        // Unpausing goes through `AndrReceive`.
+        contract
+            .ensure_execute_not_paused(
+                deps.as_ref().storage,
+                &TestMsg::AndrReceive(AndromedaMsg::Unpause { messages: None }),
+            )
+            .unwrap();
+    }
+}
@@ -4,7 +4,8 @@ use common::{
         ado_type::TypeResponse,
         block_height::BlockHeightResponse,
         operators::{IsOperatorResponse, OperatorsResponse},
-        ownership::{ContractOwnerResponse, PublisherResponse},
+        ownership::{ContractOwnerResponse, PendingOwnerResponse, PublisherResponse},
+        roles::{HasRoleResponse, RoleHoldersResponse, RolesResponse},
         version::VersionResponse,
         AndromedaQuery, QueryMsg,
     },
@@ -13,10 +14,14 @@ use common::{
     parse_message,
 };
 use cosmwasm_std::{ensure, Binary, Deps, Env, Order};
+use cw_storage_plus::{Bound, PrefixBound};
 use serde::de::DeserializeOwned;
 
 type QueryFunction<Q> = fn(Deps, Env, Q) -> Result<Binary, ContractError>;
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 impl<'a> ADOContract<'a> {
     #[allow(unreachable_patterns)]
     pub fn query<Q: DeserializeOwned>(
@@ -36,6 +41,7 @@ impl<'a> ADOContract<'a> {
                 (query_function)(deps, env, received)
             }
             AndromedaQuery::Owner {} => encode_binary(&self.query_contract_owner(deps)?),
+            AndromedaQuery::PendingOwner {} => encode_binary(&self.query_pending_owner(deps)?),
             AndromedaQuery::Operators {} => encode_binary(&self.query_operators(deps)?),
             AndromedaQuery::OriginalPublisher {} => {
                 encode_binary(&self.query_original_publisher(deps)?)
@@ -48,21 +54,57 @@ impl<'a> ADOContract<'a> {
                 encode_binary(&self.query_is_operator(deps, &address)?)
             }
             AndromedaQuery::Version {} => encode_binary(&self.query_version(deps)?),
+            AndromedaQuery::Roles {
+                address,
+                start_after,
+                limit,
+            } => encode_binary(&self.query_roles(deps, address, start_after, limit)?),
+            AndromedaQuery::RoleHolders {
+                role,
+                start_after,
+                limit,
+            } => encode_binary(&self.query_role_holders(deps, role, start_after, limit)?),
+            AndromedaQuery::HasRole { role, address } => {
+                encode_binary(&self.query_has_role(deps, &role, &address)?)
+            }
+            AndromedaQuery::Paused {} => encode_binary(&self.query_paused(deps)?),
+            AndromedaQuery::MigrationHistory {} => {
+                encode_binary(&self.query_migration_history(deps)?)
+            }
             #[cfg(feature = "modules")]
             AndromedaQuery::Module { id } => encode_binary(&self.query_module(deps, id)?),
             #[cfg(feature = "modules")]
             AndromedaQuery::ModuleIds {} => encode_binary(&self.query_module_ids(deps)?),
+            #[cfg(feature = "modules")]
+            AndromedaQuery::FundsTransferBreakdown { sender, amount } => {
+                encode_binary(&self.query_funds_transfer_breakdown(deps, sender, amount)?)
+            }
+            #[cfg(feature = "modules")]
+            AndromedaQuery::SimulateFundsTransfer { sender, amount } => {
+                encode_binary(&self.query_simulate_funds_transfer(deps, sender, amount)?)
+            }
             _ => Err(ContractError::UnsupportedOperation {}),
         }
     }
 }
 
 impl<'a> ADOContract<'a> {
+    /// Queries the contract owner. The returned owner is empty if ownership has been renounced.
     pub fn query_contract_owner(&self, deps: Deps) -> Result<ContractOwnerResponse, ContractError> {
-        let owner = self.owner.load(deps.storage)?;
+        let owner = self.owner.may_load(deps.storage)?;
 
         Ok(ContractOwnerResponse {
-            owner: owner.to_string(),
+            owner: owner.map(|owner| owner.to_string()).unwrap_or_default(),
+        })
+    }
+
+    pub fn query_pending_owner(&self, deps: Deps) -> Result<PendingOwnerResponse, ContractError> {
+        let pending_owner = self.pending_owner.may_load(deps.storage)?;
+        let expiration = self.pending_owner_expiration.may_load(deps.storage)?;
+
+        Ok(PendingOwnerResponse {
+            pending_owner: pending_owner.map(|owner| owner.to_string()),
+            expiration,
         })
     }
 
@@ -107,4 +149,151 @@ impl<'a> ADOContract<'a> {
         let version = self.version.load(deps.storage)?;
         Ok(VersionResponse { version })
     }
+
+    pub fn query_roles(
+        &self,
+        deps: Deps,
+        address: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<RolesResponse, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        if let Some(address) = address {
+            let start = start_after.as_deref().map(Bound::exclusive);
+            let roles: Result<Vec<String>, _> = self
+                .address_roles
+                .prefix(&address)
+                .keys(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .collect();
+            return Ok(RolesResponse { roles: roles? });
+        }
+        let start = start_after.as_deref().map(PrefixBound::exclusive);
+        let mut roles: Vec<String> = vec![];
+        for key in self
+            .roles
+            .prefix_range(deps.storage, start, None, Order::Ascending)
+        {
+            let ((role, _), _) = key?;
+            // Keys are sorted by role so duplicates are adjacent.
+            if roles.last() == Some(&role) {
+                continue;
+            }
+            if roles.len() == limit {
+                break;
+            }
+            roles.push(role);
+        }
+        Ok(RolesResponse { roles })
+    }
+
+    pub fn query_role_holders(
+        &self,
+        deps: Deps,
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<RoleHoldersResponse, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.as_deref().map(Bound::exclusive);
+        let holders: Result<Vec<String>, _> = self
+            .roles
+            .prefix(&role)
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect();
+        Ok(RoleHoldersResponse {
+            role,
+            holders: holders?,
+        })
+    }
+
+    pub fn query_has_role(
+        &self,
+        deps: Deps,
+        role: &str,
+        addr: &str,
+    ) -> Result<HasRoleResponse, ContractError> {
+        Ok(HasRoleResponse {
+            has_role: self.has_role(deps.storage, role, addr),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::ado_base::roles::{MINTER, PAUSER};
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn test_query_roles() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+
+        for (role, addr) in [(MINTER, "a"), (MINTER, "b"), (PAUSER, "b"), (MINTER, "c")] {
+            contract
+                .roles
+                .save(deps.as_mut().storage, (role, addr), &true)
+                .unwrap();
+            contract
+                .address_roles
+                .save(deps.as_mut().storage, (addr, role), &true)
+                .unwrap();
+        }
+
+        let res = contract
+            .query_roles(deps.as_ref(), None, None, None)
+            .unwrap();
+        assert_eq!(vec![MINTER.to_string(), PAUSER.to_string()], res.roles);
+
+        let res = contract
+            .query_roles(deps.as_ref(), None, None, Some(1))
+            .unwrap();
+        assert_eq!(vec![MINTER.to_string()], res.roles);
+
+        let res = contract
+            .query_roles(deps.as_ref(), None, Some(MINTER.to_string()), None)
+            .unwrap();
+        assert_eq!(vec![PAUSER.to_string()], res.roles);
+
+        let res = contract
+            .query_roles(deps.as_ref(), Some("c".to_string()), None, None)
+            .unwrap();
+        assert_eq!(vec![MINTER.to_string()], res.roles);
+
+        // The limit applies to the roles of the address.
+        let res = contract
+            .query_roles(deps.as_ref(), Some("b".to_string()), None, Some(1))
+            .unwrap();
+        assert_eq!(vec![MINTER.to_string()], res.roles);
+
+        let res = contract
+            .query_roles(
+                deps.as_ref(),
+                Some("b".to_string()),
+                Some(MINTER.to_string()),
+                None,
+            )
+            .unwrap();
+        assert_eq!(vec![PAUSER.to_string()], res.roles);
+
+        let res = contract
+            .query_role_holders(deps.as_ref(), MINTER.to_string(), None, Some(2))
+            .unwrap();
+        assert_eq!(vec!["a".to_string(), "b".to_string()], res.holders);
+
+        let res = contract
+            .query_role_holders(
+                deps.as_ref(),
+                MINTER.to_string(),
+                Some("b".to_string()),
+                None,
+            )
+            .unwrap();
+        assert_eq!(vec!["c".to_string()], res.holders);
+
+        let res = contract.query_has_role(deps.as_ref(), PAUSER, "a").unwrap();
+        assert!(!res.has_role);
+    }
 }
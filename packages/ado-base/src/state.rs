@@ -1,20 +1,30 @@
 #[cfg(feature = "modules")]
 use common::ado_base::modules::Module;
-use common::{error::ContractError, parse_message};
+use common::{ado_base::migration::MigrationRecord, error::ContractError, parse_message};
 use cosmwasm_std::{Addr, Binary};
 #[cfg(feature = "withdraw")]
 use cw_asset::AssetInfo;
 use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 use serde::de::DeserializeOwned;
 
 pub struct ADOContract<'a> {
     pub(crate) owner: Item<'a, Addr>,
+    pub(crate) pending_owner: Item<'a, Addr>,
+    pub(crate) pending_owner_expiration: Item<'a, Expiration>,
     pub(crate) original_publisher: Item<'a, Addr>,
     pub(crate) block_height: Item<'a, u64>,
     pub(crate) operators: Map<'a, &'a str, bool>,
+    pub(crate) roles: Map<'a, (&'a str, &'a str), bool>,
+    /// The roles keyed by holder and role, to look up the roles of an address.
+    pub(crate) address_roles: Map<'a, (&'a str, &'a str), bool>,
+    pub(crate) paused: Item<'a, bool>,
+    pub(crate) paused_messages: Map<'a, &'a str, bool>,
+    pub(crate) admin_messages: Map<'a, &'a str, bool>,
     pub(crate) ado_type: Item<'a, String>,
     pub(crate) version: Item<'a, String>,
     pub(crate) app_contract: Item<'a, Addr>,
+    pub(crate) migration_history: Map<'a, u64, MigrationRecord>,
     #[cfg(feature = "primitive")]
     pub(crate) primitive_contract: Item<'a, Addr>,
     #[cfg(feature = "primitive")]
@@ -31,12 +41,20 @@ impl<'a> Default for ADOContract<'a> {
     fn default() -> Self {
         ADOContract {
             owner: Item::new("owner"),
+            pending_owner: Item::new("pending_owner"),
+            pending_owner_expiration: Item::new("pending_owner_expiration"),
             original_publisher: Item::new("original_publisher"),
             block_height: Item::new("block_height"),
             operators: Map::new("operators"),
+            roles: Map::new("roles"),
+            address_roles: Map::new("address_roles"),
+            paused: Item::new("paused"),
+            paused_messages: Map::new("paused_messages"),
+            admin_messages: Map::new("admin_messages"),
             ado_type: Item::new("ado_type"),
             version: Item::new("version"),
             app_contract: Item::new("app_contract"),
+            migration_history: Map::new("migration_history"),
             #[cfg(feature = "primitive")]
             primitive_contract: Item::new("primitive_contract"),
             #[cfg(feature = "primitive")]
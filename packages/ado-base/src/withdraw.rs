@@ -1,5 +1,9 @@
 use crate::ADOContract;
-use common::{ado_base::recipient::Recipient, error::ContractError, withdraw::Withdrawal};
+use common::{
+    ado_base::{recipient::Recipient, roles::WITHDRAWER},
+    error::ContractError,
+    withdraw::Withdrawal,
+};
 use cosmwasm_std::{coin, DepsMut, Env, MessageInfo, Order, Response, StdError, Storage, SubMsg};
 use cw20::Cw20Coin;
 
@@ -28,6 +32,7 @@ impl<'a> ADOContract<'a> {
     }
 
     /// Withdraw all tokens in self.withdrawable_tokens with non-zero balance to the given recipient.
+    /// **Only executable by the owner, operators and holders of the `withdrawer` role.**
     pub fn execute_withdraw(
         &self,
         deps: DepsMut,
@@ -39,7 +44,8 @@ impl<'a> ADOContract<'a> {
         let recipient = recipient.unwrap_or_else(|| Recipient::Addr(info.sender.to_string()));
         let sender = info.sender.as_str();
         ensure!(
-            self.is_owner_or_operator(deps.storage, sender)?,
+            self.is_owner_or_role(deps.storage, WITHDRAWER, sender)?
+                || self.is_operator(deps.storage, sender),
             ContractError::Unauthorized {},
         )?;
 
@@ -309,4 +315,54 @@ mod tests {
             res
         );
     }
+
+    #[test]
+    fn test_execute_withdraw_withdrawer_role() {
+        let mut deps = mock_dependencies(&[coin(100, "uusd")]);
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .withdrawable_tokens
+            .save(
+                deps.as_mut().storage,
+                "uusd",
+                &AssetInfo::Native("uusd".into()),
+            )
+            .unwrap();
+
+        let err = contract
+            .execute_withdraw(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("withdrawer", &[]),
+                None,
+                None,
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err);
+
+        contract
+            .roles
+            .save(deps.as_mut().storage, (WITHDRAWER, "withdrawer"), &true)
+            .unwrap();
+        let res = contract
+            .execute_withdraw(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("withdrawer", &[]),
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "withdrawer".to_string(),
+                amount: vec![coin(100, "uusd")],
+            })),
+            res.messages[0]
+        );
+    }
 }
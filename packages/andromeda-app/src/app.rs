@@ -1,15 +1,51 @@
 use common::ado_base::{AndromedaMsg, AndromedaQuery};
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Binary, Coin};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct AppComponent {
     pub name: String,
+    /// The code ID key in the factory, which may pin a version as `ado_type@version`. Pinned
+    /// components stay on their version when upgraded.
     pub ado_type: String,
     pub instantiate_msg: Binary,
 }
 
+/// A reusable blueprint of an app, from which the factory can create apps with `CreateApp`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AppTemplate {
+    /// The names of the parameters that must be provided when creating an app.
+    pub parameters: Vec<String>,
+    /// The components in dependency order. Each component may only reference the components
+    /// before it.
+    pub components: Vec<TemplateComponent>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TemplateComponent {
+    pub name: String,
+    pub ado_type: String,
+    /// The JSON instantiate message, in which every `{{parameter}}` is replaced by the
+    /// JSON-escaped value of the parameter. The identifiers of its `AndrAddress`es must be
+    /// addresses or the names of earlier components.
+    pub instantiate_msg: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TemplateParameter {
+    pub name: String,
+    pub value: String,
+}
+
+/// A message to a component as a step of an `ExecuteBatch`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ComponentMessage {
+    pub name: String,
+    pub msg: Binary,
+    pub funds: Vec<Coin>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct InstantiateMsg {
     pub app: Vec<AppComponent>,
@@ -17,14 +53,70 @@ pub struct InstantiateMsg {
     pub primitive_contract: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     AndrReceive(AndromedaMsg),
-    AddAppComponent { component: AppComponent },
-    ClaimOwnership { name: Option<String> },
-    ProxyMessage { name: String, msg: Binary },
-    UpdateAddress { name: String, addr: String },
+    AddAppComponent {
+        component: AppComponent,
+    },
+    /// Proposes the sender as the owner of the named component, or of every component if no name
+    /// is provided. Each proposal must be accepted on the component itself.
+    ClaimOwnership {
+        name: Option<String>,
+    },
+    /// Sends `msg` to the named component. Messages from the owner are sent by the app itself,
+    /// those from principals with a proxy permission are wrapped in a `ProxyReceive` so the
+    /// component sees the principal as the sender.
+    ProxyMessage {
+        name: String,
+        msg: Binary,
+    },
+    /// Sends each message to its component in order, reverting all of them if any fails. The funds
+    /// sent must equal the total funds of the messages.
+    ExecuteBatch {
+        msgs: Vec<ComponentMessage>,
+    },
+    /// Allows `principal` to proxy the given messages, referred to by their serialized names
+    /// (e.g. `mint`), or every message if none are provided, to the named component. Only
+    /// executable by the owner.
+    SetProxyPermission {
+        principal: String,
+        component: String,
+        messages: Option<Vec<String>>,
+    },
+    /// Only executable by the owner.
+    RemoveProxyPermission {
+        principal: String,
+        component: String,
+    },
+    UpdateAddress {
+        name: String,
+        addr: String,
+    },
+    /// Removes the named component from the app, leaving its contract untouched. Fails while
+    /// other components reference the name.
+    RemoveComponent {
+        name: String,
+    },
+    /// Fails while other components reference the current name.
+    RenameComponent {
+        name: String,
+        new_name: String,
+    },
+    /// Migrates the named component to the latest code ID the factory holds under `code_id_key`,
+    /// which defaults to the component's ADO type, and records `code_id_key` as its ADO type.
+    /// Only components instantiated with the app as their admin can be upgraded.
+    UpgradeComponent {
+        name: String,
+        code_id_key: Option<String>,
+        migrate_msg: Binary,
+    },
+    /// Migrates every component that is not on the latest code ID for its ADO type, ignoring any
+    /// pinned version, and unpins the ADO type of the migrated components.
+    UpgradeAll {
+        migrate_msg: Binary,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
@@ -35,11 +127,28 @@ pub struct MigrateMsg {}
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     AndrQuery(AndromedaQuery),
-    GetAddress { name: String },
+    GetAddress {
+        name: String,
+    },
     GetComponents {},
-    ComponentExists { name: String },
+    ComponentExists {
+        name: String,
+    },
     GetAddresses {},
     Config {},
+    /// The deployed and latest code IDs of each component.
+    ComponentVersions {},
+    /// The component names referenced by each component's instantiate message, including messages
+    /// nested in it as base64 binaries. References a component makes after instantiation, e.g.
+    /// names it resolves with `GetAddress` or receives in later messages, are not listed, nor are
+    /// changes made with `UpdateAddress`.
+    ComponentReferences {},
+    /// The components `principal` may proxy messages to, in ascending order of name.
+    ProxyPermissions {
+        principal: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -54,6 +163,33 @@ pub struct ComponentAddress {
     pub address: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ProxyPermission {
+    pub principal: String,
+    pub component: String,
+    /// The messages that may be proxied, `None` if any message may be.
+    pub messages: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ComponentReference {
+    pub name: String,
+    /// The names of the other components that the component's instantiate message refers to as
+    /// `AndrAddress`es.
+    pub references: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ComponentVersion {
+    pub name: String,
+    pub ado_type: String,
+    /// `None` if the component has not been instantiated yet.
+    pub deployed_code_id: Option<u64>,
+    /// The code ID of the latest version of the ADO type, ignoring any pinned version. `None` if
+    /// the factory has no code ID for the type, e.g. if every version is deprecated.
+    pub latest_code_id: Option<u64>,
+}
+
 #[cfg(test)]
 mod tests {
     // use super::*;
@@ -1,4 +1,6 @@
+use crate::app::{AppTemplate, TemplateParameter};
 use common::ado_base::{AndromedaMsg, AndromedaQuery};
+use cosmwasm_std::Binary;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -9,14 +11,43 @@ pub struct InstantiateMsg {}
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     AndrReceive(AndromedaMsg),
-    /// Create new token
+    /// Instantiates the ADO whose code ID is stored under `code_id_key` with `instantiate_msg`.
+    /// The sender becomes the admin of the new contract and is recorded as its creator. The
+    /// factory owns the new ADO until the creator accepts the ownership proposed to them.
     Create {
+        code_id_key: String,
+        instantiate_msg: Binary,
+    },
+    /// Creates an app from the named template, filling in its parameters. The app is created
+    /// with the code ID stored under `app`.
+    CreateApp {
+        template: String,
+        name: String,
+        primitive_contract: String,
+        parameters: Vec<TemplateParameter>,
+    },
+    /// Stores `template` under `name`, replacing any template of that name.
+    AddAppTemplate {
+        name: String,
+        template: AppTemplate,
+    },
+    RemoveAppTemplate {
         name: String,
-        symbol: String,
     },
+    /// Registers `code_id` under `code_id_key`. With a `version` the code ID is added to the
+    /// versions of the ADO type and the key resolves to the newest non-deprecated version;
+    /// without one the key is overwritten, which is rejected for ADO types with versions.
     UpdateCodeId {
         code_id_key: String,
         code_id: u64,
+        version: Option<String>,
+    },
+    /// Deprecated versions can no longer be pinned and are skipped when resolving the latest
+    /// version of the ADO type.
+    SetCodeIdDeprecated {
+        code_id_key: String,
+        version: String,
+        deprecated: bool,
     },
     /// Update token contract address by symbol
     UpdateAddress {
@@ -37,13 +68,73 @@ pub enum QueryMsg {
     GetAddress {
         symbol: String,
     },
-    /// All code IDs for Andromeda contracts
+    /// All code IDs for Andromeda contracts. The key may pin a version as `ado_type@version`.
     CodeId {
         key: String,
     },
+    /// The registered versions of an ADO type in ascending order of version string.
+    CodeIdVersions {
+        code_id_key: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    AppTemplate {
+        name: String,
+    },
+    /// The names of the stored app templates in ascending order.
+    AppTemplates {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// The ADOs created through the factory in ascending order of deployment ID.
+    Deployments {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    DeploymentsByCreator {
+        creator: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// The deployments of the ADOs created with the given `code_id_key`.
+    DeploymentsByType {
+        ado_type: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct AddressResponse {
     pub address: String,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct CodeIdVersion {
+    pub version: String,
+    pub code_id: u64,
+    pub deprecated: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct CodeIdVersionsResponse {
+    /// The version that the unpinned key resolves to.
+    pub latest: Option<String>,
+    pub versions: Vec<CodeIdVersion>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Deployment {
+    pub deployment_id: u64,
+    pub creator: String,
+    /// The `code_id_key` the ADO was created with, without any pinned version.
+    pub ado_type: String,
+    pub code_id: u64,
+    pub address: String,
+    pub height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DeploymentsResponse {
+    pub deployments: Vec<Deployment>,
+}
@@ -1,10 +1,15 @@
 use common::{
     ado_base::{AndromedaMsg, AndromedaQuery},
-    primitive::Primitive,
+    primitive::{Primitive, PrimitiveType},
 };
+use cw_utils::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Separates a key's namespace from the rest of the key, e.g. `rates/fee` is in the `rates`
+/// namespace.
+pub const NAMESPACE_SEPARATOR: char = '/';
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct InstantiateMsg {}
 
@@ -12,7 +17,8 @@ pub struct InstantiateMsg {}
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     AndrReceive(AndromedaMsg),
-    /// If key is not specified the default key will be used.
+    /// If key is not specified the default key will be used. The owner and operators may set any
+    /// key, writers of a namespace may also set the keys in that namespace.
     SetValue {
         key: Option<String>,
         value: Primitive,
@@ -21,6 +27,26 @@ pub enum ExecuteMsg {
     DeleteValue {
         key: Option<String>,
     },
+    /// Set the value of the key once `activate_at` is reached, replacing any value already
+    /// scheduled for the key. The same permissions and schema apply as for `SetValue`. If key is
+    /// not specified the default key will be used.
+    ScheduleValue {
+        key: Option<String>,
+        value: Primitive,
+        activate_at: Expiration,
+    },
+    /// Restrict the values stored under the key to the given type, or lift the restriction if
+    /// `primitive_type` is `None`. If key is not specified the default key will be used.
+    SetSchema {
+        key: Option<String>,
+        primitive_type: Option<PrimitiveType>,
+    },
+    /// Replace the addresses that may write the keys in the namespace. Only executable by the
+    /// owner.
+    SetNamespaceWriters {
+        namespace: String,
+        writers: Vec<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
@@ -31,4 +57,77 @@ pub struct MigrateMsg {}
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     AndrQuery(AndromedaQuery),
+    /// The type the values of the key are restricted to. If key is not specified the default key
+    /// will be used.
+    Schema {
+        key: Option<String>,
+    },
+    /// The addresses that may write the keys in the namespace, beside the owner and operators.
+    NamespaceWriters {
+        namespace: String,
+    },
+    /// The value the key had at the given height. If key is not specified the default key will
+    /// be used.
+    GetValueAt {
+        key: Option<String>,
+        height: u64,
+    },
+    /// The value scheduled to be set for the key, if any. If key is not specified the default key
+    /// will be used.
+    ScheduledValue {
+        key: Option<String>,
+    },
+    /// The keys with a value in ascending order, optionally only those starting with `prefix`.
+    AllKeys {
+        prefix: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SchemaResponse {
+    pub key: String,
+    pub primitive_type: Option<PrimitiveType>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct NamespaceWritersResponse {
+    pub namespace: String,
+    pub writers: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AllKeysResponse {
+    pub keys: Vec<String>,
+}
+
+/// A change to the value of a key.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ValueRecord {
+    /// The value set, or `None` if the value was deleted.
+    pub value: Option<Primitive>,
+    pub setter: String,
+    /// The height from which the value applies.
+    pub height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ScheduledValue {
+    pub value: Primitive,
+    pub setter: String,
+    pub activate_at: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetValueAtResponse {
+    pub key: String,
+    /// The latest change at or before the height, `None` if no change was recorded by then.
+    pub record: Option<ValueRecord>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ScheduledValueResponse {
+    pub key: String,
+    pub scheduled: Option<ScheduledValue>,
 }
@@ -1,4 +1,6 @@
 use common::ado_base::{hooks::AndromedaHook, AndromedaMsg, AndromedaQuery};
+use cosmwasm_std::BlockInfo;
+use cw_utils::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +21,45 @@ pub enum ExecuteMsg {
     RemoveAddress {
         address: String,
     },
+    /// Add or replace several addresses in the address list
+    AddAddresses {
+        addresses: Vec<AddressEntry>,
+    },
+    /// Remove several addresses from the address list
+    RemoveAddresses {
+        addresses: Vec<String>,
+    },
+    /// Set the rule for the execute message with the given variant name, e.g. `purchase`. A rule
+    /// of `None` reverts the message to the default rule.
+    SetMessageRule {
+        message_name: String,
+        rule: Option<MessageRule>,
+    },
+    /// Set the rule for messages without their own rule
+    SetDefaultMessageRule {
+        rule: MessageRule,
+    },
+}
+
+/// How the address list treats a message sent to the ADO it is attached to.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageRule {
+    /// Anyone may execute the message.
+    Open,
+    /// Only addresses permitted by the address list may execute the message.
+    #[default]
+    Restricted,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AddressEntry {
+    pub address: String,
+    /// When the address stops being included, in milliseconds since the epoch.
+    pub expiration: Option<u64>,
+    /// The permission tags of the address. An address without tags has every tag.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
@@ -28,11 +69,21 @@ pub struct MigrateMsg {}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    /// Query if address is included
+    /// Query if address is included, optionally with the given permission tag
     IncludesAddress {
         address: String,
+        tag: Option<String>,
+    },
+    /// Query the entries of the address list, including expired ones
+    Addresses {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Query the default message rule and the rules of individual messages
+    MessageRules {
+        start_after: Option<String>,
+        limit: Option<u32>,
     },
-    /// Query the current contract owner
     AndrHook(AndromedaHook),
     AndrQuery(AndromedaQuery),
 }
@@ -42,3 +93,42 @@ pub struct IncludesAddressResponse {
     /// Whether the address is included in the address list
     pub included: bool,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddressInfo {
+    pub address: String,
+    pub expiration: Option<Expiration>,
+    pub tags: Vec<String>,
+}
+
+impl AddressInfo {
+    /// Returns whether the address is included at the given block with the given tag. Addresses
+    /// without tags have every tag.
+    pub fn is_included(&self, block: &BlockInfo, tag: Option<&str>) -> bool {
+        let is_expired =
+            matches!(&self.expiration, Some(expiration) if expiration.is_expired(block));
+        let has_tag = match tag {
+            Some(tag) => self.tags.is_empty() || self.tags.iter().any(|t| t == tag),
+            None => true,
+        };
+        !is_expired && has_tag
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddressesResponse {
+    pub addresses: Vec<AddressInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MessageRuleInfo {
+    pub message_name: String,
+    pub rule: MessageRule,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MessageRulesResponse {
+    /// The rule of messages without their own rule
+    pub default_rule: MessageRule,
+    pub rules: Vec<MessageRuleInfo>,
+}
@@ -1,3 +1,4 @@
 pub mod address_list;
+pub mod merkle_allowlist;
 pub mod rates;
 pub mod receipt;
@@ -0,0 +1,80 @@
+use common::ado_base::{hooks::AndromedaHook, AndromedaMsg, AndromedaQuery};
+pub use common::merkle::MerkleProof;
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    /// The hex encoded root of a sha256 merkle tree whose leaves are the allowed addresses, each
+    /// optionally followed by its allocation
+    pub merkle_root: String,
+    /// The names of the execute messages that require a proof. Every message requires a proof if
+    /// not provided.
+    pub restricted_messages: Option<Vec<String>>,
+    /// The addresses of the ADOs the module is attached to, which may record uses
+    #[serde(default)]
+    pub callers: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    AndrReceive(AndromedaMsg),
+    /// Replace the merkle root. Recorded uses are kept.
+    UpdateMerkleRoot {
+        merkle_root: String,
+    },
+    UpdateRestrictedMessages {
+        restricted_messages: Option<Vec<String>>,
+    },
+    /// Replace the addresses of the ADOs that may record uses.
+    UpdateCallers {
+        callers: Vec<String>,
+    },
+    /// Record that `address` used one of its allocation. Sent by the ADO the module is attached to,
+    /// which must be one of the registered callers.
+    RecordUse {
+        address: String,
+        allocation: Uint128,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MigrateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Config {},
+    /// Query if the proof shows that the address is allowed and has allocation left
+    VerifyProof {
+        address: String,
+        merkle_proof: MerkleProof,
+    },
+    /// Query how many restricted messages the address has executed
+    Uses {
+        address: String,
+    },
+    AndrHook(AndromedaHook),
+    AndrQuery(AndromedaQuery),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ConfigResponse {
+    pub merkle_root: String,
+    pub restricted_messages: Option<Vec<String>>,
+    pub callers: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct VerifyProofResponse {
+    pub verified: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct UsesResponse {
+    pub uses: Uint128,
+}
@@ -1,9 +1,14 @@
+use crate::address_list::{IncludesAddressResponse, QueryMsg as AddressListQueryMsg};
 use common::{
     ado_base::{hooks::AndromedaHook, recipient::Recipient, AndromedaMsg, AndromedaQuery},
+    app::AndrAddress,
     error::ContractError,
     primitive::{Primitive, PrimitivePointer},
 };
-use cosmwasm_std::{ensure, Addr, Api, Coin, Decimal, Fraction, QuerierWrapper};
+use cosmwasm_std::{
+    ensure, Addr, Api, BlockInfo, Coin, Decimal, Fraction, QuerierWrapper, Uint128,
+};
+use cw_utils::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +47,88 @@ pub struct RateInfo {
     pub is_additive: bool,
     pub description: Option<String>,
     pub recipients: Vec<Recipient>,
+    /// The rate only applies once `start` has expired.
+    #[serde(default)]
+    pub start: Option<Expiration>,
+    /// The rate no longer applies once `end` has expired.
+    #[serde(default)]
+    pub end: Option<Expiration>,
+    /// The native denoms or CW20 addresses the rate applies to. If not provided, a flat rate
+    /// applies to funds of its own denom and any other rate applies to any funds. A flat rate's
+    /// own denom must be included.
+    #[serde(default)]
+    pub denoms: Option<Vec<String>>,
+    /// Senders that do not pay the rate.
+    #[serde(default)]
+    pub exempt_senders: Option<Vec<String>>,
+    /// An address list of senders that do not pay the rate.
+    #[serde(default)]
+    pub exempt_address_list: Option<AndrAddress>,
+}
+
+impl RateInfo {
+    /// Returns whether the rate applies at the given block.
+    pub fn is_active(&self, block: &BlockInfo) -> bool {
+        let has_started = match &self.start {
+            Some(start) => start.is_expired(block),
+            None => true,
+        };
+        let has_ended = matches!(&self.end, Some(end) if end.is_expired(block));
+        has_started && !has_ended
+    }
+
+    /// Ensures that a flat rate restricted to `denoms` can be charged on them, as a flat fee is
+    /// only ever charged on funds of its own denom.
+    pub fn validate_denoms(&self) -> Result<(), ContractError> {
+        if let (Rate::Flat(coin), Some(denoms)) = (&self.rate, &self.denoms) {
+            ensure!(
+                denoms.contains(&coin.denom),
+                ContractError::FlatRateDenomNotIncluded {
+                    denom: coin.denom.clone()
+                }
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns whether the rate applies to funds of `denom`, being a native denom or a CW20
+    /// address.
+    pub fn applies_to_denom(&self, denom: &str) -> bool {
+        match &self.denoms {
+            Some(denoms) => denoms.iter().any(|d| d == denom),
+            None => self.rate.applies_to_denom(denom),
+        }
+    }
+
+    /// Returns whether `sender` is exempt from the rate, either by being listed in
+    /// `exempt_senders` or by being included in `exempt_address_list`.
+    pub fn is_exempt(
+        &self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        app_contract: Option<Addr>,
+        sender: &str,
+    ) -> Result<bool, ContractError> {
+        if let Some(exempt_senders) = &self.exempt_senders {
+            if exempt_senders.iter().any(|s| s == sender) {
+                return Ok(true);
+            }
+        }
+        match &self.exempt_address_list {
+            Some(address_list) => {
+                let address_list = address_list.get_address(api, querier, app_contract)?;
+                let res: IncludesAddressResponse = querier.query_wasm_smart(
+                    address_list,
+                    &AddressListQueryMsg::IncludesAddress {
+                        address: sender.to_owned(),
+                        tag: None,
+                    },
+                )?;
+                Ok(res.included)
+            }
+            None => Ok(false),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -52,19 +139,95 @@ pub enum Rate {
     Flat(Coin),
     /// A percentage fee
     Percent(PercentRate),
+    /// A percentage fee that depends on the amount paid
+    Tiered(TieredRate),
     External(PrimitivePointer),
 }
 
+impl Rate {
+    /// Returns whether the rate can be charged on funds of `denom`. A flat rate can only be
+    /// charged on funds of its own denom.
+    pub fn applies_to_denom(&self, denom: &str) -> bool {
+        match self {
+            Rate::Flat(coin) => coin.denom == denom,
+            _ => true,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 // This is added such that both Rate::Flat and Rate::Percent have the same level of nesting which
 // makes it easier to work with on the frontend.
 pub struct PercentRate {
     pub percent: Decimal,
+    /// The minimum fee charged. A deducted fee is still capped at the payment it is taken from.
+    #[serde(default)]
+    pub min: Option<Uint128>,
+    /// The maximum fee charged.
+    #[serde(default)]
+    pub max: Option<Uint128>,
 }
 
 impl From<Decimal> for Rate {
     fn from(decimal: Decimal) -> Self {
-        Rate::Percent(PercentRate { percent: decimal })
+        Rate::Percent(PercentRate {
+            percent: decimal,
+            min: None,
+            max: None,
+        })
+    }
+}
+
+impl PercentRate {
+    fn validate(&self) -> Result<(), ContractError> {
+        ensure!(
+            !self.percent.is_zero() && self.percent <= Decimal::one(),
+            ContractError::InvalidRate {}
+        );
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            ensure!(min <= max, ContractError::InvalidRate {});
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TieredRate {
+    /// The tiers, sorted by ascending `min_amount`.
+    pub tiers: Vec<RateTier>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RateTier {
+    /// The smallest payment that this tier applies to.
+    pub min_amount: Uint128,
+    pub rate: PercentRate,
+}
+
+impl TieredRate {
+    /// Returns the index and rate of the tier that applies to `amount`, if any. This is the tier
+    /// with the largest `min_amount` that does not exceed `amount`.
+    pub fn get_tier(&self, amount: Uint128) -> Option<(usize, &PercentRate)> {
+        self.tiers
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, tier)| tier.min_amount <= amount)
+            .map(|(idx, tier)| (idx, &tier.rate))
+    }
+
+    fn validate(&self) -> Result<(), ContractError> {
+        ensure!(!self.tiers.is_empty(), ContractError::InvalidRate {});
+        ensure!(
+            self.tiers
+                .windows(2)
+                .all(|tiers| tiers[0].min_amount < tiers[1].min_amount),
+            ContractError::InvalidRate {}
+        );
+        for tier in self.tiers.iter() {
+            tier.rate.validate()?;
+        }
+        Ok(())
     }
 }
 
@@ -74,7 +237,10 @@ impl Rate {
     pub fn is_non_zero(&self) -> Result<bool, ContractError> {
         match self {
             Rate::Flat(coin) => Ok(!coin.amount.is_zero()),
-            Rate::Percent(PercentRate { percent }) => Ok(!percent.is_zero()),
+            Rate::Percent(PercentRate { percent, .. }) => Ok(!percent.is_zero()),
+            Rate::Tiered(TieredRate { tiers }) => {
+                Ok(tiers.iter().all(|tier| !tier.rate.percent.is_zero()))
+            }
             Rate::External(_) => Err(ContractError::UnexpectedExternalRate {}),
         }
     }
@@ -90,8 +256,10 @@ impl Rate {
         let rate = self.clone().get_rate(api, querier, app_contract)?;
         ensure!(rate.is_non_zero()?, ContractError::InvalidRate {});
 
-        if let Rate::Percent(PercentRate { percent }) = rate {
-            ensure!(percent <= Decimal::one(), ContractError::InvalidRate {});
+        match &rate {
+            Rate::Percent(percent_rate) => percent_rate.validate()?,
+            Rate::Tiered(tiered_rate) => tiered_rate.validate()?,
+            _ => {}
         }
 
         Ok(rate)
@@ -108,6 +276,7 @@ impl Rate {
         match self {
             Rate::Flat(_) => Ok(self),
             Rate::Percent(_) => Ok(self),
+            Rate::Tiered(_) => Ok(self),
             Rate::External(primitive_pointer) => {
                 let primitive = primitive_pointer.into_value(api, querier, app_contract)?;
                 match primitive {
@@ -151,35 +320,52 @@ impl ToString for PaymentAttribute {
 pub fn calculate_fee(fee_rate: Rate, payment: &Coin) -> Result<Coin, ContractError> {
     match fee_rate {
         Rate::Flat(rate) => Ok(Coin::new(rate.amount.u128(), rate.denom)),
-        Rate::Percent(PercentRate { percent }) => {
-            // [COM-03] Make sure that fee_rate between 0 and 100.
-            ensure!(
-                // No need for rate >=0 due to type limits (Question: Should add or remove?)
-                percent <= Decimal::one() && !percent.is_zero(),
-                ContractError::InvalidRate {}
-            );
-            let mut fee_amount = payment.amount * percent;
-
-            // Always round any remainder up and prioritise the fee receiver.
-            // Inverse of percent will always exist.
-            let reversed_fee = fee_amount * percent.inv().unwrap();
-            if payment.amount > reversed_fee {
-                // [COM-1] Added checked add to fee_amount rather than direct increment
-                fee_amount = fee_amount.checked_add(1u128.into())?;
-            }
-            Ok(Coin::new(fee_amount.u128(), payment.denom.clone()))
-        }
+        Rate::Percent(percent_rate) => calculate_percent_fee(&percent_rate, payment),
+        Rate::Tiered(tiered_rate) => match tiered_rate.get_tier(payment.amount) {
+            Some((_, percent_rate)) => calculate_percent_fee(percent_rate, payment),
+            None => Ok(Coin::new(0, payment.denom.clone())),
+        },
         Rate::External(_) => Err(ContractError::UnexpectedExternalRate {}),
     }
 }
 
+/// Calculates a percentage fee, bounded by the rate's minimum and maximum.
+fn calculate_percent_fee(
+    percent_rate: &PercentRate,
+    payment: &Coin,
+) -> Result<Coin, ContractError> {
+    let PercentRate { percent, min, max } = *percent_rate;
+    // [COM-03] Make sure that fee_rate between 0 and 100.
+    ensure!(
+        // No need for rate >=0 due to type limits (Question: Should add or remove?)
+        percent <= Decimal::one() && !percent.is_zero(),
+        ContractError::InvalidRate {}
+    );
+    let mut fee_amount = payment.amount * percent;
+
+    // Always round any remainder up and prioritise the fee receiver.
+    // Inverse of percent will always exist.
+    let reversed_fee = fee_amount * percent.inv().unwrap();
+    if payment.amount > reversed_fee {
+        // [COM-1] Added checked add to fee_amount rather than direct increment
+        fee_amount = fee_amount.checked_add(1u128.into())?;
+    }
+    if let Some(min) = min {
+        fee_amount = fee_amount.max(min);
+    }
+    if let Some(max) = max {
+        fee_amount = fee_amount.min(max);
+    }
+    Ok(Coin::new(fee_amount.u128(), payment.denom.clone()))
+}
+
 #[cfg(test)]
 mod tests {
     use andromeda_testing::testing::mock_querier::{
         mock_dependencies_custom, MOCK_PRIMITIVE_CONTRACT,
     };
     use common::app::AndrAddress;
-    use cosmwasm_std::{coin, Uint128};
+    use cosmwasm_std::{coin, testing::mock_env};
 
     use super::*;
 
@@ -234,4 +420,116 @@ mod tests {
 
         assert_eq!(expected, received);
     }
+
+    #[test]
+    fn test_calculate_fee_caps() {
+        let payment = coin(1000, "uluna");
+        let rate = |min, max| {
+            Rate::Percent(PercentRate {
+                percent: Decimal::percent(10),
+                min,
+                max,
+            })
+        };
+
+        let fee = calculate_fee(rate(None, Some(Uint128::new(50))), &payment).unwrap();
+        assert_eq!(coin(50, "uluna"), fee);
+
+        let fee = calculate_fee(rate(Some(Uint128::new(150)), None), &payment).unwrap();
+        assert_eq!(coin(150, "uluna"), fee);
+
+        let fee = calculate_fee(
+            rate(Some(Uint128::new(50)), Some(Uint128::new(150))),
+            &payment,
+        )
+        .unwrap();
+        assert_eq!(coin(100, "uluna"), fee);
+    }
+
+    #[test]
+    fn test_tiered_rate() {
+        let tier = |min_amount: u128, percent: u64| RateTier {
+            min_amount: Uint128::new(min_amount),
+            rate: PercentRate {
+                percent: Decimal::percent(percent),
+                min: None,
+                max: None,
+            },
+        };
+        let tiered_rate = TieredRate {
+            tiers: vec![tier(100, 10), tier(1000, 5)],
+        };
+
+        assert_eq!(None, tiered_rate.get_tier(Uint128::new(99)));
+        assert_eq!(0, tiered_rate.get_tier(Uint128::new(100)).unwrap().0);
+        assert_eq!(0, tiered_rate.get_tier(Uint128::new(999)).unwrap().0);
+        assert_eq!(1, tiered_rate.get_tier(Uint128::new(1000)).unwrap().0);
+
+        let rate = Rate::Tiered(tiered_rate);
+        assert_eq!(
+            coin(0, "uluna"),
+            calculate_fee(rate.clone(), &coin(50, "uluna")).unwrap()
+        );
+        assert_eq!(
+            coin(50, "uluna"),
+            calculate_fee(rate.clone(), &coin(500, "uluna")).unwrap()
+        );
+        assert_eq!(
+            coin(100, "uluna"),
+            calculate_fee(rate, &coin(2000, "uluna")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_validate_tiered_rate() {
+        let deps = mock_dependencies_custom(&[]);
+        let tier = |min_amount: u128, min: Option<u128>, max: Option<u128>| RateTier {
+            min_amount: Uint128::new(min_amount),
+            rate: PercentRate {
+                percent: Decimal::percent(10),
+                min: min.map(Uint128::new),
+                max: max.map(Uint128::new),
+            },
+        };
+        let validate = |tiers| {
+            Rate::Tiered(TieredRate { tiers }).validate(
+                deps.as_ref().api,
+                &deps.as_ref().querier,
+                None,
+            )
+        };
+
+        assert!(validate(vec![tier(0, None, None), tier(100, Some(1), Some(5))]).is_ok());
+        assert_eq!(ContractError::InvalidRate {}, validate(vec![]).unwrap_err());
+        assert_eq!(
+            ContractError::InvalidRate {},
+            validate(vec![tier(100, None, None), tier(100, None, None)]).unwrap_err()
+        );
+        assert_eq!(
+            ContractError::InvalidRate {},
+            validate(vec![tier(0, Some(5), Some(1))]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_rate_info_is_active() {
+        let block = mock_env().block;
+        let rate_info = |start, end| RateInfo {
+            rate: Rate::from(Decimal::percent(10)),
+            is_additive: false,
+            description: None,
+            recipients: vec![],
+            start,
+            end,
+            denoms: None,
+            exempt_senders: None,
+            exempt_address_list: None,
+        };
+
+        assert!(rate_info(None, None).is_active(&block));
+        assert!(rate_info(Some(Expiration::AtHeight(block.height)), None).is_active(&block));
+        assert!(!rate_info(Some(Expiration::AtHeight(block.height + 1)), None).is_active(&block));
+        assert!(rate_info(None, Some(Expiration::AtHeight(block.height + 1))).is_active(&block));
+        assert!(!rate_info(None, Some(Expiration::AtHeight(block.height))).is_active(&block));
+    }
 }
@@ -2,7 +2,7 @@ use common::{
     ado_base::{hooks::AndromedaHook, AndromedaMsg, AndromedaQuery},
     error::ContractError,
 };
-use cosmwasm_std::{to_binary, CosmosMsg, Event, SubMsg, Uint128, WasmMsg};
+use cosmwasm_std::{to_binary, CosmosMsg, Event, SubMsg, Timestamp, Uint128, WasmMsg};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -33,12 +33,52 @@ pub enum ExecuteMsg {
     /// Mint a new receipt. Only executable by the assigned `minter` address. Generates a receipt ID.
     StoreReceipt {
         receipt: Receipt,
+        /// The address whose message produced the receipt
+        #[serde(default)]
+        sender: Option<String>,
+        /// The hook that produced the receipt, if other than `OnFundsTransfer`. Such receipts are
+        /// only stored if the sending ADO enabled receipts for the hook.
+        #[serde(default)]
+        hook: Option<ReceiptHook>,
     },
     /// Edit a receipt by ID. Only executable by the assigned `minter` address or a valid `operator`.
     EditReceipt {
         receipt_id: Uint128,
         receipt: Receipt,
     },
+    /// Set the hooks for which receipts are recorded when the module is attached to `ado`.
+    /// Receipts are always recorded for `OnFundsTransfer`.
+    SetReceiptHooks {
+        ado: String,
+        hooks: ReceiptHooks,
+    },
+}
+
+/// A hook for which receipts may be recorded, other than `OnFundsTransfer`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiptHook {
+    OnExecute,
+    OnTransfer,
+}
+
+/// The hooks for which an ADO records receipts.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ReceiptHooks {
+    /// Record a receipt for every message executed on the ADO
+    pub on_execute: bool,
+    /// Record a receipt for every token transferred by the ADO
+    pub on_transfer: bool,
+}
+
+impl ReceiptHooks {
+    pub fn includes(&self, hook: ReceiptHook) -> bool {
+        match hook {
+            ReceiptHook::OnExecute => self.on_execute,
+            ReceiptHook::OnTransfer => self.on_transfer,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
@@ -53,8 +93,46 @@ pub enum QueryMsg {
     Receipt {
         receipt_id: Uint128,
     },
+    /// Query receipts in ascending order of ID. Receipts stored prior to version 0.2.0 are not
+    /// listed by this or the other receipt lists, but can still be queried by ID.
+    Receipts {
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+    /// Query the receipts produced by messages from `sender` in ascending order of ID.
+    ReceiptsBySender {
+        sender: String,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+    /// Query the receipts stored by `ado` in ascending order of ID.
+    ReceiptsByAdo {
+        ado: String,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+    /// Query the receipts stored between the given block heights, inclusive, in ascending order
+    /// of ID.
+    ReceiptsByHeight {
+        min_height: Option<u64>,
+        max_height: Option<u64>,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+    /// Query the receipts stored between the given block times, inclusive, in ascending order of
+    /// ID.
+    ReceiptsByTime {
+        min_time: Option<Timestamp>,
+        max_time: Option<Timestamp>,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
     /// The current contract config.
     ContractInfo {},
+    /// The hooks for which receipts are recorded for `ado`.
+    ReceiptHooks {
+        ado: String,
+    },
     AndrHook(AndromedaHook),
 }
 
@@ -69,15 +147,44 @@ pub struct ReceiptResponse {
     pub receipt: Receipt,
 }
 
+/// A stored receipt along with where and when it was stored.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReceiptInfo {
+    pub receipt_id: Uint128,
+    pub receipt: Receipt,
+    /// The address whose message produced the receipt, if known
+    pub sender: Option<String>,
+    /// The address that stored the receipt, i.e. the ADO whose hook produced it
+    pub ado: String,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ReceiptHooksResponse {
+    pub hooks: ReceiptHooks,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReceiptsResponse {
+    pub receipts: Vec<ReceiptInfo>,
+}
+
 pub fn generate_receipt_message(
     contract_addr: String,
+    sender: Option<String>,
     events: Vec<Event>,
+    hook: Option<ReceiptHook>,
 ) -> Result<SubMsg, ContractError> {
     let receipt = Receipt { events };
 
     Ok(SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr,
-        msg: to_binary(&ExecuteMsg::StoreReceipt { receipt })?,
+        msg: to_binary(&ExecuteMsg::StoreReceipt {
+            receipt,
+            sender,
+            hook,
+        })?,
         funds: vec![],
     })))
 }
@@ -2,6 +2,7 @@ use crate::cw721::TokenExtension;
 use common::{
     ado_base::{modules::Module, recipient::Recipient, AndromedaMsg, AndromedaQuery},
     app::AndrAddress,
+    merkle::MerkleProof,
 };
 use cosmwasm_std::{Coin, Uint128};
 use cw_utils::Expiration;
@@ -37,10 +38,16 @@ pub enum ExecuteMsg {
     /// Puchases tokens in an ongoing sale.
     Purchase {
         number_of_tokens: Option<u32>,
+        /// Passed on to the modules, for sales restricted to a merkle allowlist.
+        #[serde(default)]
+        merkle_proof: Option<MerkleProof>,
     },
     /// Purchases the token with the given id.
     PurchaseByTokenId {
         token_id: String,
+        /// Passed on to the modules, for sales restricted to a merkle allowlist.
+        #[serde(default)]
+        merkle_proof: Option<MerkleProof>,
     },
     /// Allow a user to claim their own refund if the minimum number of tokens are not sold.
     ClaimRefund {},
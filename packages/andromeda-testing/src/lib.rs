@@ -1,2 +1,3 @@
+pub mod multitest;
 pub mod reply;
 pub mod testing;
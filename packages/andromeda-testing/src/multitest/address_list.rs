@@ -0,0 +1,72 @@
+use crate::multitest::{AppResponse, Contract, Executor, MockAndromeda, MockApp};
+use andromeda_modules::address_list::{
+    ExecuteMsg, IncludesAddressResponse, InstantiateMsg, QueryMsg,
+};
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Empty};
+
+/// An address list module deployed in a [`MockApp`].
+pub struct MockAddressList(pub Addr);
+
+impl MockAddressList {
+    pub const ADO_TYPE: &'static str = "address_list";
+
+    /// Stores `code` and registers it in the factory as the address list module.
+    pub fn store_code(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        code: Box<dyn Contract<Empty>>,
+    ) -> AnyResult<u64> {
+        andr.store_code_id(app, Self::ADO_TYPE, code)
+    }
+
+    pub fn instantiate(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        sender: &Addr,
+        is_inclusive: bool,
+    ) -> AnyResult<MockAddressList> {
+        let msg = InstantiateMsg { is_inclusive };
+        let addr = andr.instantiate_ado(app, sender, Self::ADO_TYPE, &msg)?;
+        Ok(MockAddressList(addr))
+    }
+
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    pub fn execute_add_address(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        address: &str,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::AddAddress {
+            address: address.to_string(),
+        };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, &[])
+    }
+
+    pub fn execute_remove_address(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        address: &str,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::RemoveAddress {
+            address: address.to_string(),
+        };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, &[])
+    }
+
+    pub fn query_includes_address(&self, app: &MockApp, address: &str) -> AnyResult<bool> {
+        let res: IncludesAddressResponse = app.wrap().query_wasm_smart(
+            &self.0,
+            &QueryMsg::IncludesAddress {
+                address: address.to_string(),
+                tag: None,
+            },
+        )?;
+        Ok(res.included)
+    }
+}
@@ -0,0 +1,163 @@
+use crate::multitest::{Contract, Executor, MockApp};
+use andromeda_app::{
+    app::{AppComponent, InstantiateMsg as AppInstantiateMsg, QueryMsg as AppQueryMsg},
+    factory::{ExecuteMsg as FactoryExecuteMsg, InstantiateMsg as FactoryInstantiateMsg},
+};
+use andromeda_data_storage::primitive::{
+    ExecuteMsg as PrimitiveExecuteMsg, InstantiateMsg as PrimitiveInstantiateMsg,
+};
+use anyhow::Result as AnyResult;
+use common::{
+    ado_base::{AndromedaQuery, QueryMsg},
+    encode_binary,
+    primitive::Primitive,
+};
+use cosmwasm_std::{Addr, Empty};
+use serde::Serialize;
+
+/// The core Andromeda contracts deployed in a [`MockApp`]. The factory is registered under the
+/// primitive's `factory` key so that ADOs can resolve code IDs the same way they do on chain.
+pub struct MockAndromeda {
+    pub admin: Addr,
+    pub primitive: Addr,
+    pub factory: Addr,
+}
+
+impl MockAndromeda {
+    pub fn new(
+        app: &mut MockApp,
+        admin: &Addr,
+        primitive: Box<dyn Contract<Empty>>,
+        factory: Box<dyn Contract<Empty>>,
+    ) -> AnyResult<MockAndromeda> {
+        let primitive_code_id = app.store_code(primitive);
+        let primitive = app.instantiate_contract(
+            primitive_code_id,
+            admin.clone(),
+            &PrimitiveInstantiateMsg {},
+            &[],
+            "Primitive",
+            Some(admin.to_string()),
+        )?;
+
+        let factory_code_id = app.store_code(factory);
+        let factory = app.instantiate_contract(
+            factory_code_id,
+            admin.clone(),
+            &FactoryInstantiateMsg {},
+            &[],
+            "Factory",
+            Some(admin.to_string()),
+        )?;
+
+        app.execute_contract(
+            admin.clone(),
+            primitive.clone(),
+            &PrimitiveExecuteMsg::SetValue {
+                key: Some("factory".to_string()),
+                value: Primitive::String(factory.to_string()),
+            },
+            &[],
+        )?;
+
+        let andr = MockAndromeda {
+            admin: admin.clone(),
+            primitive,
+            factory,
+        };
+        andr.register_code_id(app, "primitive", primitive_code_id)?;
+        andr.register_code_id(app, "factory", factory_code_id)?;
+        Ok(andr)
+    }
+
+    /// Stores `code` and registers its code ID in the factory under `ado_type`.
+    pub fn store_code_id(
+        &self,
+        app: &mut MockApp,
+        ado_type: &str,
+        code: Box<dyn Contract<Empty>>,
+    ) -> AnyResult<u64> {
+        let code_id = app.store_code(code);
+        self.register_code_id(app, ado_type, code_id)?;
+        Ok(code_id)
+    }
+
+    fn register_code_id(&self, app: &mut MockApp, ado_type: &str, code_id: u64) -> AnyResult<()> {
+        app.execute_contract(
+            self.admin.clone(),
+            self.factory.clone(),
+            &FactoryExecuteMsg::UpdateCodeId {
+                code_id_key: ado_type.to_string(),
+                code_id,
+                version: None,
+            },
+            &[],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_code_id(&self, app: &MockApp, ado_type: &str) -> AnyResult<u64> {
+        Ok(app.wrap().query_wasm_smart(
+            &self.factory,
+            &QueryMsg::AndrQuery(AndromedaQuery::Get(Some(encode_binary(&ado_type)?))),
+        )?)
+    }
+
+    /// Instantiates the ADO stored under `ado_type` with `msg`, with `sender` as its admin.
+    pub fn instantiate_ado(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        ado_type: &str,
+        msg: &impl Serialize,
+    ) -> AnyResult<Addr> {
+        let code_id = self.get_code_id(app, ado_type)?;
+        app.instantiate_contract(
+            code_id,
+            sender.clone(),
+            msg,
+            &[],
+            ado_type,
+            Some(sender.to_string()),
+        )
+    }
+
+    /// Instantiates an app with the given components. The `app` ADO type must have been stored
+    /// with [`MockAndromeda::store_code_id`].
+    pub fn instantiate_app(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        name: &str,
+        components: Vec<AppComponent>,
+    ) -> AnyResult<Addr> {
+        let code_id = self.get_code_id(app, "app")?;
+        app.instantiate_contract(
+            code_id,
+            sender.clone(),
+            &AppInstantiateMsg {
+                app: components,
+                name: name.to_string(),
+                primitive_contract: self.primitive.to_string(),
+            },
+            &[],
+            name,
+            Some(sender.to_string()),
+        )
+    }
+
+    pub fn get_component_address(
+        &self,
+        app: &MockApp,
+        app_contract: &Addr,
+        name: &str,
+    ) -> AnyResult<Addr> {
+        let address: String = app.wrap().query_wasm_smart(
+            app_contract,
+            &AppQueryMsg::GetAddress {
+                name: name.to_string(),
+            },
+        )?;
+        Ok(Addr::unchecked(address))
+    }
+}
@@ -0,0 +1,95 @@
+use cosmwasm_std::{
+    testing::MockApi, Addr, Api, CanonicalAddr, RecoverPubkeyError, StdError, StdResult,
+    VerificationError,
+};
+
+/// The prefix of every account address that [`MockAppApi`] considers valid.
+pub const ADDRESS_PREFIX: &str = "andr1";
+/// The prefix of the contract addresses generated by `cw-multi-test`.
+const CONTRACT_PREFIX: &str = "contract";
+
+/// An [`Api`] that behaves like [`MockApi`] but only accepts addresses starting with
+/// [`ADDRESS_PREFIX`] and contract addresses, mirroring a bech32 chain. This lets ADO identifiers
+/// such as app component names fall back to being resolved through the app contract, as they
+/// would on chain.
+#[derive(Default)]
+pub struct MockAppApi {
+    api: MockApi,
+}
+
+impl MockAppApi {
+    /// Returns a valid address for the given account name.
+    pub fn addr_make(&self, name: &str) -> Addr {
+        Addr::unchecked(format!("{}{}", ADDRESS_PREFIX, name))
+    }
+
+    fn check_prefix(&self, human: &str) -> StdResult<()> {
+        if human.starts_with(ADDRESS_PREFIX) || human.starts_with(CONTRACT_PREFIX) {
+            Ok(())
+        } else {
+            Err(StdError::generic_err(format!(
+                "Invalid input: address must start with {}",
+                ADDRESS_PREFIX
+            )))
+        }
+    }
+}
+
+impl Api for MockAppApi {
+    fn addr_validate(&self, human: &str) -> StdResult<Addr> {
+        self.check_prefix(human)?;
+        self.api.addr_validate(human)
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> StdResult<CanonicalAddr> {
+        self.check_prefix(human)?;
+        self.api.addr_canonicalize(human)
+    }
+
+    fn addr_humanize(&self, canonical: &CanonicalAddr) -> StdResult<Addr> {
+        self.api.addr_humanize(canonical)
+    }
+
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.api
+            .secp256k1_verify(message_hash, signature, public_key)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        self.api
+            .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.api.ed25519_verify(message, signature, public_key)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> Result<bool, VerificationError> {
+        self.api
+            .ed25519_batch_verify(messages, signatures, public_keys)
+    }
+
+    fn debug(&self, message: &str) {
+        self.api.debug(message)
+    }
+}
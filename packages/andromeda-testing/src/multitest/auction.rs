@@ -0,0 +1,98 @@
+use crate::multitest::{AppResponse, Contract, Executor, MockAndromeda, MockApp};
+use andromeda_non_fungible_tokens::auction::{
+    AuctionStateResponse, Cw721HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg,
+};
+use anyhow::Result as AnyResult;
+use common::ado_base::modules::Module;
+use cosmwasm_std::{Addr, Coin, Empty};
+
+/// An auction ADO deployed in a [`MockApp`].
+pub struct MockAuction(pub Addr);
+
+impl MockAuction {
+    pub const ADO_TYPE: &'static str = "auction";
+
+    /// Stores `code` and registers it in the factory as the auction ADO.
+    pub fn store_code(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        code: Box<dyn Contract<Empty>>,
+    ) -> AnyResult<u64> {
+        andr.store_code_id(app, Self::ADO_TYPE, code)
+    }
+
+    pub fn instantiate(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        sender: &Addr,
+        modules: Option<Vec<Module>>,
+    ) -> AnyResult<MockAuction> {
+        let msg = InstantiateMsg { modules };
+        let addr = andr.instantiate_ado(app, sender, Self::ADO_TYPE, &msg)?;
+        Ok(MockAuction(addr))
+    }
+
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    /// The hook message to send a token to the auction with, starting an auction for it.
+    pub fn start_auction_msg(
+        start_time: u64,
+        duration: u64,
+        coin_denom: &str,
+        min_bid: Option<u128>,
+    ) -> Cw721HookMsg {
+        Cw721HookMsg::StartAuction {
+            start_time,
+            duration,
+            coin_denom: coin_denom.to_string(),
+            min_bid: min_bid.map(Into::into),
+            whitelist: None,
+        }
+    }
+
+    pub fn execute_place_bid(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        token_id: &str,
+        token_address: &Addr,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::PlaceBid {
+            token_id: token_id.to_string(),
+            token_address: token_address.to_string(),
+        };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, funds)
+    }
+
+    pub fn execute_claim(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        token_id: &str,
+        token_address: &Addr,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::Claim {
+            token_id: token_id.to_string(),
+            token_address: token_address.to_string(),
+        };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, &[])
+    }
+
+    pub fn query_latest_auction_state(
+        &self,
+        app: &MockApp,
+        token_id: &str,
+        token_address: &Addr,
+    ) -> AnyResult<AuctionStateResponse> {
+        Ok(app.wrap().query_wasm_smart(
+            &self.0,
+            &QueryMsg::LatestAuctionState {
+                token_id: token_id.to_string(),
+                token_address: token_address.to_string(),
+            },
+        )?)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::multitest::{AppResponse, Contract, Executor, MockAndromeda, MockApp};
+use andromeda_non_fungible_tokens::cw721::{
+    ExecuteMsg, InstantiateMsg, MintMsg, QueryMsg, TokenExtension, TransferAgreement,
+};
+use anyhow::Result as AnyResult;
+use common::{ado_base::modules::Module, app::AndrAddress, encode_binary};
+use cosmwasm_std::{Addr, Coin, Empty};
+use cw721::OwnerOfResponse;
+use serde::Serialize;
+
+/// A CW721 ADO deployed in a [`MockApp`].
+pub struct MockCw721(pub Addr);
+
+impl MockCw721 {
+    pub const ADO_TYPE: &'static str = "cw721";
+
+    /// Stores `code` and registers it in the factory as the CW721 ADO.
+    pub fn store_code(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        code: Box<dyn Contract<Empty>>,
+    ) -> AnyResult<u64> {
+        andr.store_code_id(app, Self::ADO_TYPE, code)
+    }
+
+    pub fn instantiate_msg(minter: &str, modules: Option<Vec<Module>>) -> InstantiateMsg {
+        InstantiateMsg {
+            name: "Test Tokens".to_string(),
+            symbol: "TT".to_string(),
+            minter: AndrAddress {
+                identifier: minter.to_string(),
+            },
+            modules,
+        }
+    }
+
+    pub fn instantiate(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        sender: &Addr,
+        minter: &str,
+        modules: Option<Vec<Module>>,
+    ) -> AnyResult<MockCw721> {
+        let msg = Self::instantiate_msg(minter, modules);
+        let addr = andr.instantiate_ado(app, sender, Self::ADO_TYPE, &msg)?;
+        Ok(MockCw721(addr))
+    }
+
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    /// Mints `token_id` to `owner` with a placeholder extension.
+    pub fn execute_mint(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        token_id: &str,
+        owner: &str,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::Mint(Box::new(MintMsg {
+            token_id: token_id.to_string(),
+            owner: owner.to_string(),
+            token_uri: None,
+            extension: TokenExtension {
+                name: token_id.to_string(),
+                publisher: sender.to_string(),
+                description: None,
+                attributes: vec![],
+                image: "".to_string(),
+                image_data: None,
+                external_url: None,
+                animation_url: None,
+                youtube_url: None,
+            },
+        }));
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, &[])
+    }
+
+    pub fn execute_transfer_nft(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        recipient: &str,
+        token_id: &str,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, funds)
+    }
+
+    /// Sends `token_id` to `contract` along with its cw721 receive hook `msg`.
+    pub fn execute_send_nft(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        contract: &Addr,
+        token_id: &str,
+        msg: &impl Serialize,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::SendNft {
+            contract: contract.to_string(),
+            token_id: token_id.to_string(),
+            msg: encode_binary(msg)?,
+        };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, &[])
+    }
+
+    pub fn execute_transfer_agreement(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        token_id: &str,
+        agreement: Option<TransferAgreement>,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::TransferAgreement {
+            token_id: token_id.to_string(),
+            agreement,
+        };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, &[])
+    }
+
+    pub fn query_owner_of(&self, app: &MockApp, token_id: &str) -> AnyResult<String> {
+        let res: OwnerOfResponse = app.wrap().query_wasm_smart(
+            &self.0,
+            &QueryMsg::OwnerOf {
+                token_id: token_id.to_string(),
+                include_expired: None,
+            },
+        )?;
+        Ok(res.owner)
+    }
+}
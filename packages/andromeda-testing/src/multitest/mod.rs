@@ -0,0 +1,37 @@
+//! Helpers for testing ADOs end to end with `cw-multi-test`. Contracts are stored from their entry
+//! points and messages, sub-messages, replies and queries are routed between them instead of being
+//! answered by mocked responses.
+mod address_list;
+mod andromeda;
+mod api;
+mod auction;
+mod cw721;
+mod rates;
+mod receipt;
+
+pub use address_list::MockAddressList;
+pub use andromeda::MockAndromeda;
+pub use api::{MockAppApi, ADDRESS_PREFIX};
+pub use auction::MockAuction;
+pub use cw721::MockCw721;
+pub use cw_multi_test::{AppResponse, Contract, ContractWrapper, Executor};
+pub use rates::MockRates;
+pub use receipt::MockReceipt;
+
+use cosmwasm_std::{Addr, Coin};
+use cw_multi_test::{App, AppBuilder, BankKeeper};
+
+/// A `cw-multi-test` [`App`] that validates addresses with [`MockAppApi`].
+pub type MockApp = App<BankKeeper, MockAppApi>;
+
+pub fn mock_app() -> MockApp {
+    AppBuilder::new()
+        .with_api(MockAppApi::default())
+        .build(|_, _, _| {})
+}
+
+/// Sets the native balance of `address`, replacing any existing balance.
+pub fn init_balance(app: &mut MockApp, address: &Addr, amount: Vec<Coin>) {
+    app.init_modules(|router, _, storage| router.bank.init_balance(storage, address, amount))
+        .unwrap();
+}
@@ -0,0 +1,52 @@
+use crate::multitest::{AppResponse, Contract, Executor, MockAndromeda, MockApp};
+use andromeda_modules::rates::{ExecuteMsg, InstantiateMsg, PaymentsResponse, QueryMsg, RateInfo};
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Empty};
+
+/// A rates module deployed in a [`MockApp`].
+pub struct MockRates(pub Addr);
+
+impl MockRates {
+    pub const ADO_TYPE: &'static str = "rates";
+
+    /// Stores `code` and registers it in the factory as the rates module.
+    pub fn store_code(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        code: Box<dyn Contract<Empty>>,
+    ) -> AnyResult<u64> {
+        andr.store_code_id(app, Self::ADO_TYPE, code)
+    }
+
+    pub fn instantiate(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        sender: &Addr,
+        rates: Vec<RateInfo>,
+    ) -> AnyResult<MockRates> {
+        let msg = InstantiateMsg { rates };
+        let addr = andr.instantiate_ado(app, sender, Self::ADO_TYPE, &msg)?;
+        Ok(MockRates(addr))
+    }
+
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    pub fn execute_update_rates(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        rates: Vec<RateInfo>,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::UpdateRates { rates };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, &[])
+    }
+
+    pub fn query_payments(&self, app: &MockApp) -> AnyResult<Vec<RateInfo>> {
+        let res: PaymentsResponse = app
+            .wrap()
+            .query_wasm_smart(&self.0, &QueryMsg::Payments {})?;
+        Ok(res.payments)
+    }
+}
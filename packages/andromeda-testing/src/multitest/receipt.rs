@@ -0,0 +1,66 @@
+use crate::multitest::{AppResponse, Contract, Executor, MockAndromeda, MockApp};
+use andromeda_modules::receipt::{
+    ExecuteMsg, InstantiateMsg, QueryMsg, ReceiptHooks, ReceiptInfo, ReceiptsResponse,
+};
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Empty};
+
+/// A receipt module deployed in a [`MockApp`].
+pub struct MockReceipt(pub Addr);
+
+impl MockReceipt {
+    pub const ADO_TYPE: &'static str = "receipt";
+
+    /// Stores `code` and registers it in the factory as the receipt module.
+    pub fn store_code(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        code: Box<dyn Contract<Empty>>,
+    ) -> AnyResult<u64> {
+        andr.store_code_id(app, Self::ADO_TYPE, code)
+    }
+
+    pub fn instantiate(
+        app: &mut MockApp,
+        andr: &MockAndromeda,
+        sender: &Addr,
+        minter: &str,
+    ) -> AnyResult<MockReceipt> {
+        let msg = InstantiateMsg {
+            minter: minter.to_string(),
+        };
+        let addr = andr.instantiate_ado(app, sender, Self::ADO_TYPE, &msg)?;
+        Ok(MockReceipt(addr))
+    }
+
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    pub fn execute_set_receipt_hooks(
+        &self,
+        app: &mut MockApp,
+        sender: &Addr,
+        ado: &Addr,
+        hooks: ReceiptHooks,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::SetReceiptHooks {
+            ado: ado.to_string(),
+            hooks,
+        };
+        app.execute_contract(sender.clone(), self.0.clone(), &msg, &[])
+    }
+
+    /// Queries the first page of receipts stored by `ado`.
+    pub fn query_receipts_by_ado(&self, app: &MockApp, ado: &Addr) -> AnyResult<Vec<ReceiptInfo>> {
+        let res: ReceiptsResponse = app.wrap().query_wasm_smart(
+            &self.0,
+            &QueryMsg::ReceiptsByAdo {
+                ado: ado.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+        Ok(res.receipts)
+    }
+}
@@ -1,6 +1,6 @@
 use common::{
     ado_base::{
-        hooks::{AndromedaHook, OnFundsTransferResponse},
+        hooks::{AndromedaHook, HookResponse, OnFundsTransferResponse},
         ownership::ContractOwnerResponse,
         AndromedaQuery, QueryMsg,
     },
@@ -21,9 +21,9 @@ use andromeda_non_fungible_tokens::{
 use cosmwasm_std::{
     coin, coins, from_binary, from_slice,
     testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR},
-    to_binary, BankMsg, Binary, Coin, ContractResult, CosmosMsg, Decimal, Event, OwnedDeps,
-    Querier, QuerierResult, QueryRequest, Response, SubMsg, SystemError, SystemResult, Uint128,
-    WasmMsg, WasmQuery,
+    to_binary, BankMsg, Binary, Coin, ContractInfoResponse, ContractResult, CosmosMsg, Decimal,
+    Event, OwnedDeps, Querier, QuerierResult, QueryRequest, Response, SubMsg, SystemError,
+    SystemResult, Uint128, WasmMsg, WasmQuery,
 };
 use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
 
@@ -117,6 +117,11 @@ impl WasmMockQuerier {
                     }
                 }
             }
+            QueryRequest::Wasm(WasmQuery::ContractInfo { .. }) => {
+                // Every mock contract runs the code ID the factory holds for "receipt".
+                let res = ContractInfoResponse::new(1, "creator");
+                SystemResult::Ok(ContractResult::Ok(to_binary(&res).unwrap()))
+            }
             _ => self.base.handle_query(request),
         }
     }
@@ -212,7 +217,10 @@ impl WasmMockQuerier {
                             })
                             .unwrap(),
                         }));
-                        let resp = Response::new().add_submessage(msg);
+                        let resp = HookResponse {
+                            msgs: vec![msg],
+                            events: vec![],
+                        };
                         return SystemResult::Ok(ContractResult::Ok(to_binary(&resp).unwrap()));
                     }
                     panic!("Unsupported Query")
@@ -237,9 +245,9 @@ impl WasmMockQuerier {
     fn handle_addresslist_query(&self, msg: &Binary) -> QuerierResult {
         match from_binary(msg).unwrap() {
             AddressListQueryMsg::AndrHook(hook_msg) => match hook_msg {
-                AndromedaHook::OnExecute { sender, payload: _ } => {
+                AndromedaHook::OnExecute { sender, .. } => {
                     let whitelisted_addresses = ["sender", "minter", "purchaser", "creator"];
-                    let response: Response = Response::default();
+                    let response = HookResponse::default();
                     if whitelisted_addresses.contains(&sender.as_str()) {
                         SystemResult::Ok(ContractResult::Ok(to_binary(&response).unwrap()))
                     } else {
@@ -256,13 +264,18 @@ impl WasmMockQuerier {
         match from_binary(msg).unwrap() {
             ReceiptQueryMsg::AndrHook(hook_msg) => match hook_msg {
                 AndromedaHook::OnFundsTransfer {
-                    sender: _,
+                    sender,
                     payload,
                     amount,
                 } => {
                     let events: Vec<Event> = from_binary(&payload).unwrap();
-                    let receipt_msg =
-                        generate_receipt_message(MOCK_RECEIPT_CONTRACT.into(), events).unwrap();
+                    let receipt_msg = generate_receipt_message(
+                        MOCK_RECEIPT_CONTRACT.into(),
+                        Some(sender),
+                        events,
+                        None,
+                    )
+                    .unwrap();
                     let response = OnFundsTransferResponse {
                         msgs: vec![receipt_msg],
                         events: vec![],
@@ -0,0 +1,890 @@
+use andromeda_app::{
+    app::{
+        AppComponent, AppTemplate, ComponentVersion, ExecuteMsg as AppExecuteMsg,
+        QueryMsg as AppQueryMsg, TemplateComponent,
+    },
+    factory::{DeploymentsResponse, ExecuteMsg as FactoryExecuteMsg, QueryMsg as FactoryQueryMsg},
+};
+use andromeda_modules::{
+    address_list::{
+        ExecuteMsg as AddressListExecuteMsg, InstantiateMsg as AddressListInstantiateMsg,
+    },
+    merkle_allowlist::{InstantiateMsg as MerkleAllowlistInstantiateMsg, MerkleProof},
+    rates::{InstantiateMsg as RatesInstantiateMsg, PercentRate, Rate, RateInfo},
+    receipt::ReceiptHooks,
+};
+use andromeda_non_fungible_tokens::{
+    crowdfund::{
+        CrowdfundMintMsg, ExecuteMsg as CrowdfundExecuteMsg,
+        InstantiateMsg as CrowdfundInstantiateMsg, QueryMsg as CrowdfundQueryMsg,
+    },
+    cw721::{TokenExtension, TransferAgreement},
+};
+use andromeda_testing::multitest::{
+    init_balance, mock_app, ContractWrapper, Executor, MockAddressList, MockAndromeda, MockApp,
+    MockAuction, MockCw721, MockRates, MockReceipt,
+};
+use common::{
+    ado_base::{
+        hooks::SimulateFundsTransferResponse,
+        modules::{Module, ADDRESS_LIST, MERKLE_ALLOWLIST, RATES, RECEIPT},
+        ownership::{ContractOwnerResponse, PendingOwnerResponse},
+        recipient::Recipient,
+        AndromedaMsg, AndromedaQuery, ExecuteMsg as BaseExecuteMsg, QueryMsg,
+    },
+    app::AndrAddress,
+    encode_binary,
+    error::ContractError,
+    primitive::Value,
+    Funds,
+};
+use cosmwasm_std::{coin, Addr, Decimal, DepsMut, Empty, Env, Event, Response, StdResult, Uint128};
+use cw20::Expiration;
+use sha2::{Digest, Sha256};
+
+const OWNER: &str = "andr1owner";
+const SELLER: &str = "andr1seller";
+const BUYER: &str = "andr1buyer";
+const FEE_RECIPIENT: &str = "andr1fee_recipient";
+const DENOM: &str = "uandr";
+
+fn mock_andromeda(app: &mut MockApp) -> MockAndromeda {
+    let andr = MockAndromeda::new(
+        app,
+        &Addr::unchecked(OWNER),
+        Box::new(ContractWrapper::new(
+            andromeda_primitive::contract::execute,
+            andromeda_primitive::contract::instantiate,
+            andromeda_primitive::contract::query,
+        )),
+        Box::new(
+            ContractWrapper::new(
+                andromeda_factory::contract::execute,
+                andromeda_factory::contract::instantiate,
+                andromeda_factory::contract::query,
+            )
+            .with_reply(andromeda_factory::contract::reply),
+        ),
+    )
+    .unwrap();
+
+    andr.store_code_id(
+        app,
+        "app",
+        Box::new(
+            ContractWrapper::new(
+                andromeda_app_contract::contract::execute,
+                andromeda_app_contract::contract::instantiate,
+                andromeda_app_contract::contract::query,
+            )
+            .with_reply(andromeda_app_contract::contract::reply),
+        ),
+    )
+    .unwrap();
+    MockCw721::store_code(
+        app,
+        &andr,
+        Box::new(ContractWrapper::new(
+            andromeda_cw721::contract::execute,
+            andromeda_cw721::contract::instantiate,
+            andromeda_cw721::contract::query,
+        )),
+    )
+    .unwrap();
+    MockRates::store_code(
+        app,
+        &andr,
+        Box::new(ContractWrapper::new(
+            andromeda_rates::contract::execute,
+            andromeda_rates::contract::instantiate,
+            andromeda_rates::contract::query,
+        )),
+    )
+    .unwrap();
+    MockAddressList::store_code(
+        app,
+        &andr,
+        Box::new(ContractWrapper::new(
+            andromeda_address_list::contract::execute,
+            andromeda_address_list::contract::instantiate,
+            andromeda_address_list::contract::query,
+        )),
+    )
+    .unwrap();
+    MockReceipt::store_code(
+        app,
+        &andr,
+        Box::new(ContractWrapper::new(
+            andromeda_receipt::contract::execute,
+            andromeda_receipt::contract::instantiate,
+            andromeda_receipt::contract::query,
+        )),
+    )
+    .unwrap();
+    MockAuction::store_code(
+        app,
+        &andr,
+        Box::new(ContractWrapper::new(
+            andromeda_auction::contract::execute,
+            andromeda_auction::contract::instantiate,
+            andromeda_auction::contract::query,
+        )),
+    )
+    .unwrap();
+    andr.store_code_id(
+        app,
+        "crowdfund",
+        Box::new(
+            ContractWrapper::new(
+                andromeda_crowdfund::contract::execute,
+                andromeda_crowdfund::contract::instantiate,
+                andromeda_crowdfund::contract::query,
+            )
+            .with_reply(andromeda_crowdfund::contract::reply),
+        ),
+    )
+    .unwrap();
+    andr.store_code_id(
+        app,
+        "merkle_allowlist",
+        Box::new(ContractWrapper::new(
+            andromeda_merkle_allowlist::contract::execute,
+            andromeda_merkle_allowlist::contract::instantiate,
+            andromeda_merkle_allowlist::contract::query,
+        )),
+    )
+    .unwrap();
+
+    andr
+}
+
+fn rates_component() -> AppComponent {
+    AppComponent {
+        name: "rates".to_string(),
+        ado_type: "rates".to_string(),
+        instantiate_msg: encode_binary(&RatesInstantiateMsg {
+            rates: vec![RateInfo {
+                rate: Rate::Percent(PercentRate {
+                    percent: Decimal::percent(10),
+                    min: None,
+                    max: None,
+                }),
+                is_additive: true,
+                description: None,
+                recipients: vec![Recipient::Addr(FEE_RECIPIENT.to_string())],
+                start: None,
+                end: None,
+                denoms: None,
+                exempt_senders: None,
+                exempt_address_list: None,
+            }],
+        })
+        .unwrap(),
+    }
+}
+
+fn whitelist_component() -> AppComponent {
+    AppComponent {
+        name: "whitelist".to_string(),
+        ado_type: "address_list".to_string(),
+        instantiate_msg: encode_binary(&AddressListInstantiateMsg { is_inclusive: true }).unwrap(),
+    }
+}
+
+fn cw721_component(modules: Vec<Module>) -> AppComponent {
+    AppComponent {
+        name: "tokens".to_string(),
+        ado_type: "cw721".to_string(),
+        instantiate_msg: encode_binary(&MockCw721::instantiate_msg(OWNER, Some(modules))).unwrap(),
+    }
+}
+
+fn module(module_type: &str, name: &str) -> Module {
+    Module {
+        module_type: module_type.to_string(),
+        address: AndrAddress {
+            identifier: name.to_string(),
+        },
+        is_mutable: false,
+        priority: None,
+    }
+}
+
+fn query_balance(app: &MockApp, address: &Addr) -> u128 {
+    app.wrap()
+        .query_balance(address, DENOM)
+        .unwrap()
+        .amount
+        .u128()
+}
+
+#[test]
+fn test_app_instantiates_components() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    assert_eq!(3, andr.get_code_id(&app, "app").unwrap());
+
+    let app_contract = andr
+        .instantiate_app(
+            &mut app,
+            &Addr::unchecked(OWNER),
+            "Test App",
+            vec![
+                rates_component(),
+                cw721_component(vec![module(RATES, "rates")]),
+            ],
+        )
+        .unwrap();
+
+    let cw721 = andr
+        .get_component_address(&app, &app_contract, "tokens")
+        .unwrap();
+    let rates = andr
+        .get_component_address(&app, &app_contract, "rates")
+        .unwrap();
+    assert_ne!(cw721, rates);
+
+    // Components are owned by the app until ownership is claimed.
+    let res: ContractOwnerResponse = app
+        .wrap()
+        .query_wasm_smart(&cw721, &QueryMsg::AndrQuery(AndromedaQuery::Owner {}))
+        .unwrap();
+    assert_eq!(app_contract.to_string(), res.owner);
+}
+
+#[test]
+fn test_factory_create_proposes_creator_as_owner() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    let creator = Addr::unchecked(SELLER);
+
+    let cw721 = cw721_component(vec![]);
+    app.execute_contract(
+        creator.clone(),
+        andr.factory.clone(),
+        &FactoryExecuteMsg::Create {
+            code_id_key: cw721.ado_type,
+            instantiate_msg: cw721.instantiate_msg,
+        },
+        &[],
+    )
+    .unwrap();
+    let res: DeploymentsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &andr.factory,
+            &FactoryQueryMsg::DeploymentsByCreator {
+                creator: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let cw721 = Addr::unchecked(&res.deployments[0].address);
+
+    // The factory instantiated the token so owns it until the creator accepts ownership.
+    let query_owner = |app: &MockApp| -> String {
+        let res: ContractOwnerResponse = app
+            .wrap()
+            .query_wasm_smart(&cw721, &QueryMsg::AndrQuery(AndromedaQuery::Owner {}))
+            .unwrap();
+        res.owner
+    };
+    assert_eq!(andr.factory.to_string(), query_owner(&app));
+    let res: PendingOwnerResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &cw721,
+            &QueryMsg::AndrQuery(AndromedaQuery::PendingOwner {}),
+        )
+        .unwrap();
+    assert_eq!(Some(creator.to_string()), res.pending_owner);
+
+    app.execute_contract(
+        creator.clone(),
+        cw721.clone(),
+        &BaseExecuteMsg::AndrReceive(AndromedaMsg::AcceptOwnership {}),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(creator.to_string(), query_owner(&app));
+}
+
+#[test]
+fn test_factory_create_app_proposes_creator_as_owner() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    let creator = Addr::unchecked(SELLER);
+
+    let cw721 = cw721_component(vec![]);
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        andr.factory.clone(),
+        &FactoryExecuteMsg::AddAppTemplate {
+            name: "nft".to_string(),
+            template: AppTemplate {
+                parameters: vec![],
+                components: vec![TemplateComponent {
+                    name: cw721.name,
+                    ado_type: cw721.ado_type,
+                    instantiate_msg: String::from_utf8(cw721.instantiate_msg.to_vec()).unwrap(),
+                }],
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        creator.clone(),
+        andr.factory.clone(),
+        &FactoryExecuteMsg::CreateApp {
+            template: "nft".to_string(),
+            name: "Test App".to_string(),
+            primitive_contract: andr.primitive.to_string(),
+            parameters: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let res: DeploymentsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &andr.factory,
+            &FactoryQueryMsg::DeploymentsByCreator {
+                creator: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let app_contract = Addr::unchecked(&res.deployments[0].address);
+    assert!(andr
+        .get_component_address(&app, &app_contract, "tokens")
+        .is_ok());
+
+    let res: PendingOwnerResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &app_contract,
+            &QueryMsg::AndrQuery(AndromedaQuery::PendingOwner {}),
+        )
+        .unwrap();
+    assert_eq!(Some(creator.to_string()), res.pending_owner);
+
+    app.execute_contract(
+        creator.clone(),
+        app_contract.clone(),
+        &BaseExecuteMsg::AndrReceive(AndromedaMsg::AcceptOwnership {}),
+        &[],
+    )
+    .unwrap();
+    let res: ContractOwnerResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &app_contract,
+            &QueryMsg::AndrQuery(AndromedaQuery::Owner {}),
+        )
+        .unwrap();
+    assert_eq!(creator.to_string(), res.owner);
+}
+
+fn noop_migrate(_deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+#[test]
+fn test_upgrade_pinned_component() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    let admin = Addr::unchecked(OWNER);
+
+    let mut code_ids = vec![];
+    for version in ["1.0.0", "2.0.0", "3.0.0"] {
+        let code_id = app.store_code(Box::new(
+            ContractWrapper::new(
+                andromeda_cw721::contract::execute,
+                andromeda_cw721::contract::instantiate,
+                andromeda_cw721::contract::query,
+            )
+            .with_migrate(noop_migrate),
+        ));
+        app.execute_contract(
+            admin.clone(),
+            andr.factory.clone(),
+            &FactoryExecuteMsg::UpdateCodeId {
+                code_id_key: "cw721".to_string(),
+                code_id,
+                version: Some(version.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+        code_ids.push(code_id);
+    }
+
+    let mut cw721 = cw721_component(vec![]);
+    cw721.ado_type = "cw721@1.0.0".to_string();
+    let app_contract = andr
+        .instantiate_app(&mut app, &admin, "Test App", vec![cw721])
+        .unwrap();
+    let query_versions = |app: &MockApp| -> Vec<ComponentVersion> {
+        app.wrap()
+            .query_wasm_smart(&app_contract, &AppQueryMsg::ComponentVersions {})
+            .unwrap()
+    };
+    let query_ado_type = |app: &MockApp| -> String {
+        let components: Vec<AppComponent> = app
+            .wrap()
+            .query_wasm_smart(&app_contract, &AppQueryMsg::GetComponents {})
+            .unwrap();
+        components[0].ado_type.clone()
+    };
+    // The latest version ignores the pinned version.
+    assert_eq!(
+        vec![ComponentVersion {
+            name: "tokens".to_string(),
+            ado_type: "cw721@1.0.0".to_string(),
+            deployed_code_id: Some(code_ids[0]),
+            latest_code_id: Some(code_ids[2]),
+        }],
+        query_versions(&app)
+    );
+
+    app.execute_contract(
+        admin.clone(),
+        app_contract.clone(),
+        &AppExecuteMsg::UpgradeComponent {
+            name: "tokens".to_string(),
+            code_id_key: Some("cw721@2.0.0".to_string()),
+            migrate_msg: encode_binary(&Empty {}).unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!("cw721@2.0.0", query_ado_type(&app));
+
+    // Deprecating the pinned version does not break the versions query.
+    app.execute_contract(
+        admin.clone(),
+        andr.factory.clone(),
+        &FactoryExecuteMsg::SetCodeIdDeprecated {
+            code_id_key: "cw721".to_string(),
+            version: "2.0.0".to_string(),
+            deprecated: true,
+        },
+        &[],
+    )
+    .unwrap();
+    let versions = query_versions(&app);
+    assert_eq!(Some(code_ids[1]), versions[0].deployed_code_id);
+    assert_eq!(Some(code_ids[2]), versions[0].latest_code_id);
+
+    app.execute_contract(
+        admin,
+        app_contract.clone(),
+        &AppExecuteMsg::UpgradeAll {
+            migrate_msg: encode_binary(&Empty {}).unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!("cw721", query_ado_type(&app));
+    let versions = query_versions(&app);
+    assert_eq!(Some(code_ids[2]), versions[0].deployed_code_id);
+    assert_eq!(versions[0].latest_code_id, versions[0].deployed_code_id);
+}
+
+#[test]
+fn test_cw721_transfer_agreement_with_rates() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    let app_contract = andr
+        .instantiate_app(
+            &mut app,
+            &Addr::unchecked(OWNER),
+            "Test App",
+            vec![
+                rates_component(),
+                cw721_component(vec![module(RATES, "rates")]),
+            ],
+        )
+        .unwrap();
+    let cw721 = MockCw721(
+        andr.get_component_address(&app, &app_contract, "tokens")
+            .unwrap(),
+    );
+
+    // Frontends can show the final price before the purchase.
+    let res: SimulateFundsTransferResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw721.addr(),
+            &QueryMsg::AndrQuery(AndromedaQuery::SimulateFundsTransfer {
+                sender: BUYER.to_string(),
+                amount: Funds::Native(coin(100, DENOM)),
+            }),
+        )
+        .unwrap();
+    assert_eq!(Uint128::new(10), res.total_tax);
+    assert_eq!(Funds::Native(coin(100, DENOM)), res.leftover_funds);
+
+    let owner = Addr::unchecked(OWNER);
+    let seller = Addr::unchecked(SELLER);
+    cw721.execute_mint(&mut app, &owner, "1", SELLER).unwrap();
+    cw721
+        .execute_transfer_agreement(
+            &mut app,
+            &seller,
+            "1",
+            Some(TransferAgreement {
+                amount: Value::Raw(coin(100, DENOM)),
+                purchaser: BUYER.to_string(),
+            }),
+        )
+        .unwrap();
+
+    let buyer = Addr::unchecked(BUYER);
+    init_balance(&mut app, &buyer, vec![coin(110, DENOM)]);
+
+    // The 10% tax is additive so the agreed amount alone is not enough.
+    let err = cw721
+        .execute_transfer_nft(&mut app, &buyer, BUYER, "1", &[coin(100, DENOM)])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InsufficientFunds {},
+        err.downcast::<ContractError>().unwrap()
+    );
+    assert_eq!(110, query_balance(&app, &buyer));
+
+    cw721
+        .execute_transfer_nft(&mut app, &buyer, BUYER, "1", &[coin(110, DENOM)])
+        .unwrap();
+
+    assert_eq!(BUYER, cw721.query_owner_of(&app, "1").unwrap());
+    assert_eq!(0, query_balance(&app, &buyer));
+    assert_eq!(100, query_balance(&app, &seller));
+    assert_eq!(10, query_balance(&app, &Addr::unchecked(FEE_RECIPIENT)));
+    assert_eq!(0, query_balance(&app, cw721.addr()));
+}
+
+#[test]
+fn test_cw721_address_list_module() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    let owner = Addr::unchecked(OWNER);
+    let app_contract = andr
+        .instantiate_app(
+            &mut app,
+            &owner,
+            "Test App",
+            vec![
+                whitelist_component(),
+                cw721_component(vec![module(ADDRESS_LIST, "whitelist")]),
+            ],
+        )
+        .unwrap();
+    let cw721 = MockCw721(
+        andr.get_component_address(&app, &app_contract, "tokens")
+            .unwrap(),
+    );
+
+    // The address list is owned by the app, so it is updated through a proxied message.
+    app.execute_contract(
+        owner.clone(),
+        app_contract.clone(),
+        &AppExecuteMsg::ProxyMessage {
+            name: "whitelist".to_string(),
+            msg: encode_binary(&AddressListExecuteMsg::AddAddress {
+                address: OWNER.to_string(),
+            })
+            .unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    let whitelist = MockAddressList(
+        andr.get_component_address(&app, &app_contract, "whitelist")
+            .unwrap(),
+    );
+    assert!(whitelist.query_includes_address(&app, OWNER).unwrap());
+    assert!(!whitelist.query_includes_address(&app, SELLER).unwrap());
+
+    cw721.execute_mint(&mut app, &owner, "1", SELLER).unwrap();
+
+    let err = cw721
+        .execute_transfer_nft(&mut app, &Addr::unchecked(SELLER), BUYER, "1", &[])
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("Unauthorized"));
+    assert_eq!(SELLER, cw721.query_owner_of(&app, "1").unwrap());
+}
+
+#[test]
+fn test_cw721_receipt_module_round_trip() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    let owner = Addr::unchecked(OWNER);
+    let receipt = MockReceipt::instantiate(&mut app, &andr, &owner, OWNER).unwrap();
+    let cw721 = MockCw721::instantiate(
+        &mut app,
+        &andr,
+        &owner,
+        OWNER,
+        Some(vec![module(RECEIPT, receipt.addr().as_str())]),
+    )
+    .unwrap();
+    cw721.execute_mint(&mut app, &owner, "1", SELLER).unwrap();
+    assert!(receipt
+        .query_receipts_by_ado(&app, cw721.addr())
+        .unwrap()
+        .is_empty());
+
+    // The token queries the module's transfer hook, which returns a message storing a receipt
+    // that the token then sends back to the module.
+    receipt
+        .execute_set_receipt_hooks(
+            &mut app,
+            &owner,
+            cw721.addr(),
+            ReceiptHooks {
+                on_execute: false,
+                on_transfer: true,
+            },
+        )
+        .unwrap();
+    cw721
+        .execute_transfer_nft(&mut app, &Addr::unchecked(SELLER), BUYER, "1", &[])
+        .unwrap();
+
+    let receipts = receipt.query_receipts_by_ado(&app, cw721.addr()).unwrap();
+    assert_eq!(1, receipts.len());
+    assert_eq!(Some(SELLER.to_string()), receipts[0].sender);
+    assert_eq!(
+        vec![Event::new("transfer")
+            .add_attribute("token_id", "1")
+            .add_attribute("recipient", BUYER)],
+        receipts[0].receipt.events
+    );
+    assert_eq!(BUYER, cw721.query_owner_of(&app, "1").unwrap());
+}
+
+#[test]
+fn test_auction_rates_module() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    let owner = Addr::unchecked(OWNER);
+    let seller = Addr::unchecked(SELLER);
+    let buyer = Addr::unchecked(BUYER);
+    let rates = MockRates::instantiate(
+        &mut app,
+        &andr,
+        &owner,
+        vec![RateInfo {
+            rate: Rate::from(Decimal::percent(10)),
+            is_additive: false,
+            description: None,
+            recipients: vec![Recipient::Addr(FEE_RECIPIENT.to_string())],
+            start: None,
+            end: None,
+            denoms: None,
+            exempt_senders: None,
+            exempt_address_list: None,
+        }],
+    )
+    .unwrap();
+    let auction = MockAuction::instantiate(
+        &mut app,
+        &andr,
+        &owner,
+        Some(vec![module(RATES, rates.addr().as_str())]),
+    )
+    .unwrap();
+    let cw721 = MockCw721::instantiate(&mut app, &andr, &owner, OWNER, None).unwrap();
+    cw721.execute_mint(&mut app, &owner, "1", SELLER).unwrap();
+
+    let start_time = app.block_info().time.plus_seconds(1).nanos() / 1_000_000;
+    cw721
+        .execute_send_nft(
+            &mut app,
+            &seller,
+            auction.addr(),
+            "1",
+            &MockAuction::start_auction_msg(start_time, 10_000, DENOM, None),
+        )
+        .unwrap();
+    assert_eq!(
+        auction.addr().to_string(),
+        cw721.query_owner_of(&app, "1").unwrap()
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(2));
+    init_balance(&mut app, &buyer, vec![coin(100, DENOM)]);
+    auction
+        .execute_place_bid(&mut app, &buyer, "1", cw721.addr(), &[coin(100, DENOM)])
+        .unwrap();
+    let state = auction
+        .query_latest_auction_state(&app, "1", cw721.addr())
+        .unwrap();
+    assert_eq!(BUYER, state.high_bidder_addr);
+
+    // The royalty is deducted from the winning bid when the token is claimed.
+    app.update_block(|block| block.time = block.time.plus_seconds(10));
+    auction
+        .execute_claim(&mut app, &seller, "1", cw721.addr())
+        .unwrap();
+    assert_eq!(BUYER, cw721.query_owner_of(&app, "1").unwrap());
+    assert_eq!(90, query_balance(&app, &seller));
+    assert_eq!(10, query_balance(&app, &Addr::unchecked(FEE_RECIPIENT)));
+    assert_eq!(0, query_balance(&app, auction.addr()));
+}
+
+#[test]
+fn test_crowdfund_merkle_allowlist_module() {
+    let mut app = mock_app();
+    let andr = mock_andromeda(&mut app);
+    let owner = Addr::unchecked(OWNER);
+    let buyer = Addr::unchecked(BUYER);
+    let mallory = Addr::unchecked("andr1mallory");
+
+    // A tree of the buyer and one other address, whose leaves are the hashes of the addresses.
+    let buyer_leaf = Sha256::digest(BUYER.as_bytes());
+    let other_leaf = Sha256::digest(SELLER.as_bytes());
+    let mut leaves = [buyer_leaf.as_slice(), other_leaf.as_slice()];
+    leaves.sort_unstable();
+    let merkle_root = hex::encode(Sha256::digest(&leaves.concat()));
+    let merkle_proof = MerkleProof {
+        proof: vec![hex::encode(other_leaf)],
+        allocation: None,
+    };
+
+    let app_contract = andr
+        .instantiate_app(
+            &mut app,
+            &owner,
+            "Test App",
+            vec![
+                AppComponent {
+                    name: "allowlist".to_string(),
+                    ado_type: "merkle_allowlist".to_string(),
+                    instantiate_msg: encode_binary(&MerkleAllowlistInstantiateMsg {
+                        merkle_root,
+                        restricted_messages: Some(vec![
+                            "purchase".to_string(),
+                            "purchase_by_token_id".to_string(),
+                        ]),
+                        callers: vec![],
+                    })
+                    .unwrap(),
+                },
+                AppComponent {
+                    name: "tokens".to_string(),
+                    ado_type: "cw721".to_string(),
+                    instantiate_msg: encode_binary(&MockCw721::instantiate_msg("crowdfund", None))
+                        .unwrap(),
+                },
+                AppComponent {
+                    name: "crowdfund".to_string(),
+                    ado_type: "crowdfund".to_string(),
+                    instantiate_msg: encode_binary(&CrowdfundInstantiateMsg {
+                        token_address: AndrAddress {
+                            identifier: "tokens".to_string(),
+                        },
+                        can_mint_after_sale: false,
+                        modules: Some(vec![module(MERKLE_ALLOWLIST, "allowlist")]),
+                    })
+                    .unwrap(),
+                },
+            ],
+        )
+        .unwrap();
+    let crowdfund = andr
+        .get_component_address(&app, &app_contract, "crowdfund")
+        .unwrap();
+
+    // The crowdfund is owned by the app, so the sale is set up through proxied messages.
+    let mint_msg = |token_id: &str| CrowdfundMintMsg {
+        token_id: token_id.to_string(),
+        owner: None,
+        token_uri: None,
+        extension: TokenExtension {
+            name: token_id.to_string(),
+            publisher: OWNER.to_string(),
+            description: None,
+            attributes: vec![],
+            image: "".to_string(),
+            image_data: None,
+            external_url: None,
+            animation_url: None,
+            youtube_url: None,
+        },
+    };
+    let expiration = Expiration::AtHeight(app.block_info().height + 100);
+    for msg in [
+        CrowdfundExecuteMsg::Mint(vec![mint_msg("1"), mint_msg("2")]),
+        CrowdfundExecuteMsg::StartSale {
+            expiration,
+            price: coin(100, DENOM),
+            min_tokens_sold: Uint128::new(1),
+            max_amount_per_wallet: None,
+            recipient: Recipient::Addr(SELLER.to_string()),
+        },
+    ] {
+        app.execute_contract(
+            owner.clone(),
+            app_contract.clone(),
+            &AppExecuteMsg::ProxyMessage {
+                name: "crowdfund".to_string(),
+                msg: encode_binary(&msg).unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    init_balance(&mut app, &buyer, vec![coin(100, DENOM)]);
+    init_balance(&mut app, &mallory, vec![coin(100, DENOM)]);
+    let purchase = |merkle_proof: Option<MerkleProof>| CrowdfundExecuteMsg::Purchase {
+        number_of_tokens: Some(1),
+        merkle_proof,
+    };
+
+    let err = app
+        .execute_contract(
+            buyer.clone(),
+            crowdfund.clone(),
+            &purchase(None),
+            &[coin(100, DENOM)],
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("Unauthorized"));
+
+    // The proof only holds for the address it was made for.
+    let err = app
+        .execute_contract(
+            mallory.clone(),
+            crowdfund.clone(),
+            &purchase(Some(merkle_proof.clone())),
+            &[coin(100, DENOM)],
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("Verification Failed"));
+
+    app.execute_contract(
+        buyer.clone(),
+        crowdfund.clone(),
+        &purchase(Some(merkle_proof)),
+        &[coin(100, DENOM)],
+    )
+    .unwrap();
+    assert_eq!(0, query_balance(&app, &buyer));
+    assert_eq!(100, query_balance(&app, &mallory));
+    let available: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            &crowdfund,
+            &CrowdfundQueryMsg::AvailableTokens {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(vec!["2".to_string()], available);
+}
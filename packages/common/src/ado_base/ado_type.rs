@@ -6,3 +6,28 @@ use serde::{Deserialize, Serialize};
 pub struct TypeResponse {
     pub ado_type: String,
 }
+
+/// Separates the ADO type from the version in a pinned code ID key such as `cw721@0.1.0`.
+pub const VERSION_SEPARATOR: char = '@';
+
+/// Splits a code ID key into its ADO type and, if the key is pinned, its version.
+pub fn split_versioned_ado_type(code_id_key: &str) -> (&str, Option<&str>) {
+    match code_id_key.split_once(VERSION_SEPARATOR) {
+        Some((ado_type, version)) => (ado_type, Some(version)),
+        None => (code_id_key, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_versioned_ado_type() {
+        assert_eq!(("cw721", None), split_versioned_ado_type("cw721"));
+        assert_eq!(
+            ("cw721", Some("0.1.0")),
+            split_versioned_ado_type("cw721@0.1.0")
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use crate::Funds;
-use cosmwasm_std::{Binary, Event, SubMsg};
+use crate::{merkle::MerkleProof, Funds};
+use cosmwasm_std::{Binary, Event, SubMsg, Uint128, Uint64};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +9,10 @@ pub enum AndromedaHook {
     OnExecute {
         sender: String,
         payload: Binary,
+        /// The proof given with the executed message, for modules that restrict it to a merkle
+        /// allowlist
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        merkle_proof: Option<MerkleProof>,
     },
     OnFundsTransfer {
         sender: String,
@@ -30,9 +34,82 @@ pub struct OnFundsTransferResponse {
     pub leftover_funds: Funds,
 }
 
+/// What a single module takes from a funds transfer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ModuleFundsTransfer {
+    pub module_idx: Uint64,
+    pub module_type: String,
+    pub address: String,
+    pub msgs: Vec<SubMsg>,
+    pub events: Vec<Event>,
+    /// The funds passed on to the next module.
+    pub leftover_funds: Funds,
+}
+
+/// A dry run of `on_funds_transfer`, listing each module that handled the funds in the order in
+/// which they were run.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FundsTransferBreakdownResponse {
+    pub modules: Vec<ModuleFundsTransfer>,
+}
+
+/// The result of transferring funds through every module, without executing anything.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SimulateFundsTransferResponse {
+    pub payments: Vec<SubMsg>,
+    pub events: Vec<Event>,
+    pub leftover_funds: Funds,
+    /// The amount to be paid on top of the transferred amount.
+    pub total_tax: Uint128,
+}
+
+/// The response of a module to an `OnExecute` or `OnTransfer` hook. The calling ADO appends the
+/// sub-messages and events to its own response. Modules that still return a serialized
+/// `Response` are accepted as well, in which case its messages and events are used.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct HookResponse {
+    #[serde(default, alias = "messages")]
+    pub msgs: Vec<SubMsg>,
+    #[serde(default)]
+    pub events: Vec<Event>,
+}
+
 /// Helper enum for serialization
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum HookMsg {
     AndrHook(AndromedaHook),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{from_binary, to_binary, BankMsg, Empty, Response};
+
+    #[test]
+    fn test_hook_response_from_response() {
+        let msg = SubMsg::new(BankMsg::Burn { amount: vec![] });
+        let event = Event::new("hook");
+        let response: Response<Empty> = Response::new()
+            .add_submessage(msg.clone())
+            .add_event(event.clone())
+            .add_attribute("action", "hook");
+
+        let hook_response: HookResponse = from_binary(&to_binary(&response).unwrap()).unwrap();
+        assert_eq!(
+            HookResponse {
+                msgs: vec![msg],
+                events: vec![event],
+            },
+            hook_response
+        );
+
+        let empty: Response<Empty> = Response::new();
+        let hook_response: HookResponse = from_binary(&to_binary(&empty).unwrap()).unwrap();
+        assert_eq!(HookResponse::default(), hook_response);
+    }
+}
@@ -0,0 +1,18 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MigrationRecord {
+    pub from_version: String,
+    pub to_version: String,
+    /// The versions of the state migration steps that were run, in order.
+    pub steps: Vec<String>,
+    pub block_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MigrationHistoryResponse {
+    pub migrations: Vec<MigrationRecord>,
+}
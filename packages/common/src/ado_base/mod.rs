@@ -1,16 +1,20 @@
 pub mod ado_type;
 pub mod block_height;
 pub mod hooks;
+pub mod migration;
 pub mod modules;
 pub mod operators;
 pub mod ownership;
+pub mod pause;
 pub mod recipient;
+pub mod roles;
 pub mod version;
 
 use crate::{
     ado_base::{modules::Module, recipient::Recipient},
     error::ContractError,
     withdraw::Withdrawal,
+    Funds,
 };
 use cosmwasm_std::{to_binary, Binary, QuerierWrapper, QueryRequest, Uint64, WasmQuery};
 use schemars::JsonSchema;
@@ -30,9 +34,22 @@ pub struct InstantiateMsg {
 pub enum AndromedaMsg {
     /// Standard Messages
     Receive(Option<Binary>),
-    UpdateOwner {
+    /// Executes `msg` as if it was sent by `origin`. Only accepted from the app contract, which
+    /// vouches for the original sender of the messages it proxies.
+    ProxyReceive {
+        origin: String,
+        msg: Binary,
+    },
+    /// Proposes `address` as the new contract owner. The proposal takes effect once the proposed
+    /// owner sends `AcceptOwnership` before `expiration` (in milliseconds since the epoch).
+    ProposeOwnership {
         address: String,
+        expiration: Option<u64>,
     },
+    AcceptOwnership {},
+    CancelOwnershipProposal {},
+    /// Permanently removes the contract owner. **This cannot be undone.**
+    RenounceOwnership {},
     UpdateOperators {
         operators: Vec<String>,
     },
@@ -53,6 +70,11 @@ pub enum AndromedaMsg {
         module_idx: Uint64,
         module: Module,
     },
+    /// Sets the order in which modules are run. `module_idxs` must contain every registered
+    /// module exactly once.
+    ReorderModules {
+        module_idxs: Vec<Uint64>,
+    },
     RefreshAddress {
         contract: String,
     },
@@ -60,6 +82,26 @@ pub enum AndromedaMsg {
         limit: Option<u32>,
         start_after: Option<String>,
     },
+    /// Grants `role` to `address`. **Only executable by the contract owner.**
+    GrantRole {
+        role: String,
+        address: String,
+    },
+    /// Revokes `role` from `address`. **Only executable by the contract owner.**
+    RevokeRole {
+        role: String,
+        address: String,
+    },
+    /// Pauses the given messages, referred to by their serialized names (e.g. `place_bid`), or
+    /// every user message if none are provided. Andromeda messages and the administrative
+    /// messages registered by the ADO are not affected by pausing everything.
+    Pause {
+        messages: Option<Vec<String>>,
+    },
+    /// Unpauses the given messages, or everything if none are provided.
+    Unpause {
+        messages: Option<Vec<String>>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug)]
@@ -67,14 +109,49 @@ pub enum AndromedaMsg {
 pub enum AndromedaQuery {
     Get(Option<Binary>),
     Owner {},
+    PendingOwner {},
     Operators {},
     Type {},
     OriginalPublisher {},
     BlockHeightUponCreation {},
-    IsOperator { address: String },
-    Module { id: Uint64 },
+    IsOperator {
+        address: String,
+    },
+    Module {
+        id: Uint64,
+    },
     ModuleIds {},
+    /// Runs the `OnFundsTransfer` hook of every module without executing anything and returns
+    /// what each module would take from `amount`.
+    FundsTransferBreakdown {
+        sender: String,
+        amount: Funds,
+    },
+    /// Runs `on_funds_transfer` for `amount` without executing anything and returns the
+    /// resulting payments and the tax owed on top of `amount`.
+    SimulateFundsTransfer {
+        sender: String,
+        amount: Funds,
+    },
     Version {},
+    /// Lists every role with at least one holder, or the roles held by `address` if provided, in
+    /// ascending order.
+    Roles {
+        address: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    RoleHolders {
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    HasRole {
+        role: String,
+        address: String,
+    },
+    Paused {},
+    MigrationHistory {},
 }
 
 /// Helper enum for serialization
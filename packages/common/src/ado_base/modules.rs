@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 pub const RATES: &str = "rates";
 pub const BIDS: &str = "bids";
 pub const ADDRESS_LIST: &str = "address_list";
+pub const MERKLE_ALLOWLIST: &str = "merkle_allowlist";
 pub const AUCTION: &str = "auction";
 pub const RECEIPT: &str = "receipt";
 pub const OTHER: &str = "other";
@@ -17,6 +18,11 @@ pub struct Module {
     pub module_type: String,
     pub address: AndrAddress,
     pub is_mutable: bool,
+    /// Modules are run by ascending priority. Modules without a priority are run after those with
+    /// one, in the order in which they were registered. On funds transfers, modules of equal
+    /// priority that deduct funds are run before those that do not.
+    #[serde(default)]
+    pub priority: Option<u64>,
 }
 
 impl Module {
@@ -72,6 +78,7 @@ mod tests {
                 identifier: "".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         let res = addresslist_module.validate(
@@ -86,6 +93,7 @@ mod tests {
                 identifier: "".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
         addresslist_module
             .validate(&[addresslist_module.clone(), auction_module], "cw721")
@@ -100,6 +108,7 @@ mod tests {
                 identifier: "".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         let res = module.validate(&[module.clone(), module.clone()], "cw721");
@@ -119,6 +128,7 @@ mod tests {
                 identifier: "".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
         module
             .validate(&[module.clone(), other_module], "cw721")
@@ -133,6 +143,7 @@ mod tests {
                 identifier: "".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         module
@@ -145,6 +156,7 @@ mod tests {
                 identifier: "".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
         module
             .validate(&[module.clone(), other_module], "cw721")
@@ -159,6 +171,7 @@ mod tests {
                 identifier: "".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         let res = module.validate(&[module.clone(), module.clone()], "cw721");
@@ -170,6 +183,7 @@ mod tests {
                 identifier: "".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
         module
             .validate(&[module.clone(), other_module], "cw721")
@@ -184,6 +198,7 @@ mod tests {
                 identifier: "addr1".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         let module2 = Module {
@@ -192,6 +207,7 @@ mod tests {
                 identifier: "addr2".to_string(),
             },
             is_mutable: false,
+            priority: None,
         };
 
         let res = module1.validate(&[module1.clone(), module2], "cw721");
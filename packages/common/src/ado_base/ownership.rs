@@ -1,3 +1,4 @@
+use cw_utils::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -12,3 +13,10 @@ pub struct ContractOwnerResponse {
 pub struct PublisherResponse {
     pub original_publisher: String,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingOwnerResponse {
+    pub pending_owner: Option<String>,
+    pub expiration: Option<Expiration>,
+}
@@ -0,0 +1,11 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PausedResponse {
+    /// Whether every user message is paused.
+    pub paused: bool,
+    /// The individually paused messages, e.g. `place_bid`.
+    pub messages: Vec<String>,
+}
@@ -0,0 +1,26 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const MINTER: &str = "minter";
+pub const PAUSER: &str = "pauser";
+pub const RATE_ADMIN: &str = "rate_admin";
+pub const WITHDRAWER: &str = "withdrawer";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct RolesResponse {
+    pub roles: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct RoleHoldersResponse {
+    pub role: String,
+    pub holders: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct HasRoleResponse {
+    pub has_role: bool,
+}
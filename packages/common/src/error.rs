@@ -53,6 +53,9 @@ pub enum ContractError {
     #[error("InvalidPrimitive")]
     InvalidPrimitive {},
 
+    #[error("Invalid primitive type for key {key}, expected {expected}")]
+    InvalidPrimitiveType { key: String, expected: String },
+
     #[error("IncorrectAmount: {msg}")]
     IncorrectAmount { msg: String },
 
@@ -128,6 +131,9 @@ pub enum ContractError {
     #[error("InvalidRate")]
     InvalidRate {},
 
+    #[error("FlatRateDenomNotIncluded: {denom}")]
+    FlatRateDenomNotIncluded { denom: String },
+
     #[error("InsufficientFunds")]
     InsufficientFunds {},
 
@@ -304,6 +310,9 @@ pub enum ContractError {
     #[error("ModuleDoesNotExist")]
     ModuleDoesNotExist {},
 
+    #[error("InvalidModuleOrder: every registered module must be listed exactly once")]
+    InvalidModuleOrder {},
+
     #[error("token_id already claimed")]
     Claimed {},
 
@@ -403,6 +412,9 @@ pub enum ContractError {
     #[error("Verification Failed")]
     VerificationFailed {},
 
+    #[error("Allocation exhausted")]
+    AllocationExhausted {},
+
     #[error("Invalid Asset: {asset}")]
     InvalidAsset { asset: String },
 
@@ -463,6 +475,9 @@ pub enum ContractError {
     #[error("Invalid component: {name}")]
     InvalidComponent { name: String },
 
+    #[error("Component {name} is referenced by {dependents}")]
+    ComponentReferenced { name: String, dependents: String },
+
     #[error("Multi-batch not supported")]
     MultiBatchNotSupported {},
 
@@ -474,6 +489,45 @@ pub enum ContractError {
 
     #[error("Invalid Expiration Time")]
     InvalidExpirationTime {},
+
+    #[error("Invalid role: {role}")]
+    InvalidRole { role: String },
+
+    #[error("No ownership proposal")]
+    NoOwnershipProposal {},
+
+    #[error("Ownership proposal expired")]
+    OwnershipProposalExpired {},
+
+    #[error("Paused: {msg}")]
+    Paused { msg: String },
+
+    #[error("Invalid migration: {msg}")]
+    InvalidMigration { msg: String },
+
+    #[error("Invalid version: {version}")]
+    InvalidVersion { version: String },
+
+    #[error("Version {version} of {code_id_key} is already registered")]
+    CodeIdVersionExists {
+        code_id_key: String,
+        version: String,
+    },
+
+    #[error("Missing template parameter: {name}")]
+    MissingTemplateParameter { name: String },
+
+    #[error("Unknown template parameter: {name}")]
+    UnknownTemplateParameter { name: String },
+
+    #[error("Version {version} of {code_id_key} is deprecated")]
+    CodeIdDeprecated {
+        code_id_key: String,
+        version: String,
+    },
+
+    #[error("{code_id_key} has registered versions so a version must be provided")]
+    CodeIdVersionRequired { code_id_key: String },
 }
 
 impl From<Cw20ContractError> for ContractError {
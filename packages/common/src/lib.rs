@@ -2,6 +2,7 @@ pub mod ado_base;
 pub mod app;
 pub mod error;
 pub mod expiration;
+pub mod merkle;
 pub mod primitive;
 pub mod rates;
 pub mod response;
@@ -13,7 +14,7 @@ use crate::error::ContractError;
 use ado_base::{AndromedaQuery, QueryMsg};
 use cosmwasm_std::{
     ensure, from_binary, to_binary, BankMsg, Binary, Coin, CosmosMsg, QuerierWrapper, QueryRequest,
-    SubMsg, WasmQuery,
+    SubMsg, Uint128, WasmQuery,
 };
 use cw20::Cw20Coin;
 use schemars::JsonSchema;
@@ -62,6 +63,32 @@ pub fn unwrap_or_err<T>(val_opt: &Option<T>, err: ContractError) -> Result<&T, C
     }
 }
 
+/// Extracts the variant name of a serialized execute message, e.g. `place_bid` for
+/// `{"place_bid":{...}}` or `claim` for `"claim"`.
+///
+/// ## Arguments
+/// * `msg` - The JSON encoded message
+///
+/// Returns the name of the outermost enum variant.
+pub fn get_message_name(msg: &Binary) -> Result<String, ContractError> {
+    let parsing_error = || ContractError::ParsingError {
+        err: "Unable to determine message name".to_string(),
+    };
+    let json = std::str::from_utf8(msg.as_slice()).map_err(|_| parsing_error())?;
+    let json = json.trim_start();
+    // Struct and tuple variants are serialized as a map with a single key.
+    let json = match json.strip_prefix('{') {
+        Some(inner) => inner.trim_start(),
+        None => json,
+    };
+    let name = json
+        .strip_prefix('"')
+        .and_then(|rest| rest.split('"').next())
+        .ok_or_else(parsing_error)?;
+    ensure!(!name.is_empty(), parsing_error());
+    Ok(name.to_string())
+}
+
 pub fn query_primitive<T>(
     querier: QuerierWrapper,
     contract_address: String,
@@ -84,7 +111,18 @@ pub enum Funds {
     Cw20(Cw20Coin),
 }
 
+// `Cw20Coin` does not derive `Eq` but its equality is total.
+impl Eq for Funds {}
+
 impl Funds {
+    /// Returns the amount of either kind of funds.
+    pub fn amount(&self) -> Uint128 {
+        match self {
+            Funds::Native(coin) => coin.amount,
+            Funds::Cw20(cw20_coin) => cw20_coin.amount,
+        }
+    }
+
     // There is probably a more idiomatic way of doing this with From and Into...
     pub fn try_get_coin(&self) -> Result<Coin, ContractError> {
         match self {
@@ -212,6 +250,31 @@ mod test {
         assert!(parse_struct::<TestStruct>(&invalid_json).is_err())
     }
 
+    #[test]
+    fn test_get_message_name() {
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case")]
+        enum TestMsg {
+            PlaceBid { token_id: String },
+            Receive(Option<Binary>),
+            Claim,
+        }
+
+        let msg = to_binary(&TestMsg::PlaceBid {
+            token_id: "\"token\"".to_string(),
+        })
+        .unwrap();
+        assert_eq!("place_bid", get_message_name(&msg).unwrap());
+
+        let msg = to_binary(&TestMsg::Receive(None)).unwrap();
+        assert_eq!("receive", get_message_name(&msg).unwrap());
+
+        let msg = to_binary(&TestMsg::Claim).unwrap();
+        assert_eq!("claim", get_message_name(&msg).unwrap());
+
+        assert!(get_message_name(&to_binary(&1u64).unwrap()).is_err());
+    }
+
     #[test]
     fn test_merge_coins() {
         let mut coins = vec![coin(100, "uusd"), coin(100, "uluna")];
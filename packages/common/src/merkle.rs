@@ -0,0 +1,56 @@
+use cosmwasm_std::{ensure, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
+use std::convert::TryInto;
+
+use crate::error::ContractError;
+
+/// A proof that an address is included in a merkle allowlist. ADOs whose messages accept a proof
+/// pass it on to their modules in the `OnExecute` hook.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MerkleProof {
+    /// The hex encoded hashes of the leaf's siblings, starting from the bottom of the tree
+    pub proof: Vec<String>,
+    /// The number of restricted messages the sender may execute, if their leaf has one
+    pub allocation: Option<Uint128>,
+}
+
+/// Checks that `merkle_root` is a hex encoded sha256 hash.
+pub fn validate_merkle_root(merkle_root: &str) -> Result<(), ContractError> {
+    let mut root_buf: [u8; 32] = [0; 32];
+    hex::decode_to_slice(merkle_root, &mut root_buf)?;
+    Ok(())
+}
+
+/// Verifies that `leaf` is included in the merkle tree with the given root. The leaves of the
+/// tree are the sha256 hashes of their contents and each pair of nodes is sorted before being
+/// hashed together.
+///
+/// ## Arguments
+/// * `leaf` - The contents of the leaf, e.g. an address followed by its allocation
+/// * `proof` - The hex encoded hashes of the leaf's siblings, starting from the bottom of the tree
+/// * `merkle_root` - The hex encoded root of the tree
+pub fn verify_proof(leaf: &str, proof: &[String], merkle_root: &str) -> Result<(), ContractError> {
+    let hash = sha2::Sha256::digest(leaf.as_bytes())
+        .as_slice()
+        .try_into()
+        .map_err(|_| ContractError::WrongLength {})?;
+
+    let hash = proof.iter().try_fold(hash, |hash, p| {
+        let mut proof_buf = [0; 32];
+        hex::decode_to_slice(p, &mut proof_buf)?;
+        let mut hashes = [hash, proof_buf];
+        hashes.sort_unstable();
+        sha2::Sha256::digest(&hashes.concat())
+            .as_slice()
+            .try_into()
+            .map_err(|_| ContractError::WrongLength {})
+    })?;
+
+    let mut root_buf: [u8; 32] = [0; 32];
+    hex::decode_to_slice(merkle_root, &mut root_buf)?;
+    ensure!(root_buf == hash, ContractError::VerificationFailed {});
+    Ok(())
+}
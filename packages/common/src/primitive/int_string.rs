@@ -0,0 +1,47 @@
+//! (De)serializes the integer of a `Primitive::Int64` or `Primitive::Int128` as a string, as JSON
+//! numbers cannot hold every 64 and 128 bit integer and the contract JSON serializer already
+//! quotes 128 bit ones.
+
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: fmt::Display,
+    S: Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(IntVisitor(PhantomData))
+}
+
+struct IntVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for IntVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string-encoded integer")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
@@ -1,11 +1,19 @@
-use cosmwasm_std::{Binary, Coin, Decimal, StdError, Uint128};
+use crate::error::ContractError;
+use cosmwasm_std::{Addr, Api, Binary, Coin, Decimal, StdError, Timestamp, Uint128};
+use cw_utils::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 pub use value::{PrimitivePointer, Value};
 
+mod int_string;
+mod object;
 mod value;
 
+/// The maximum number of nested vectors and objects in a `Primitive`, counting the outermost one.
+pub const MAX_PRIMITIVE_DEPTH: usize = 4;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Primitive {
@@ -16,6 +24,48 @@ pub enum Primitive {
     Bool(bool),
     Vec(Vec<Primitive>),
     Binary(Binary),
+    /// Named values, sent as a list of `[key, value]` pairs as maps are not supported by the
+    /// contract JSON serializer.
+    Object(
+        #[serde(with = "object")]
+        #[schemars(with = "Vec<(String, Primitive)>")]
+        BTreeMap<String, Primitive>,
+    ),
+    /// An address, which is validated when stored in a primitive contract.
+    Addr(Addr),
+    Timestamp(Timestamp),
+    Expiration(Expiration),
+    /// A signed integer, sent as a string like `Uint64`.
+    Int64(
+        #[serde(with = "int_string")]
+        #[schemars(with = "String")]
+        i64,
+    ),
+    /// A signed integer, sent as a string like `Uint128`.
+    Int128(
+        #[serde(with = "int_string")]
+        #[schemars(with = "String")]
+        i128,
+    ),
+}
+
+/// The type of a `Primitive`, used to restrict the values that can be stored under a key.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PrimitiveType {
+    Uint128,
+    Decimal,
+    Coin,
+    String,
+    Bool,
+    Vec,
+    Binary,
+    Object,
+    Addr,
+    Timestamp,
+    Expiration,
+    Int64,
+    Int128,
 }
 
 fn parse_error(type_name: String) -> StdError {
@@ -67,19 +117,112 @@ impl From<Binary> for Primitive {
     }
 }
 
+impl From<BTreeMap<String, Primitive>> for Primitive {
+    fn from(value: BTreeMap<String, Primitive>) -> Self {
+        Primitive::Object(value)
+    }
+}
+
+impl From<Addr> for Primitive {
+    fn from(value: Addr) -> Self {
+        Primitive::Addr(value)
+    }
+}
+
+impl From<Timestamp> for Primitive {
+    fn from(value: Timestamp) -> Self {
+        Primitive::Timestamp(value)
+    }
+}
+
+impl From<Expiration> for Primitive {
+    fn from(value: Expiration) -> Self {
+        Primitive::Expiration(value)
+    }
+}
+
+impl From<i64> for Primitive {
+    fn from(value: i64) -> Self {
+        Primitive::Int64(value)
+    }
+}
+
+impl From<i128> for Primitive {
+    fn from(value: i128) -> Self {
+        Primitive::Int128(value)
+    }
+}
+
 // These are methods to help the calling user quickly retreive the data in the Primitive as they
 // often already know what the type should be.
 impl Primitive {
     pub fn is_invalid(&self) -> bool {
+        self.is_invalid_at_depth(1)
+    }
+
+    fn is_invalid_at_depth(&self, depth: usize) -> bool {
         match self {
             // Avoid infinite recursion problem by not allowing nested vectors.
-            Primitive::Vec(vector) => vector
-                .iter()
-                .any(|p| matches!(p, Primitive::Vec(_)) || p.is_invalid()),
+            Primitive::Vec(vector) => {
+                depth > MAX_PRIMITIVE_DEPTH
+                    || vector
+                        .iter()
+                        .any(|p| matches!(p, Primitive::Vec(_)) || p.is_invalid_at_depth(depth + 1))
+            }
+            Primitive::Object(object) => {
+                depth > MAX_PRIMITIVE_DEPTH
+                    || object.values().any(|p| p.is_invalid_at_depth(depth + 1))
+            }
             _ => false,
         }
     }
 
+    /// Ensures the primitive is valid and that any addresses it holds are valid.
+    pub fn validate(&self, api: &dyn Api) -> Result<(), ContractError> {
+        if self.is_invalid() {
+            return Err(ContractError::InvalidPrimitive {});
+        }
+        self.validate_addresses(api)
+    }
+
+    fn validate_addresses(&self, api: &dyn Api) -> Result<(), ContractError> {
+        match self {
+            Primitive::Addr(addr) => {
+                api.addr_validate(addr.as_str())?;
+            }
+            Primitive::Vec(vector) => {
+                for p in vector {
+                    p.validate_addresses(api)?;
+                }
+            }
+            Primitive::Object(object) => {
+                for p in object.values() {
+                    p.validate_addresses(api)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn primitive_type(&self) -> PrimitiveType {
+        match self {
+            Primitive::Uint128(_) => PrimitiveType::Uint128,
+            Primitive::Decimal(_) => PrimitiveType::Decimal,
+            Primitive::Coin(_) => PrimitiveType::Coin,
+            Primitive::String(_) => PrimitiveType::String,
+            Primitive::Bool(_) => PrimitiveType::Bool,
+            Primitive::Vec(_) => PrimitiveType::Vec,
+            Primitive::Binary(_) => PrimitiveType::Binary,
+            Primitive::Object(_) => PrimitiveType::Object,
+            Primitive::Addr(_) => PrimitiveType::Addr,
+            Primitive::Timestamp(_) => PrimitiveType::Timestamp,
+            Primitive::Expiration(_) => PrimitiveType::Expiration,
+            Primitive::Int64(_) => PrimitiveType::Int64,
+            Primitive::Int128(_) => PrimitiveType::Int128,
+        }
+    }
+
     pub fn try_get_uint128(&self) -> Result<Uint128, StdError> {
         match self {
             Primitive::Uint128(value) => Ok(*value),
@@ -128,6 +271,48 @@ impl Primitive {
             _ => Err(parse_error(String::from("Binary"))),
         }
     }
+
+    pub fn try_get_object(&self) -> Result<BTreeMap<String, Primitive>, StdError> {
+        match self {
+            Primitive::Object(object) => Ok(object.clone()),
+            _ => Err(parse_error(String::from("Object"))),
+        }
+    }
+
+    pub fn try_get_addr(&self) -> Result<Addr, StdError> {
+        match self {
+            Primitive::Addr(addr) => Ok(addr.clone()),
+            _ => Err(parse_error(String::from("Addr"))),
+        }
+    }
+
+    pub fn try_get_timestamp(&self) -> Result<Timestamp, StdError> {
+        match self {
+            Primitive::Timestamp(value) => Ok(*value),
+            _ => Err(parse_error(String::from("Timestamp"))),
+        }
+    }
+
+    pub fn try_get_expiration(&self) -> Result<Expiration, StdError> {
+        match self {
+            Primitive::Expiration(value) => Ok(*value),
+            _ => Err(parse_error(String::from("Expiration"))),
+        }
+    }
+
+    pub fn try_get_int64(&self) -> Result<i64, StdError> {
+        match self {
+            Primitive::Int64(value) => Ok(*value),
+            _ => Err(parse_error(String::from("Int64"))),
+        }
+    }
+
+    pub fn try_get_int128(&self) -> Result<i128, StdError> {
+        match self {
+            Primitive::Int128(value) => Ok(*value),
+            _ => Err(parse_error(String::from("Int128"))),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -139,7 +324,7 @@ pub struct GetValueResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::to_binary;
+    use cosmwasm_std::{from_binary, testing::mock_dependencies, to_binary};
 
     #[test]
     fn test_parse_error() {
@@ -230,6 +415,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn primitive_type() {
+        assert_eq!(
+            PrimitiveType::Coin,
+            Primitive::Coin(Coin::new(1, "uusd")).primitive_type()
+        );
+        assert_eq!(
+            PrimitiveType::Decimal,
+            Primitive::Decimal(Decimal::one()).primitive_type()
+        );
+    }
+
     #[test]
     fn is_valid() {
         let valid_primitive = Primitive::Bool(true);
@@ -250,5 +447,124 @@ mod tests {
 
         let invalid_primitive = Primitive::Vec(vec![Primitive::Bool(true), Primitive::Vec(vec![])]);
         assert!(invalid_primitive.is_invalid());
+
+        let mut object = Primitive::Vec(vec![]);
+        for _ in 1..MAX_PRIMITIVE_DEPTH {
+            object = Primitive::Object(BTreeMap::from([("nested".to_string(), object)]));
+        }
+        assert!(!object.is_invalid());
+        let invalid_primitive = Primitive::Object(BTreeMap::from([("nested".to_string(), object)]));
+        assert!(invalid_primitive.is_invalid());
+    }
+
+    #[test]
+    fn validate() {
+        let deps = mock_dependencies();
+        let primitive = Primitive::Object(BTreeMap::from([(
+            "recipients".to_string(),
+            Primitive::Vec(vec![Primitive::Addr(Addr::unchecked("recipient"))]),
+        )]));
+        assert!(primitive.validate(deps.as_ref().api).is_ok());
+
+        let primitive = Primitive::Object(BTreeMap::from([(
+            "recipients".to_string(),
+            Primitive::Vec(vec![Primitive::Addr(Addr::unchecked("x"))]),
+        )]));
+        assert!(primitive.validate(deps.as_ref().api).is_err());
+
+        let primitive = Primitive::Vec(vec![Primitive::Vec(vec![])]);
+        assert_eq!(
+            ContractError::InvalidPrimitive {},
+            primitive.validate(deps.as_ref().api).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn try_get_object() {
+        let object = BTreeMap::from([
+            (
+                "start".to_string(),
+                Primitive::Timestamp(Timestamp::from_seconds(1)),
+            ),
+            ("price".to_string(), Primitive::Uint128(Uint128::new(10))),
+        ]);
+        let primitive = Primitive::Object(object.clone());
+        assert_eq!(object, primitive.try_get_object().unwrap());
+
+        let primitive = Primitive::String("String".to_string());
+        assert_eq!(
+            parse_error("Object".to_string()),
+            primitive.try_get_object().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn try_get_new_types() {
+        let primitive = Primitive::Addr(Addr::unchecked("addr"));
+        assert_eq!(Addr::unchecked("addr"), primitive.try_get_addr().unwrap());
+        assert_eq!(
+            parse_error("Int64".to_string()),
+            primitive.try_get_int64().unwrap_err()
+        );
+
+        let primitive = Primitive::Timestamp(Timestamp::from_seconds(1));
+        assert_eq!(
+            Timestamp::from_seconds(1),
+            primitive.try_get_timestamp().unwrap()
+        );
+
+        let primitive = Primitive::Expiration(Expiration::AtHeight(1));
+        assert_eq!(
+            Expiration::AtHeight(1),
+            primitive.try_get_expiration().unwrap()
+        );
+
+        let primitive = Primitive::Int64(-1);
+        assert_eq!(-1, primitive.try_get_int64().unwrap());
+
+        let primitive = Primitive::Int128(-1);
+        assert_eq!(-1, primitive.try_get_int128().unwrap());
+        assert_eq!(
+            parse_error("Addr".to_string()),
+            primitive.try_get_addr().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn object_serialization() {
+        let primitive = Primitive::Object(BTreeMap::from([
+            ("b".to_string(), Primitive::Int128(-5)),
+            ("a".to_string(), Primitive::Bool(true)),
+        ]));
+        let binary = to_binary(&primitive).unwrap();
+        assert_eq!(
+            r#"{"object":[["a",{"bool":true}],["b",{"int128":"-5"}]]}"#,
+            String::from_utf8(binary.to_vec()).unwrap()
+        );
+        assert_eq!(primitive, from_binary(&binary).unwrap());
+    }
+
+    #[test]
+    fn int_serialization() {
+        for (primitive, json) in [
+            (
+                Primitive::Int64(i64::MIN),
+                r#"{"int64":"-9223372036854775808"}"#,
+            ),
+            (
+                Primitive::Int128(i128::MAX),
+                r#"{"int128":"170141183460469231731687303715884105727"}"#,
+            ),
+        ] {
+            let binary = to_binary(&primitive).unwrap();
+            assert_eq!(json, String::from_utf8(binary.to_vec()).unwrap());
+            assert_eq!(primitive, from_binary(&binary).unwrap());
+        }
+
+        // Integers must be sent as strings.
+        let res = from_binary::<Primitive>(&Binary::from(br#"{"int64":-1}"#));
+        assert!(res.is_err());
+        let res = from_binary::<Primitive>(&Binary::from(br#"{"int128":"1.5"}"#));
+        assert!(res.is_err());
     }
 }
@@ -0,0 +1,20 @@
+//! (De)serializes the entries of a `Primitive::Object` as a list of `[key, value]` pairs.
+
+use super::Primitive;
+use serde::{Deserialize, Deserializer, Serializer};
+use std::collections::BTreeMap;
+
+pub fn serialize<S>(object: &BTreeMap<String, Primitive>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(object.iter())
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<BTreeMap<String, Primitive>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let entries: Vec<(String, Primitive)> = Vec::deserialize(deserializer)?;
+    Ok(entries.into_iter().collect())
+}
@@ -4,9 +4,13 @@ use crate::{
     error::ContractError,
     primitive::{GetValueResponse, Primitive},
 };
-use cosmwasm_std::{to_binary, Addr, Api, Coin, Decimal, QuerierWrapper, StdError, Uint128};
+use cosmwasm_std::{
+    to_binary, Addr, Api, Coin, Decimal, QuerierWrapper, StdError, Timestamp, Uint128,
+};
+use cw_utils::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -153,6 +157,72 @@ impl Value<Vec<Primitive>> {
     }
 }
 
+impl Value<BTreeMap<String, Primitive>> {
+    pub fn try_into_object(
+        self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        app_address: Option<Addr>,
+    ) -> Result<Option<BTreeMap<String, Primitive>>, ContractError> {
+        self.try_into_value(api, querier, app_address, |p| p.try_get_object())
+    }
+}
+
+impl Value<Addr> {
+    pub fn try_into_addr(
+        self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        app_address: Option<Addr>,
+    ) -> Result<Option<Addr>, ContractError> {
+        self.try_into_value(api, querier, app_address, |p| p.try_get_addr())
+    }
+}
+
+impl Value<Timestamp> {
+    pub fn try_into_timestamp(
+        self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        app_address: Option<Addr>,
+    ) -> Result<Option<Timestamp>, ContractError> {
+        self.try_into_value(api, querier, app_address, |p| p.try_get_timestamp())
+    }
+}
+
+impl Value<Expiration> {
+    pub fn try_into_expiration(
+        self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        app_address: Option<Addr>,
+    ) -> Result<Option<Expiration>, ContractError> {
+        self.try_into_value(api, querier, app_address, |p| p.try_get_expiration())
+    }
+}
+
+impl Value<i64> {
+    pub fn try_into_int64(
+        self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        app_address: Option<Addr>,
+    ) -> Result<Option<i64>, ContractError> {
+        self.try_into_value(api, querier, app_address, |p| p.try_get_int64())
+    }
+}
+
+impl Value<i128> {
+    pub fn try_into_int128(
+        self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        app_address: Option<Addr>,
+    ) -> Result<Option<i128>, ContractError> {
+        self.try_into_value(api, querier, app_address, |p| p.try_get_int128())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -362,4 +432,163 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn test_value_into_object() {
+        let deps = mock_dependencies_custom(&[]);
+        let value = Value::Raw(BTreeMap::from([(
+            "price".to_string(),
+            Primitive::Uint128(Uint128::new(10)),
+        )]));
+        assert_eq!(
+            Some(BTreeMap::from([(
+                "price".to_string(),
+                Primitive::Uint128(Uint128::new(10))
+            )])),
+            value
+                .try_into_object(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+
+        let value = Value::Pointer(PrimitivePointer {
+            address: AndrAddress {
+                identifier: MOCK_PRIMITIVE_CONTRACT.to_owned(),
+            },
+            key: Some("Object".to_string()),
+        });
+        assert_eq!(
+            Some(BTreeMap::from([(
+                "price".to_string(),
+                Primitive::Uint128(Uint128::new(10))
+            )])),
+            value
+                .try_into_object(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_value_into_addr() {
+        let deps = mock_dependencies_custom(&[]);
+        let value = Value::Raw(Addr::unchecked("addr"));
+        assert_eq!(
+            Some(Addr::unchecked("addr")),
+            value
+                .try_into_addr(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+
+        let value = Value::Pointer(PrimitivePointer {
+            address: AndrAddress {
+                identifier: MOCK_PRIMITIVE_CONTRACT.to_owned(),
+            },
+            key: Some("Addr".to_string()),
+        });
+        assert_eq!(
+            Some(Addr::unchecked("addr")),
+            value
+                .try_into_addr(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_value_into_timestamp() {
+        let deps = mock_dependencies_custom(&[]);
+        let value = Value::Raw(Timestamp::from_seconds(100));
+        assert_eq!(
+            Some(Timestamp::from_seconds(100)),
+            value
+                .try_into_timestamp(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+
+        let value = Value::Pointer(PrimitivePointer {
+            address: AndrAddress {
+                identifier: MOCK_PRIMITIVE_CONTRACT.to_owned(),
+            },
+            key: Some("Timestamp".to_string()),
+        });
+        assert_eq!(
+            Some(Timestamp::from_seconds(100)),
+            value
+                .try_into_timestamp(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_value_into_expiration() {
+        let deps = mock_dependencies_custom(&[]);
+        let value = Value::Raw(Expiration::AtHeight(100));
+        assert_eq!(
+            Some(Expiration::AtHeight(100)),
+            value
+                .try_into_expiration(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+
+        let value = Value::Pointer(PrimitivePointer {
+            address: AndrAddress {
+                identifier: MOCK_PRIMITIVE_CONTRACT.to_owned(),
+            },
+            key: Some("Expiration".to_string()),
+        });
+        assert_eq!(
+            Some(Expiration::AtHeight(100)),
+            value
+                .try_into_expiration(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_value_into_int64() {
+        let deps = mock_dependencies_custom(&[]);
+        let value = Value::Raw(-10i64);
+        assert_eq!(
+            Some(-10i64),
+            value
+                .try_into_int64(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+
+        let value = Value::Pointer(PrimitivePointer {
+            address: AndrAddress {
+                identifier: MOCK_PRIMITIVE_CONTRACT.to_owned(),
+            },
+            key: Some("Int64".to_string()),
+        });
+        assert_eq!(
+            Some(-10i64),
+            value
+                .try_into_int64(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_value_into_int128() {
+        let deps = mock_dependencies_custom(&[]);
+        let value = Value::Raw(-10i128);
+        assert_eq!(
+            Some(-10i128),
+            value
+                .try_into_int128(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+
+        let value = Value::Pointer(PrimitivePointer {
+            address: AndrAddress {
+                identifier: MOCK_PRIMITIVE_CONTRACT.to_owned(),
+            },
+            key: Some("Int128".to_string()),
+        });
+        assert_eq!(
+            Some(-10i128),
+            value
+                .try_into_int128(deps.as_ref().api, &deps.as_ref().querier, None)
+                .unwrap()
+        );
+    }
 }
@@ -1,10 +1,12 @@
-use cosmwasm_std::{BankMsg, CosmosMsg, SubMsg, Uint128};
+use crate::error::ContractError;
+use cosmwasm_std::{from_binary, BankMsg, CosmosMsg, SubMsg, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
 
 /// Gets the amount of tax paid by iterating over the `msgs` and comparing it to the
 /// difference between the base amount and the amount left over after royalties.
 /// It is assumed that each bank message has a single Coin to send as transfer
-/// agreements only accept a single Coin. It is also assumed that the result will always be
-/// non-negative.
+/// agreements only accept a single Coin. CW20 payments are counted from `Transfer` and `Send`
+/// messages. An error is returned if the payments are less than the deducted amount.
 ///
 /// # Arguments
 ///
@@ -17,17 +19,98 @@ pub fn get_tax_amount(
     msgs: &[SubMsg],
     base_amount: Uint128,
     remaining_amount_after_royalties: Uint128,
-) -> Uint128 {
-    let deducted_amount = base_amount - remaining_amount_after_royalties;
-    msgs.iter()
-        .map(|msg| {
-            if let CosmosMsg::Bank(BankMsg::Send { amount, .. }) = &msg.msg {
-                amount[0].amount
-            } else {
-                Uint128::zero()
-            }
+) -> Result<Uint128, ContractError> {
+    let deducted_amount = base_amount.checked_sub(remaining_amount_after_royalties)?;
+    let total_payments = msgs
+        .iter()
+        .map(|msg| match &msg.msg {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. }) => amount
+                .first()
+                .map(|coin| coin.amount)
+                .unwrap_or_else(Uint128::zero),
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_binary(msg) {
+                Ok(Cw20ExecuteMsg::Transfer { amount, .. })
+                | Ok(Cw20ExecuteMsg::Send { amount, .. }) => amount,
+                _ => Uint128::zero(),
+            },
+            _ => Uint128::zero(),
         })
-        .reduce(|total, amount| total + amount)
-        .unwrap_or_else(Uint128::zero)
-        - deducted_amount
+        .try_fold(Uint128::zero(), |total, amount| total.checked_add(amount))?;
+    Ok(total_payments.checked_sub(deducted_amount)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{coins, to_binary};
+
+    #[test]
+    fn test_get_tax_amount() {
+        let msgs = vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: "royalty".to_string(),
+                amount: coins(10, "uusd"),
+            }),
+            SubMsg::new(BankMsg::Send {
+                to_address: "tax".to_string(),
+                amount: coins(5, "uusd"),
+            }),
+        ];
+        assert_eq!(
+            Uint128::new(5),
+            get_tax_amount(&msgs, Uint128::new(100), Uint128::new(90)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_tax_amount_cw20() {
+        let msgs = vec![
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "cw20".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "royalty".to_string(),
+                    amount: Uint128::new(10),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "cw20".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: "tax".to_string(),
+                    amount: Uint128::new(5),
+                    msg: to_binary(&"").unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            // Other messages are ignored.
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "receipt".to_string(),
+                msg: to_binary(&"store_receipt").unwrap(),
+                funds: vec![],
+            }),
+        ];
+        assert_eq!(
+            Uint128::new(5),
+            get_tax_amount(&msgs, Uint128::new(100), Uint128::new(90)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_tax_amount_insufficient_payments() {
+        // Bank messages without coins are counted as empty.
+        let msgs = vec![SubMsg::new(BankMsg::Send {
+            to_address: "royalty".to_string(),
+            amount: vec![],
+        })];
+        assert_eq!(
+            ContractError::Overflow {},
+            get_tax_amount(&msgs, Uint128::new(100), Uint128::new(90)).unwrap_err()
+        );
+        assert_eq!(
+            ContractError::Overflow {},
+            get_tax_amount(&msgs, Uint128::new(90), Uint128::new(100)).unwrap_err()
+        );
+    }
 }
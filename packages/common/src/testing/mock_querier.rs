@@ -5,9 +5,11 @@ use crate::{
 use cosmwasm_std::{
     from_binary, from_slice,
     testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR},
-    to_binary, Binary, Coin, ContractResult, Decimal, OwnedDeps, Querier, QuerierResult,
-    QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+    to_binary, Addr, Binary, Coin, ContractResult, Decimal, OwnedDeps, Querier, QuerierResult,
+    QueryRequest, SystemError, SystemResult, Timestamp, Uint128, WasmQuery,
 };
+use cw_utils::Expiration;
+use std::collections::BTreeMap;
 
 pub const MOCK_APP_CONTRACT: &str = "app_contract";
 pub const MOCK_PRIMITIVE_CONTRACT: &str = "primitive_contract";
@@ -104,6 +106,33 @@ impl WasmMockQuerier {
                                 key,
                                 value: Primitive::Vec(vec![Primitive::from("String".to_string())]),
                             },
+                            "Object" => GetValueResponse {
+                                key,
+                                value: Primitive::Object(BTreeMap::from([(
+                                    "price".to_string(),
+                                    Primitive::Uint128(Uint128::new(10)),
+                                )])),
+                            },
+                            "Addr" => GetValueResponse {
+                                key,
+                                value: Primitive::Addr(Addr::unchecked("addr")),
+                            },
+                            "Timestamp" => GetValueResponse {
+                                key,
+                                value: Primitive::Timestamp(Timestamp::from_seconds(100)),
+                            },
+                            "Expiration" => GetValueResponse {
+                                key,
+                                value: Primitive::Expiration(Expiration::AtHeight(100)),
+                            },
+                            "Int64" => GetValueResponse {
+                                key,
+                                value: Primitive::Int64(-10),
+                            },
+                            "Int128" => GetValueResponse {
+                                key,
+                                value: Primitive::Int128(-10),
+                            },
                             _ => {
                                 return SystemResult::Ok(ContractResult::Err(
                                     "Not Found".to_string(),